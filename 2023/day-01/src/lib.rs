@@ -1,14 +1,45 @@
-use std::fs;
+use std::fmt::Display;
 use std::str;
-use std::usize::MAX;
 
+use aoc_core::{debug, parse::ParseError, solution::Solution};
+
+/// The number made of the first and last digits of the line
+fn get_digits_calibration(line: &str) -> u32 {
+    let mut test = line
+        .chars()
+        .flat_map(|c| c.to_digit(10))
+        .map(|i| char::from_digit(i, 10).unwrap());
+
+    let mut temp: Vec<char> = vec![];
+    let first = test.next().unwrap_or('0');
+    temp.push(first);
+    temp.push(test.next_back().unwrap_or(first));
+
+    let calibration: u32 = str::parse(&(temp.iter().collect::<String>())).expect("Ouch");
+    debug!("Word '{}' calibration is: {:?}", line, calibration);
+    calibration
+}
+
+#[cfg(test)]
+mod tests_get_digits_calibration {
+    use super::*;
+
+    #[test]
+    fn get_digits_calibration_01() {
+        assert_eq!(get_digits_calibration("1abc2"), 12);
+        assert_eq!(get_digits_calibration("treb7uchet"), 77);
+        assert_eq!(get_digits_calibration(""), 0);
+    }
+}
+
+/// Same as `get_digits_calibration`, with spelled out digits counting as well
 fn get_calibration(line: &str) -> u32 {
     let mut ascending_numbers_position: Vec<(&str, usize)> = vec![];
     let ascending_numbers = [
         "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
     ];
     for number in ascending_numbers {
-        ascending_numbers_position.push((number, line.find(&number).unwrap_or(MAX)));
+        ascending_numbers_position.push((number, line.find(number).unwrap_or(usize::MAX)));
     }
     ascending_numbers_position.sort_by_key(|t| t.1);
 
@@ -18,13 +49,14 @@ fn get_calibration(line: &str) -> u32 {
         "zero", "eno", "owt", "eerht", "ruof", "evif", "xis", "neves", "thgie", "enin",
     ];
     for number in descending_numbers {
-        descending_numbers_position.push((number, reversed_line.find(&number).unwrap_or(MAX)));
+        descending_numbers_position
+            .push((number, reversed_line.find(number).unwrap_or(usize::MAX)));
     }
     descending_numbers_position.sort_by_key(|t| t.1);
 
     let cleaned_line = {
-        let temp = line.clone();
-        let first = ascending_numbers_position.get(0);
+        let temp = line;
+        let first = ascending_numbers_position.first();
         let temp2 = match first {
             Some(i) => {
                 if i.0 == "one" {
@@ -49,8 +81,9 @@ fn get_calibration(line: &str) -> u32 {
             }
             None => temp.replace("nothing", "0"),
         };
-        let last = descending_numbers_position.get(0);
-        let temp3 = match last {
+        let last = descending_numbers_position.first();
+
+        match last {
             Some(i) => {
                 if i.0 == "eno" {
                     temp2.replace("one", "1")
@@ -73,37 +106,33 @@ fn get_calibration(line: &str) -> u32 {
                 }
             }
             None => temp2.replace("nothing", "0"),
-        };
-        temp3
+        }
     };
 
-    let mut test = cleaned_line
-        .chars()
-        .flat_map(|c| c.to_digit(10))
-        .map(|i| char::from_digit(i, 10).unwrap());
-
-    let mut temp: Vec<char> = vec![];
-    let first = test.next().unwrap_or('0');
-    temp.push(first);
-    temp.push(test.last().unwrap_or(first));
-
-    let calibration: u32 = str::parse(&(temp.iter().collect::<String>())).expect("Ouch");
-    println!("Word '{}' calibration is: {:?}", line, calibration);
-    return calibration;
+    get_digits_calibration(&cleaned_line)
 }
 
-fn main() {
-    let file_path = "assets/input";
-    println!("Loading input file: {}", file_path);
-    let file_content = fs::read_to_string(file_path).expect("Cannot load file");
+pub struct Day01;
 
-    let mut calibrations_sum = 0;
+impl Solution for Day01 {
+    type Input = Vec<String>;
 
-    for line in file_content.split("\n") {
-        calibrations_sum += get_calibration(&line);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.split("\n").map(String::from).collect())
     }
 
-    println!("Calibration sum: {}", calibrations_sum);
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(
+            input
+                .iter()
+                .map(|line| get_digits_calibration(line))
+                .sum::<u32>(),
+        )
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(input.iter().map(|line| get_calibration(line)).sum::<u32>())
+    }
 }
 
 #[cfg(test)]
@@ -160,3 +189,28 @@ mod tests {
         assert_eq!(get_calibration("sevenine"), 79);
     }
 }
+
+#[cfg(test)]
+mod tests_day01 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day01_example_part1() {
+        assert_eq!(
+            solve::<Day01>("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet", &[1]),
+            Ok(vec![Some(String::from("142"))])
+        );
+    }
+
+    #[test]
+    fn day01_example_part2() {
+        assert_eq!(
+            solve::<Day01>(
+                "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen",
+                &[2]
+            ),
+            Ok(vec![Some(String::from("281"))])
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt::Display;
+use std::str;
+
+use aoc_core::{
+    debug,
+    parse::{parse_field, ParseError},
+    solution::Solution,
+    trace,
+};
+
+const RED_COUNT: u32 = 12;
+const GREEN_COUNT: u32 = 13;
+const BLUE_COUNT: u32 = 14;
+
+/// (red, green, blue)
+type Round = (u32, u32, u32);
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    id: u32,
+    rounds: Vec<Round>,
+}
+
+fn is_impossible(round: &Round) -> bool {
+    round.0 > RED_COUNT || round.1 > GREEN_COUNT || round.2 > BLUE_COUNT
+}
+
+fn get_power(round: &Round) -> u32 {
+    round.0 * round.1 * round.2
+}
+
+fn parse_game(index: usize, line: &str) -> Result<Game, ParseError> {
+    let (game, rounds) = line
+        .split_once(":")
+        .ok_or_else(|| ParseError::missing(index, line, "`:` after the game id"))?;
+    let id = game
+        .strip_prefix("Game ")
+        .ok_or_else(|| ParseError::at(index, line, game, "expected `Game <id>`"))?;
+    let mut game = Game {
+        id: parse_field(index, line, id, "game id")?,
+        rounds: vec![],
+    };
+    for round_as_string in rounds.split(";") {
+        let mut round: Round = (0, 0, 0);
+        for ball_count_for_one_color in round_as_string.split(",") {
+            let ball_count_for_one_color = ball_count_for_one_color.trim();
+            let (count, color) = ball_count_for_one_color.split_once(" ").ok_or_else(|| {
+                ParseError::at(
+                    index,
+                    line,
+                    ball_count_for_one_color,
+                    "expected `<count> <color>`",
+                )
+            })?;
+            let count = parse_field(index, line, count, "ball count")?;
+            match color {
+                "red" => round.0 = count,
+                "green" => round.1 = count,
+                "blue" => round.2 = count,
+                _ => return Err(ParseError::at(index, line, color, "unknown color")),
+            }
+        }
+        game.rounds.push(round);
+    }
+    trace!("{:?}", game);
+    Ok(game)
+}
+
+#[cfg(test)]
+mod tests_parse_game {
+    use super::*;
+
+    #[test]
+    fn parse_game_01() {
+        let game = parse_game(0, "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red").unwrap();
+        assert_eq!(game.id, 3);
+        assert_eq!(game.rounds, vec![(20, 8, 6), (4, 0, 5)]);
+    }
+
+    #[test]
+    fn parse_game_malformed() {
+        assert_eq!(
+            parse_game(1, "Game 2: 1 blue, 2 purple").map(|_| ()),
+            Err(ParseError {
+                line: 2,
+                column: 19,
+                text: String::from("purple"),
+                reason: String::from("unknown color"),
+            })
+        );
+        assert_eq!(
+            parse_game(0, "Game 1: 3 blue; red").map(|_| ()),
+            Err(ParseError::at(
+                0,
+                "Game 1: 3 blue; red",
+                "red",
+                "expected `<count> <color>`"
+            ))
+        );
+        assert_eq!(
+            parse_game(0, "Game 1").map(|_| ()),
+            Err(ParseError::missing(0, "Game 1", "`:` after the game id"))
+        );
+    }
+}
+
+fn get_games(file_content: &str) -> Result<Vec<Game>, ParseError> {
+    file_content
+        .split("\n")
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| parse_game(index, line))
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_games(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        let mut game_id_sum = 0;
+
+        for game in input.iter() {
+            let mut should_add_game = true;
+            for round in game.rounds.iter() {
+                if is_impossible(round) {
+                    debug!("Game {} MUST NOT BE ADDED", game.id);
+                    should_add_game = false;
+                    break;
+                }
+            }
+            if should_add_game {
+                debug!("Game {} is added", game.id);
+                game_id_sum += game.id;
+            }
+        }
+
+        Some(game_id_sum)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let mut game_powers_sum = 0;
+
+        for game in input.iter() {
+            let mut minimum_round: Round = (0, 0, 0);
+            for round in game.rounds.iter() {
+                if round.0 > minimum_round.0 {
+                    minimum_round.0 = round.0;
+                }
+                if round.1 > minimum_round.1 {
+                    minimum_round.1 = round.1;
+                }
+                if round.2 > minimum_round.2 {
+                    minimum_round.2 = round.2;
+                }
+            }
+            game_powers_sum += get_power(&minimum_round);
+        }
+
+        Some(game_powers_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_possible_01() {
+        assert!(!is_impossible(&(4, 3, 0)));
+    }
+
+    #[test]
+    fn is_possible_02() {
+        assert!(is_impossible(&(8, 6, 20)));
+    }
+
+    #[test]
+    fn is_possible_03() {
+        assert!(is_impossible(&(14, 3, 15)));
+    }
+}
+
+#[cfg(test)]
+mod tests_day02 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day02_example() {
+        let input = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+        assert_eq!(
+            solve::<Day02>(input, &[1, 2]),
+            Ok(vec![Some(String::from("8")), Some(String::from("2286"))])
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_core::{
+    debug,
+    parse::{parse_field, ParseError},
+    solution::Solution,
+};
+
+type Coordinates = (u32, u32);
+
+type Schematic = HashMap<Coordinates, char>;
+
+/// Schematics smaller than `LEN_MAX` x `HEIGHT_MAX` are fine, as anything
+/// missing is considered to be a `.`
+fn parse_schematic(file_content: &str) -> Result<Schematic, ParseError> {
+    let mut schematic: Schematic = HashMap::new();
+    for (index, line) in file_content.lines().enumerate() {
+        if index >= HEIGHT_MAX as usize {
+            return Err(ParseError::line(
+                index,
+                line,
+                format!("the schematic is at most {} lines high", HEIGHT_MAX),
+            ));
+        }
+        if line.chars().count() > LEN_MAX as usize {
+            return Err(ParseError::line(
+                index,
+                line,
+                format!("the schematic is at most {} characters wide", LEN_MAX),
+            ));
+        }
+        for number in line
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|number| !number.is_empty())
+        {
+            parse_field::<u32>(index, line, number, "part number")?;
+        }
+        for (w, char) in (1..).zip(line.chars()) {
+            schematic.insert((w, index as u32 + 1), char);
+        }
+    }
+    Ok(schematic)
+}
+
+fn is_symbol(c: &char) -> bool {
+    !c.is_ascii_digit() && c != &'.'
+}
+
+const LEN_MAX: i32 = 140;
+const HEIGHT_MAX: i32 = 140;
+
+/// The first cell around the given coordinates whose content matches
+fn find_around(
+    schematic: &Schematic,
+    coordinates: &Coordinates,
+    matches: impl Fn(&char) -> bool,
+) -> Option<Coordinates> {
+    let casted_coordinates: (i32, i32) = (coordinates.0 as i32, coordinates.1 as i32);
+    for h in (casted_coordinates.1 - 1).clamp(0, HEIGHT_MAX) as u32
+        ..=(casted_coordinates.1 + 1).clamp(0, HEIGHT_MAX) as u32
+    {
+        for w in (casted_coordinates.0 - 1).clamp(0, LEN_MAX) as u32
+            ..=(casted_coordinates.0 + 1).clamp(0, LEN_MAX) as u32
+        {
+            if matches(schematic.get(&(w, h)).unwrap_or(&'.')) {
+                return Some((w, h));
+            }
+        }
+    }
+    None
+}
+
+fn has_symbol_around(schematic: &Schematic, coordinates: &Coordinates) -> bool {
+    find_around(schematic, coordinates, is_symbol).is_some()
+}
+
+fn get_part_numbers(schematic: &Schematic) -> Vec<u32> {
+    let mut part_numbers: Vec<u32> = vec![];
+
+    // The flag that will tell us to keep accumulating
+    // digits to form the part number
+    let mut acc = false;
+
+    // The flag that will tell us that the part is valid
+    // (it has a symbol around it)
+    let mut should_add_part = false;
+
+    // Temporary vector to accumulate digits to form the part number
+    let mut temp_chars: Vec<char> = vec![];
+
+    for h in 1..=HEIGHT_MAX as u32 {
+        for w in 1..=LEN_MAX as u32 {
+            let coordinates = (w, h);
+            let c = schematic.get(&coordinates).unwrap_or(&'.');
+            if c.is_ascii_digit() {
+                if !acc {
+                    acc = true;
+                }
+                temp_chars.push(*c);
+                if has_symbol_around(schematic, &coordinates) {
+                    should_add_part = true;
+                }
+            } else {
+                if acc {
+                    let new_part = temp_chars
+                        .iter()
+                        .collect::<String>()
+                        .parse()
+                        .expect("Not a valid number");
+                    if should_add_part {
+                        debug!("New part: {} will be added", new_part);
+                        part_numbers.push(new_part);
+                    } else {
+                        debug!("New part: {} will NOT be added", new_part);
+                    }
+                    // Clean up for next part
+                    acc = false;
+                    should_add_part = false;
+                    temp_chars.clear();
+                }
+            }
+        }
+    }
+
+    part_numbers
+}
+
+/// If applicable, retrieve the gear center of a possible gear part
+fn get_gear_center(schematic: &Schematic, coordinates: &Coordinates) -> Option<Coordinates> {
+    find_around(schematic, coordinates, |c| c == &'*')
+}
+
+#[derive(Debug)]
+struct GearPart {
+    number: u32,
+    gear_center: Coordinates,
+}
+
+fn get_potential_gear_parts(schematic: &Schematic) -> Vec<GearPart> {
+    let mut gear_parts: Vec<GearPart> = vec![];
+
+    // The flag that will tell us to keep accumulating
+    // digits to form the part number
+    let mut acc = false;
+
+    // The flag that will tell us that the part is valid
+    // (it has a symbol around it)
+    let mut should_add_part = false;
+
+    // Temporary vector to accumulate digits to form the part number
+    let mut temp_chars: Vec<char> = vec![];
+    let mut temp_gear_center: Coordinates = (0, 0);
+
+    for h in 1..=HEIGHT_MAX as u32 {
+        for w in 1..=LEN_MAX as u32 {
+            let coordinates = (w, h);
+            let c = schematic.get(&coordinates).unwrap_or(&'.');
+            if c.is_ascii_digit() {
+                if !acc {
+                    acc = true;
+                }
+                temp_chars.push(*c);
+                if let Some(gear_center) = get_gear_center(schematic, &coordinates) {
+                    should_add_part = true;
+                    temp_gear_center = gear_center;
+                }
+            } else {
+                if acc {
+                    let new_gear_part = GearPart {
+                        number: temp_chars
+                            .iter()
+                            .collect::<String>()
+                            .parse()
+                            .expect("Not a valid number"),
+                        gear_center: temp_gear_center,
+                    };
+
+                    if should_add_part {
+                        debug!("New gear part: {:?} will be added", new_gear_part);
+                        gear_parts.push(new_gear_part);
+                    } else {
+                        debug!("New gear part: {:?} will NOT be added", new_gear_part);
+                    }
+                    // Clean up for next part
+                    acc = false;
+                    should_add_part = false;
+                    temp_chars.clear();
+                    temp_gear_center = (0, 0);
+                }
+            }
+        }
+    }
+
+    gear_parts
+}
+
+fn get_gear_centers(schematic: &Schematic) -> Vec<Coordinates> {
+    let mut gear_centers: Vec<Coordinates> = vec![];
+    for h in 1..=HEIGHT_MAX as u32 {
+        for w in 1..=LEN_MAX as u32 {
+            let coordinates = (w, h);
+            if schematic.get(&coordinates) == Some(&'*') {
+                gear_centers.push(coordinates);
+            }
+        }
+    }
+    gear_centers
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_schematic(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(get_part_numbers(input).iter().sum::<u32>())
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let potential_gear_parts = get_potential_gear_parts(input);
+        let potential_gear_centers = get_gear_centers(input);
+
+        let mut gear_ratio_sum = 0;
+        for potential_gear_center in potential_gear_centers {
+            let gear_parts_for_gear_center = potential_gear_parts
+                .iter()
+                .filter(|e| e.gear_center == potential_gear_center);
+            if gear_parts_for_gear_center.clone().count() == 2 {
+                let gear_ratio: u32 = gear_parts_for_gear_center.fold(1, |acc, e| acc * e.number);
+                gear_ratio_sum += gear_ratio;
+            }
+        }
+
+        Some(gear_ratio_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_schematic_01() {
+        let schematic = parse_schematic(&String::from(".6+\n54*")).unwrap();
+        assert_eq!(schematic.get(&(1, 1)), Some(&'.'));
+        assert_eq!(schematic.get(&(2, 1)), Some(&'6'));
+        assert_eq!(schematic.get(&(3, 1)), Some(&'+'));
+        assert_eq!(schematic.get(&(1, 2)), Some(&'5'));
+        assert_eq!(schematic.get(&(2, 2)), Some(&'4'));
+        assert_eq!(schematic.get(&(3, 2)), Some(&'*'));
+        assert_eq!(schematic.get(&(1, 3)), None);
+    }
+
+    #[test]
+    fn parse_schematic_malformed() {
+        assert_eq!(
+            parse_schematic("...\n.12345678901*"),
+            Err(ParseError {
+                line: 2,
+                column: 2,
+                text: String::from("12345678901"),
+                reason: String::from("invalid part number: number too large to fit in target type"),
+            })
+        );
+        let too_wide = ".".repeat(141);
+        assert_eq!(
+            parse_schematic(&too_wide),
+            Err(ParseError::line(
+                0,
+                &too_wide,
+                "the schematic is at most 140 characters wide"
+            ))
+        );
+    }
+}
@@ -0,0 +1,411 @@
+use std::fmt::Display;
+
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+    trace,
+};
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Scratchcard {
+    id: usize,
+    winning_numbers: Vec<u32>,
+    our_numbers: Vec<u32>,
+    copies: u32,
+}
+
+// Using a two dimensional array allows us to handle multiple scratchcard copies
+type ScratchcardList = Vec<Scratchcard>;
+
+fn parse_scratchcard(index: usize, line: &str) -> Result<Scratchcard, ParseError> {
+    let (card, numbers) = line
+        .split_once(":")
+        .ok_or_else(|| ParseError::missing(index, line, "`:` after the card id"))?;
+    let id = card
+        .strip_prefix("Card")
+        .ok_or_else(|| ParseError::at(index, line, card, "expected `Card <id>`"))?
+        .trim();
+    let (winning_numbers, our_numbers) = numbers
+        .split_once("|")
+        .ok_or_else(|| ParseError::missing(index, line, "`|` between both number lists"))?;
+
+    let parse_numbers = |numbers: &str| -> Result<Vec<u32>, ParseError> {
+        numbers
+            .split_whitespace()
+            .map(|number| parse_field(index, line, number, "number"))
+            .collect()
+    };
+
+    Ok(Scratchcard {
+        id: parse_field(index, line, id, "scratchcard id")?,
+        winning_numbers: parse_numbers(winning_numbers)?,
+        our_numbers: parse_numbers(our_numbers)?,
+        copies: 1,
+    })
+}
+
+#[cfg(test)]
+mod tests_parse_scratchcard {
+    use super::*;
+
+    #[test]
+    fn parse_scratchcard_short() {
+        let scratchcard =
+            parse_scratchcard(0, "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+
+        assert_eq!(scratchcard.id, 1);
+        assert_eq!(scratchcard.winning_numbers, vec![41, 48, 83, 86, 17]);
+        assert_eq!(scratchcard.our_numbers, vec![83, 86, 6, 31, 17, 9, 48, 53]);
+    }
+
+    #[test]
+    fn parse_scratchcard_01() {
+        let scratchcard = parse_scratchcard(0, &String::from(
+"Card   123: 73 92 13 35 18 96 37 72 76 39 | 82 14 66 57 25 98 49 28  3 95 81 85 31 30 16 79  7 12 55 19 97 45  9 58  2"
+        )).unwrap();
+
+        assert_eq!(scratchcard.id, 123);
+        assert_eq!(
+            scratchcard.winning_numbers,
+            vec![73, 92, 13, 35, 18, 96, 37, 72, 76, 39]
+        );
+        assert_eq!(
+            scratchcard.our_numbers,
+            vec![
+                82, 14, 66, 57, 25, 98, 49, 28, 3, 95, 81, 85, 31, 30, 16, 79, 7, 12, 55, 19, 97,
+                45, 9, 58, 2
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_scratchcard_malformed() {
+        assert_eq!(
+            parse_scratchcard(6, "Card 7: 41 48 8x | 83 86"),
+            Err(ParseError {
+                line: 7,
+                column: 15,
+                text: String::from("8x"),
+                reason: String::from("invalid number: invalid digit found in string"),
+            })
+        );
+        assert_eq!(
+            parse_scratchcard(0, "Card 1: 41 48"),
+            Err(ParseError {
+                line: 1,
+                column: 14,
+                text: String::new(),
+                reason: String::from("missing `|` between both number lists"),
+            })
+        );
+    }
+}
+
+fn get_winning_numbers_count(scratchcard: &Scratchcard) -> u32 {
+    scratchcard
+        .our_numbers
+        .iter()
+        .map(|n| scratchcard.winning_numbers.iter().find(|w| w == &n))
+        .filter(|found| found.is_some())
+        .count() as u32
+}
+
+#[cfg(test)]
+mod tests_get_winning_numbers_count {
+    use super::*;
+
+    #[test]
+    fn get_winning_numbers_count_regular() {
+        assert_eq!(
+            get_winning_numbers_count(&Scratchcard {
+                id: 1,
+                winning_numbers: vec![41, 48, 83, 86, 17],
+                our_numbers: vec![83, 86, 6, 31, 17, 9, 48, 53],
+                copies: 1
+            }),
+            4
+        );
+    }
+
+    #[test]
+    fn get_winning_numbers_count_empty_our_numbers() {
+        assert_eq!(
+            get_winning_numbers_count(&Scratchcard {
+                id: 1,
+                winning_numbers: vec![41, 48, 83, 86, 17],
+                our_numbers: vec![],
+                copies: 1
+            }),
+            0
+        );
+    }
+
+    #[test]
+    fn get_winning_numbers_count_empty_winning_numbers() {
+        assert_eq!(
+            get_winning_numbers_count(&Scratchcard {
+                id: 1,
+                winning_numbers: vec![],
+                our_numbers: vec![83, 86, 6, 31, 17, 9, 48, 53],
+                copies: 1
+            }),
+            0
+        );
+    }
+
+    #[test]
+    fn get_winning_numbers_count_double_winning_number_83() {
+        assert_eq!(
+            get_winning_numbers_count(&Scratchcard {
+                id: 1,
+                winning_numbers: vec![41, 48, 83, 86, 17, 83],
+                our_numbers: vec![83, 86, 6, 31, 17, 9, 48, 53, 83],
+                copies: 1
+            }),
+            5
+        );
+    }
+}
+
+/// The first winning number is worth one point, each following one doubles it
+fn compute_scratchcard_score(scratchcard: &Scratchcard) -> u32 {
+    match get_winning_numbers_count(scratchcard) {
+        0 => 0,
+        count => 2_u32.pow(count - 1),
+    }
+}
+
+#[cfg(test)]
+mod tests_compute_score {
+    use super::*;
+
+    #[test]
+    fn compute_score_01() {
+        assert_eq!(
+            compute_scratchcard_score(&Scratchcard {
+                id: 1,
+                winning_numbers: vec![41, 48, 83, 86, 17],
+                our_numbers: vec![83, 86, 6, 31, 17, 9, 48, 53],
+                copies: 1,
+            }),
+            8
+        );
+    }
+
+    #[test]
+    fn compute_score_02() {
+        assert_eq!(
+            compute_scratchcard_score(&Scratchcard {
+                id: 1,
+                winning_numbers: vec![13, 32, 20, 16, 61],
+                our_numbers: vec![61, 30, 68, 82, 17, 32, 24, 19],
+                copies: 1,
+            }),
+            2
+        );
+    }
+
+    #[test]
+    fn compute_score_03() {
+        assert_eq!(
+            compute_scratchcard_score(&Scratchcard {
+                id: 1,
+                winning_numbers: vec![1, 21, 53, 59, 44],
+                our_numbers: vec![69, 82, 63, 72, 16, 21, 14, 1],
+                copies: 1,
+            }),
+            2
+        );
+    }
+
+    #[test]
+    fn compute_score_04() {
+        assert_eq!(
+            compute_scratchcard_score(&Scratchcard {
+                id: 1,
+                winning_numbers: vec![41, 92, 73, 84, 69],
+                our_numbers: vec![59, 84, 76, 51, 58, 5, 54, 83],
+                copies: 1,
+            }),
+            1
+        );
+    }
+
+    #[test]
+    fn compute_score_05() {
+        assert_eq!(
+            compute_scratchcard_score(&Scratchcard {
+                id: 1,
+                winning_numbers: vec![87, 83, 26, 28, 32],
+                our_numbers: vec![88, 30, 70, 12, 93, 22, 82, 36],
+                copies: 1,
+            }),
+            0
+        );
+    }
+
+    #[test]
+    fn compute_score_06() {
+        assert_eq!(
+            compute_scratchcard_score(&Scratchcard {
+                id: 1,
+                winning_numbers: vec![31, 18, 13, 56, 72],
+                our_numbers: vec![74, 77, 10, 23, 35, 67, 36, 11],
+                copies: 1,
+            }),
+            0
+        );
+    }
+}
+
+fn insert_copies(card_id: usize, scratchcard_list: &ScratchcardList) -> ScratchcardList {
+    let mut out_list = scratchcard_list.clone();
+
+    let how_many_to_add = get_winning_numbers_count(scratchcard_list.get(card_id).unwrap());
+    // println!("Adding {} after card {}", how_many_to_add, card_id);
+
+    for i in 1..=how_many_to_add as usize {
+        let further_card = scratchcard_list.get(card_id + i);
+        if further_card.is_some() {
+            out_list[card_id + i].copies += 1
+        };
+    }
+    out_list
+}
+
+#[cfg(test)]
+mod tests_insert_copies {
+    use super::*;
+
+    #[test]
+    fn insert_copies_not_enough_scratchcards() {
+        let scratchcard_list: ScratchcardList = vec![Scratchcard {
+            id: 1,
+            winning_numbers: vec![41, 48, 83, 86, 17],
+            our_numbers: vec![83, 86, 6, 31, 17, 9, 48, 53],
+            copies: 1,
+        }];
+        insert_copies(0, &scratchcard_list);
+        assert_eq!(
+            scratchcard_list
+                .iter()
+                .fold(0, |acc, card| acc + card.copies),
+            1
+        );
+    }
+
+    #[test]
+    fn insert_copies_add_only_one_cause_not_enough_to_add() {
+        let scratchcard_list: ScratchcardList = vec![
+            Scratchcard {
+                id: 1,
+                winning_numbers: vec![41, 48, 83, 86, 17],
+                our_numbers: vec![83, 86, 6, 31, 17, 9, 48, 53],
+                copies: 1,
+            },
+            Scratchcard {
+                id: 2,
+                winning_numbers: vec![73, 92, 13, 35, 18, 96, 37, 72, 76, 39],
+                our_numbers: vec![
+                    82, 14, 66, 57, 25, 98, 49, 28, 3, 95, 81, 85, 31, 30, 16, 79, 7, 12, 55, 19,
+                    97, 45, 9, 58, 2,
+                ],
+                copies: 1,
+            },
+        ];
+        let updated_list = insert_copies(0, &scratchcard_list);
+        assert_eq!(
+            updated_list.iter().fold(0, |acc, card| acc + card.copies),
+            3
+        );
+    }
+
+    #[test]
+    fn insert_copies_add_enough() {
+        let mut scratchcard_list: ScratchcardList = vec![];
+        scratchcard_list.push(Scratchcard {
+            id: 0,
+            winning_numbers: vec![41, 48, 83, 86, 17],
+            our_numbers: vec![83, 86, 6, 31, 17, 9, 48, 53],
+            copies: 1,
+        });
+
+        for i in 0..10 {
+            scratchcard_list.push(Scratchcard {
+                id: i,
+                winning_numbers: vec![],
+                our_numbers: vec![],
+                copies: 1,
+            });
+        }
+        let updated_list = insert_copies(0, &scratchcard_list);
+        assert_eq!(
+            updated_list.iter().fold(0, |acc, card| acc + card.copies),
+            15
+        );
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = ScratchcardList;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Initializing with an empty scratchcard at position 0 to avoid
+        // a noobish off-by-one error
+        let mut scratchcard_list: ScratchcardList = vec![Scratchcard {
+            id: 0,
+            winning_numbers: vec![],
+            our_numbers: vec![],
+            copies: 0,
+        }];
+        for (index, line) in input.lines().enumerate() {
+            scratchcard_list.push(parse_scratchcard(index, line)?);
+        }
+        Ok(scratchcard_list)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(input.iter().map(compute_scratchcard_score).sum::<u32>())
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let mut card_id = 0;
+        let mut temp_list: ScratchcardList = input.clone();
+        while let Some(card) = temp_list.clone().get(card_id) {
+            trace!(
+                "Handling card {}, which has {} copies",
+                card.id,
+                card.copies
+            );
+            for _ in 1..=card.copies {
+                temp_list = insert_copies(card_id, &temp_list);
+            }
+            card_id += 1;
+        }
+
+        Some(temp_list.iter().fold(0, |acc, card| acc + card.copies))
+    }
+}
+
+#[cfg(test)]
+mod tests_day04 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day04_example() {
+        let input = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        assert_eq!(
+            solve::<Day04>(input, &[1, 2]),
+            Ok(vec![Some(String::from("13")), Some(String::from("30"))])
+        );
+    }
+}
@@ -0,0 +1,414 @@
+use std::fmt::Display;
+
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    range_set::RangeSet,
+    solution::Solution,
+};
+
+/// The seeds line of the `index`th line of the input, which must have pairs
+/// of numbers for part 2 to make sense of it
+fn parse_seeds(index: usize, line: &str) -> Result<Vec<u64>, ParseError> {
+    let fields: Vec<&str> = line
+        .strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::line(index, line, "expected `seeds: <seed>...`"))?
+        .split_whitespace()
+        .collect();
+    if !fields.len().is_multiple_of(2) {
+        return Err(ParseError::line(
+            index,
+            line,
+            "expected pairs of range start and range length",
+        ));
+    }
+    let mut seeds = vec![];
+    for pair in fields.chunks(2) {
+        let start: u64 = parse_field(index, line, pair[0], "seed")?;
+        let length: u64 = parse_field(index, line, pair[1], "seed")?;
+        // Part 1 also reads both numbers as ranges of length 1, which must
+        // not end past the largest value either
+        if start
+            .checked_add(length)
+            .and_then(|end| end.checked_add(1))
+            .is_none()
+        {
+            return Err(ParseError::at(
+                index,
+                line,
+                pair[1],
+                "the seed range goes past the largest value",
+            ));
+        }
+        seeds.extend([start, length]);
+    }
+    Ok(seeds)
+}
+
+#[cfg(test)]
+mod tests_parse_seeds {
+    use super::*;
+
+    #[test]
+    fn parse_seed_ok() {
+        assert_eq!(
+            parse_seeds(0, "seeds: 3136945476 509728956 1904897211 495273540 1186343315 66026055 1381149926 11379441 4060485949 190301545 444541979 351779229 1076140984 104902451 264807001 60556152 3676523418 44140882 3895155702 111080695"),
+            Ok(vec![
+3136945476,509728956,1904897211,495273540,1186343315,66026055,1381149926,11379441,4060485949,190301545,444541979,351779229,1076140984,104902451,264807001,60556152,3676523418,44140882,3895155702,111080695
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_seeds_malformed() {
+        assert_eq!(
+            parse_seeds(0, "seeds: 79 1x 55 13"),
+            Err(ParseError {
+                line: 1,
+                column: 11,
+                text: String::from("1x"),
+                reason: String::from("invalid seed: invalid digit found in string"),
+            })
+        );
+        assert_eq!(
+            parse_seeds(0, "seeds: 79 14 55"),
+            Err(ParseError::line(
+                0,
+                "seeds: 79 14 55",
+                "expected pairs of range start and range length"
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_seeds_overflow() {
+        let error = parse_seeds(0, "seeds: 79 14 18446744073709551610 10").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (35, "10"));
+        assert_eq!(error.reason, "the seed range goes past the largest value");
+        let error = parse_seeds(0, "seeds: 18446744073709551615 0").unwrap_err();
+        assert_eq!(error.column, 29);
+    }
+}
+
+/// Part 1 reads the seeds line as single seeds, that is ranges of length 1
+fn get_seeds(seeds: &[u64]) -> RangeSet<u64> {
+    seeds.iter().map(|&seed| seed..seed + 1).collect()
+}
+
+/// Part 2 reads the seeds line as pairs of range start and range length
+fn get_seed_ranges(seeds: &[u64]) -> RangeSet<u64> {
+    seeds
+        .chunks(2)
+        .map(|pair| pair[0]..pair[0] + pair[1])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_get_seed_ranges {
+    use super::*;
+
+    #[test]
+    fn get_seeds_ok() {
+        assert_eq!(
+            get_seeds(&parse_seeds(0, "seeds: 1 3").unwrap()).ranges(),
+            &[1..2, 3..4]
+        );
+    }
+
+    #[test]
+    fn get_seed_ranges_ok() {
+        assert_eq!(
+            get_seed_ranges(&parse_seeds(0, "seeds: 1 3 11 42").unwrap()).ranges(),
+            &[1..4, 11..53]
+        );
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Info {
+    destination_range_start: u64,
+    source_range_start: u64,
+    range_length: u64,
+}
+
+type Map = Vec<Info>;
+
+trait Sort {
+    fn sort(&mut self);
+}
+
+impl Sort for Map {
+    fn sort(&mut self) {
+        self.sort_by(|a, b| {
+            a.source_range_start
+                .partial_cmp(&b.source_range_start)
+                .unwrap()
+        });
+    }
+}
+
+/// The lines of a map, each with its (0-based) index in the input
+fn parse_to_map(lines: &[(usize, &str)]) -> Result<Map, ParseError> {
+    let mut out = vec![];
+    for &(index, line) in lines {
+        let [destination, source, length] = line
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .try_into()
+            .map_err(|_| {
+                ParseError::line(
+                    index,
+                    line,
+                    "expected `<destination start> <source start> <length>`",
+                )
+            })?;
+        let info = Info {
+            destination_range_start: parse_field(index, line, destination, "destination start")?,
+            source_range_start: parse_field(index, line, source, "source start")?,
+            range_length: parse_field(index, line, length, "range length")?,
+        };
+        if [info.destination_range_start, info.source_range_start]
+            .iter()
+            .any(|start| start.checked_add(info.range_length).is_none())
+        {
+            return Err(ParseError::at(
+                index,
+                line,
+                length,
+                "the range goes past the largest value",
+            ));
+        }
+        out.push(info);
+    }
+    out.sort();
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests_parse_to_map {
+    use super::*;
+
+    #[test]
+    fn parse_to_map_01() {
+        assert_eq!(
+            parse_to_map(&[(0, "3 1 5"), (1, "6 3 2")]),
+            Ok(vec![
+                Info {
+                    destination_range_start: 3,
+                    source_range_start: 1,
+                    range_length: 5
+                },
+                Info {
+                    destination_range_start: 6,
+                    source_range_start: 3,
+                    range_length: 2
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_to_map_malformed() {
+        assert_eq!(
+            parse_to_map(&[(3, "3 1 5"), (4, "6 3")]),
+            Err(ParseError::line(
+                4,
+                "6 3",
+                "expected `<destination start> <source start> <length>`"
+            ))
+        );
+        assert_eq!(
+            parse_to_map(&[(3, "3 -1 5")]),
+            Err(ParseError {
+                line: 4,
+                column: 3,
+                text: String::from("-1"),
+                reason: String::from("invalid source start: invalid digit found in string"),
+            })
+        );
+        assert_eq!(
+            parse_to_map(&[(3, "18446744073709551610 1 10")]),
+            Err(ParseError {
+                line: 4,
+                column: 24,
+                text: String::from("10"),
+                reason: String::from("the range goes past the largest value"),
+            })
+        );
+    }
+}
+
+/// Where the values of `values` end up through `map`: values covered by a
+/// rule are shifted, the others are kept
+fn map_values(values: &RangeSet<u64>, map: &Map) -> RangeSet<u64> {
+    let mut mapped = RangeSet::new();
+    let mut remaining = values.clone();
+    for info in map {
+        let source =
+            RangeSet::from(info.source_range_start..info.source_range_start + info.range_length);
+        let covered = remaining.intersection(&source);
+        mapped =
+            mapped.union(&covered.shift(info.source_range_start, info.destination_range_start));
+        remaining = remaining.difference(&source);
+    }
+    mapped.union(&remaining)
+}
+
+#[cfg(test)]
+mod tests_map_values {
+    use super::*;
+
+    fn map() -> Map {
+        vec![
+            Info {
+                destination_range_start: 1,
+                source_range_start: 2,
+                range_length: 5,
+            },
+            Info {
+                destination_range_start: 10,
+                source_range_start: 15,
+                range_length: 3,
+            },
+            Info {
+                destination_range_start: 10,
+                source_range_start: 45,
+                range_length: 100,
+            },
+        ]
+    }
+
+    fn single(value: u64) -> RangeSet<u64> {
+        RangeSet::from(value..value + 1)
+    }
+
+    #[test]
+    fn map_values_found() {
+        assert_eq!(map_values(&single(3), &map()), single(2));
+    }
+
+    #[test]
+    fn map_values_not_found() {
+        assert_eq!(map_values(&single(999), &map()), single(999));
+    }
+
+    #[test]
+    fn map_values_out_of_range() {
+        assert_eq!(map_values(&single(22), &map()), single(22));
+        // The end of a rule is excluded
+        assert_eq!(map_values(&single(7), &map()), single(7));
+    }
+
+    #[test]
+    fn map_values_last_entry() {
+        assert_eq!(map_values(&single(62), &map()), single(27));
+    }
+
+    #[test]
+    fn map_values_split() {
+        assert_eq!(
+            map_values(&RangeSet::from(0..50), &map()).ranges(),
+            &[0..6, 7..15, 18..45]
+        );
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    // From seed-to-soil to humidity-to-location, in the order of the input
+    maps: Vec<Map>,
+}
+
+fn parse_almanac(content: &str) -> Result<Almanac, ParseError> {
+    let mut seeds = vec![];
+    let mut maps: Vec<Map> = vec![];
+    let mut lines = content.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        if line.starts_with("seeds:") {
+            seeds = parse_seeds(index, line)?;
+        } else if line.ends_with("map:") {
+            let acc: Vec<(usize, &str)> =
+                lines.by_ref().take_while(|(_, l)| !l.is_empty()).collect();
+            maps.push(parse_to_map(&acc)?);
+        } else if !line.is_empty() {
+            return Err(ParseError::line(
+                index,
+                line,
+                "expected the seeds or the title of a map",
+            ));
+        }
+    }
+    Ok(Almanac { seeds, maps })
+}
+
+/// Send the seeds through every map, one stage at a time
+fn get_lowest_location(seeds: RangeSet<u64>, maps: &[Map]) -> Option<u64> {
+    maps.iter()
+        .fold(seeds, |values, map| map_values(&values, map))
+        .min()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_almanac(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        get_lowest_location(get_seeds(&input.seeds), &input.maps)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        get_lowest_location(get_seed_ranges(&input.seeds), &input.maps)
+    }
+}
+
+#[cfg(test)]
+mod tests_day05 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day05_example() {
+        let input = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        assert_eq!(
+            solve::<Day05>(input, &[1, 2]),
+            Ok(vec![Some(String::from("35")), Some(String::from("46"))])
+        );
+    }
+}
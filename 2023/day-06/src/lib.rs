@@ -0,0 +1,173 @@
+use std::fmt::Display;
+
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+fn get_ways_to_beat_record(race: &Race) -> u64 {
+    (1..=race.time)
+        .map(|i| i * (race.time - i))
+        .filter(|d| d > &race.distance)
+        .count() as u64
+}
+
+fn parse_numbers(index: usize, line: &str, label: &str) -> Result<Vec<u64>, ParseError> {
+    line.strip_prefix(label)
+        .ok_or_else(|| ParseError::line(index, line, format!("expected `{}`", label)))?
+        .split_whitespace()
+        .map(|number| parse_field(index, line, number, "number"))
+        .collect()
+}
+
+/// The number written by `numbers` without the spaces, if it fits
+fn join_numbers(numbers: impl Iterator<Item = u64>) -> Option<u64> {
+    numbers
+        .map(|number| number.to_string())
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+/// Turns out there is only one race: the spaces between numbers are just bad kerning
+fn fix_kerning(races: &[Race]) -> Option<Race> {
+    Some(Race {
+        time: join_numbers(races.iter().map(|race| race.time))?,
+        distance: join_numbers(races.iter().map(|race| race.distance))?,
+    })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Race>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines();
+        let time_line = lines.next().unwrap_or_default();
+        let times = parse_numbers(0, time_line, "Time:")?;
+        let distance_line = lines.next().unwrap_or_default();
+        let distances = parse_numbers(1, distance_line, "Distance:")?;
+        if times.is_empty() {
+            return Err(ParseError::missing(0, time_line, "race times"));
+        }
+        if times.len() != distances.len() {
+            return Err(ParseError::line(
+                1,
+                distance_line,
+                "expected as many distances as times",
+            ));
+        }
+        for (index, line, numbers) in [(0, time_line, &times), (1, distance_line, &distances)] {
+            if join_numbers(numbers.iter().copied()).is_none() {
+                return Err(ParseError::line(
+                    index,
+                    line,
+                    "the numbers are too large once put together",
+                ));
+            }
+        }
+
+        let mut races: Vec<Race> = vec![];
+        for i in 0..times.len() {
+            races.push(Race {
+                time: times[i],
+                distance: distances[i],
+            });
+        }
+        Ok(races)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(input.iter().map(get_ways_to_beat_record).product::<u64>())
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        fix_kerning(input).map(|race| get_ways_to_beat_record(&race))
+    }
+}
+
+#[cfg(test)]
+mod tests_get_ways_to_beat_record {
+    use super::*;
+
+    #[test]
+    fn get_ways_to_beat_record_01() {
+        assert_eq!(
+            get_ways_to_beat_record(&Race {
+                time: 7,
+                distance: 9
+            }),
+            4
+        );
+    }
+
+    #[test]
+    fn get_ways_to_beat_record_02() {
+        assert_eq!(
+            get_ways_to_beat_record(&Race {
+                time: 15,
+                distance: 40
+            }),
+            8
+        );
+    }
+
+    #[test]
+    fn get_ways_to_beat_record_03() {
+        assert_eq!(
+            get_ways_to_beat_record(&Race {
+                time: 30,
+                distance: 200
+            }),
+            9
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests_day06 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day06_example() {
+        assert_eq!(
+            solve::<Day06>("Time:      7  15   30\nDistance:  9  40  200", &[1, 2]),
+            Ok(vec![Some(String::from("288")), Some(String::from("71503"))])
+        );
+    }
+
+    #[test]
+    fn day06_malformed() {
+        assert_eq!(
+            solve::<Day06>("Time:      7  15\nDistance:  9", &[1]),
+            Err(ParseError::line(
+                1,
+                "Distance:  9",
+                "expected as many distances as times"
+            ))
+        );
+        assert_eq!(
+            solve::<Day06>("Time:\nDistance:", &[1]),
+            Err(ParseError::missing(0, "Time:", "race times"))
+        );
+        assert_eq!(
+            solve::<Day06>(
+                "Time:      12345678901  12345678901\nDistance:  9  40",
+                &[1]
+            ),
+            Err(ParseError::line(
+                0,
+                "Time:      12345678901  12345678901",
+                "the numbers are too large once put together"
+            ))
+        );
+    }
+}
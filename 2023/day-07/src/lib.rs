@@ -1,12 +1,12 @@
-use std::{cmp::Ordering, fmt::Display, fs};
+use std::{cmp::Ordering, fmt::Display};
 
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+    trace,
+};
 use itertools::Itertools;
 
-fn get_file_content(file_path: &String) -> String {
-    println!("Loading input file: {}", file_path);
-    fs::read_to_string(file_path).expect("Cannot load file")
-}
-
 #[derive(PartialOrd, Ord, PartialEq, Eq, Debug, Clone)]
 enum Card {
     Joker,
@@ -19,6 +19,7 @@ enum Card {
     Eight,
     Nine,
     Ten,
+    Jack,
     Queen,
     King,
     Ace,
@@ -37,6 +38,7 @@ impl Display for Card {
             Card::Eight => write!(f, "8"),
             Card::Nine => write!(f, "9"),
             Card::Ten => write!(f, "T"),
+            Card::Jack => write!(f, "J"),
             Card::Queen => write!(f, "Q"),
             Card::King => write!(f, "K"),
             Card::Ace => write!(f, "A"),
@@ -71,7 +73,7 @@ impl TryFrom<char> for Card {
         } else if c == '2' {
             Ok(Card::Two)
         } else if c == 'J' {
-            Ok(Card::Joker)
+            Ok(Card::Jack)
         } else {
             Err(format!("Invalid card: {}", c))
         }
@@ -80,6 +82,23 @@ impl TryFrom<char> for Card {
     type Error = String;
 }
 
+impl Card {
+    /// In part 2, J cards are jokers instead of jacks
+    fn as_joker(&self) -> Card {
+        match self {
+            Card::Jack => Card::Joker,
+            card => card.clone(),
+        }
+    }
+}
+
+fn parse_cards(str_cards: &str) -> Vec<Card> {
+    str_cards
+        .chars()
+        .map(|c| Card::try_from(c).unwrap())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests_card {
     use super::*;
@@ -91,11 +110,12 @@ mod tests_card {
 
     #[test]
     fn card_try_from_ko() {
-        assert_eq!(Card::try_from('X').is_err(), true,);
+        assert!(Card::try_from('X').is_err(),);
     }
 }
 
-#[derive(Ord, PartialEq, Eq, Debug, Clone)]
+#[allow(clippy::enum_variant_names)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 enum Kind {
     HighCard,
     OnePair,
@@ -126,13 +146,14 @@ impl Display for Kind {
 
 impl From<&str> for Kind {
     fn from(str_cards: &str) -> Self {
-        let cards: Vec<Card> = str_cards
-            .chars()
-            .map(|c| Card::try_from(c).unwrap())
-            .collect();
+        Kind::from(parse_cards(str_cards).as_slice())
+    }
+}
 
+impl From<&[Card]> for Kind {
+    fn from(cards: &[Card]) -> Self {
         let sorted = {
-            let mut temp = cards.clone();
+            let mut temp = cards.to_vec();
             temp.sort();
             temp.reverse();
             temp
@@ -158,11 +179,6 @@ impl From<&str> for Kind {
             }
         };
 
-        if joker_count != 0 {
-            dbg!(&deduped);
-            print!("{} ", &joker_count);
-        }
-
         // Obviously the main card cannot be a Joker
         let most_copies = {
             if deduped[0].1 == &Card::Joker && deduped.len() > 1 {
@@ -175,10 +191,6 @@ impl From<&str> for Kind {
         // Using jokers to upgrade the highest kind (when applicable)
          + joker_count;
 
-        if joker_count != 0 {
-            dbg!(&most_copies);
-        }
-
         let kind: Kind = {
             if most_copies == 5 {
                 Kind::FiveOfAKind
@@ -195,14 +207,20 @@ impl From<&str> for Kind {
             } else if most_copies == 1 {
                 Kind::HighCard
             } else {
-                Err("Invalid kind").expect("Fatal Error")
+                panic!("{1}: {:?}", "Invalid kind", "Fatal Error")
             }
         };
 
         if joker_count != 0 {
-            println!("{}", &kind);
+            trace!(
+                "{:?} with {} jokers: {} copies, {}",
+                deduped,
+                joker_count,
+                most_copies,
+                kind
+            );
         }
-        return kind;
+        kind
     }
 }
 
@@ -244,10 +262,17 @@ mod tests_kind_from {
     fn detect_five_of_a_kind() {
         assert_eq!(Kind::from("33333"), Kind::FiveOfAKind);
     }
+
+    #[test]
+    fn detect_with_jokers() {
+        let cards: Vec<Card> = parse_cards("QJJQ2").iter().map(Card::as_joker).collect();
+        assert_eq!(Kind::from("QJJQ2"), Kind::TwoPairs);
+        assert_eq!(Kind::from(cards.as_slice()), Kind::FourOfAKind);
+    }
 }
 
-impl PartialOrd for Kind {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+impl Ord for Kind {
+    fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
             (Kind::OnePair, Kind::HighCard)
             | (Kind::TwoPairs, Kind::HighCard)
@@ -269,7 +294,7 @@ impl PartialOrd for Kind {
             | (Kind::FiveOfAKind, Kind::TwoPairs)
             | (Kind::FiveOfAKind, Kind::ThreeOfAKind)
             | (Kind::FiveOfAKind, Kind::FullHouse)
-            | (Kind::FiveOfAKind, Kind::FourOfAKind) => Some(Ordering::Greater),
+            | (Kind::FiveOfAKind, Kind::FourOfAKind) => Ordering::Greater,
             (Kind::HighCard, Kind::OnePair)
             | (Kind::HighCard, Kind::TwoPairs)
             | (Kind::OnePair, Kind::TwoPairs)
@@ -290,15 +315,21 @@ impl PartialOrd for Kind {
             | (Kind::TwoPairs, Kind::FiveOfAKind)
             | (Kind::ThreeOfAKind, Kind::FiveOfAKind)
             | (Kind::FullHouse, Kind::FiveOfAKind)
-            | (Kind::FourOfAKind, Kind::FiveOfAKind) => Some(Ordering::Less),
+            | (Kind::FourOfAKind, Kind::FiveOfAKind) => Ordering::Less,
             // Kind only deals on the…well "kind" level.
             // If two hands are of equal kind, comparisons will be done on
             // the Hand level
-            _ => Some(Ordering::Equal),
+            _ => Ordering::Equal,
         }
     }
 }
 
+impl PartialOrd for Kind {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[cfg(test)]
 mod tests_kind_partialord {
     use super::*;
@@ -328,42 +359,70 @@ mod tests_kind_partialord {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Ord)]
-struct Hand {
-    cards: String,
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Hand {
+    cards: Vec<Card>,
     kind: Kind,
     bid: u32,
 }
 
-impl PartialOrd for Hand {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        let mut ordering = self.kind.partial_cmp(&other.kind);
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut ordering = self.kind.cmp(&other.kind);
         match ordering {
-            Some(Ordering::Equal) => {
-                let mut self_chars = self.cards.chars().map(|c| Card::try_from(c).unwrap());
-                let mut other_chars = other.cards.chars().map(|c| Card::try_from(c).unwrap());
-                for _ in 0..5 {
-                    ordering = self_chars
-                        .next()
-                        .unwrap()
-                        .partial_cmp(&other_chars.next().unwrap());
-                    if ordering != Some(Ordering::Equal) {
+            Ordering::Equal => {
+                for (self_card, other_card) in self.cards.iter().zip(&other.cards) {
+                    ordering = self_card.cmp(other_card);
+                    if ordering != Ordering::Equal {
                         break;
                     }
                 }
-                return ordering;
+                ordering
             }
             _ => ordering,
         }
     }
 }
 
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Display for Hand {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {}, {})", self.cards, self.kind, self.bid)
+        write!(
+            f,
+            "({}, {}, {})",
+            self.cards.iter().join(""),
+            self.kind,
+            self.bid
+        )
     }
 }
 
+impl Hand {
+    fn new(cards: Vec<Card>, bid: u32) -> Hand {
+        Hand {
+            kind: Kind::from(cards.as_slice()),
+            cards,
+            bid,
+        }
+    }
+
+    fn with_jokers(&self) -> Hand {
+        Hand::new(self.cards.iter().map(Card::as_joker).collect(), self.bid)
+    }
+}
+
+fn get_total_winnings(mut hands: Vec<Hand>) -> u32 {
+    hands.sort();
+    hands.iter().enumerate().fold(0, |acc, (index, element)| {
+        acc + element.bid * (index as u32 + 1)
+    })
+}
+
 #[cfg(test)]
 mod tests_hand {
     use std::cmp::Ordering;
@@ -376,12 +435,12 @@ mod tests_hand {
     fn hand_partial_cmp() {
         assert_eq!(
             Hand {
-                cards: String::from("33332"),
+                cards: parse_cards("33332"),
                 kind: Kind::FourOfAKind,
                 bid: 0
             }
             .partial_cmp(&Hand {
-                cards: String::from("2AAAA"),
+                cards: parse_cards("2AAAA"),
                 kind: Kind::FourOfAKind,
                 bid: 0
             }),
@@ -390,24 +449,100 @@ mod tests_hand {
     }
 }
 
-fn main() {
-    let content = get_file_content(&String::from("assets/input"));
-
-    let mut hands: Vec<Hand> = vec![];
-    for str_hands in content.lines() {
-        let mut split = str_hands.split_whitespace();
-        let cards = split.next().unwrap();
-        hands.push(Hand {
-            cards: cards.to_string(),
-            kind: Kind::from(cards),
-            bid: split.next().unwrap().parse().unwrap_or(0),
-        });
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut hands: Vec<Hand> = vec![];
+        for (index, line) in input.lines().enumerate() {
+            let mut split = line.split_whitespace();
+            let str_cards = split
+                .next()
+                .ok_or_else(|| ParseError::missing(index, line, "cards"))?;
+            let cards = str_cards
+                .char_indices()
+                .map(|(offset, c)| {
+                    Card::try_from(c).map_err(|_| {
+                        ParseError::at(
+                            index,
+                            line,
+                            &str_cards[offset..offset + c.len_utf8()],
+                            "unknown card",
+                        )
+                    })
+                })
+                .collect::<Result<Vec<Card>, ParseError>>()?;
+            if cards.len() != 5 {
+                return Err(ParseError::at(index, line, str_cards, "expected 5 cards"));
+            }
+            let bid = split
+                .next()
+                .ok_or_else(|| ParseError::missing(index, line, "bid"))?;
+            hands.push(Hand::new(cards, parse_field(index, line, bid, "bid")?));
+        }
+        Ok(hands)
     }
 
-    hands.sort();
-    println!(
-        "\nTotal Winnings: {}",
-        hands.iter().enumerate().fold(0, |acc, (index, element)| acc
-            + element.bid * (index as u32 + 1))
-    );
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(get_total_winnings(input.to_vec()))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(get_total_winnings(
+            input.iter().map(Hand::with_jokers).collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests_day07 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day07_example() {
+        let input = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        assert_eq!(
+            solve::<Day07>(input, &[1, 2]),
+            Ok(vec![Some(String::from("6440")), Some(String::from("5905"))])
+        );
+    }
+
+    #[test]
+    fn day07_malformed() {
+        assert_eq!(
+            solve::<Day07>("32T3K 765\nKK6X7 28", &[1]),
+            Err(ParseError {
+                line: 2,
+                column: 4,
+                text: String::from("X"),
+                reason: String::from("unknown card"),
+            })
+        );
+        assert_eq!(
+            solve::<Day07>("32T3K 765\nKK677", &[1]),
+            Err(ParseError {
+                line: 2,
+                column: 6,
+                text: String::new(),
+                reason: String::from("missing bid"),
+            })
+        );
+        assert_eq!(
+            solve::<Day07>("32T3K 765\nKKK 28", &[1]),
+            Err(ParseError {
+                line: 2,
+                column: 1,
+                text: String::from("KKK"),
+                reason: String::from("expected 5 cards"),
+            })
+        );
+    }
 }
@@ -0,0 +1,546 @@
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_core::{
+    cycle::{find_cycle, Cycle},
+    debug,
+    dot::{Dot, Shape, ToDot},
+    info,
+    parse::ParseError,
+    solution::Solution,
+    trace,
+};
+use num::integer::{ExtendedGcd, Integer};
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Left => f.write_str("L"),
+            Direction::Right => f.write_str("R"),
+        }
+    }
+}
+
+fn parse_instructions(index: usize, line: &str) -> Result<Vec<Direction>, ParseError> {
+    line.char_indices()
+        .map(|(offset, c)| match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(ParseError::at(
+                index,
+                line,
+                &line[offset..offset + c.len_utf8()],
+                "unknown direction",
+            )),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_instructions {
+    use super::*;
+
+    #[test]
+    fn parse_instructions_trivial() {
+        assert_eq!(parse_instructions(0, "L"), Ok(vec![Direction::Left]));
+        assert_eq!(parse_instructions(0, "R"), Ok(vec![Direction::Right]));
+    }
+
+    #[test]
+    fn parse_instructions_real() {
+        assert_eq!(
+            parse_instructions(0, "LLRLR"),
+            Ok(vec![
+                Direction::Left,
+                Direction::Left,
+                Direction::Right,
+                Direction::Left,
+                Direction::Right,
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_instructions_malformed() {
+        assert_eq!(
+            parse_instructions(0, "LRU"),
+            Err(ParseError {
+                line: 1,
+                column: 3,
+                text: String::from("U"),
+                reason: String::from("unknown direction"),
+            })
+        );
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct Node {
+    left_side: String,
+    right_side: String,
+}
+
+pub struct Network {
+    instructions: Vec<Direction>,
+    nodes: HashMap<String, Node>,
+}
+
+/// A `AAA = (BBB, CCC)` line
+fn parse_node(index: usize, line: &str) -> Result<(String, Node), ParseError> {
+    let (name, links) = line
+        .split_once(" = ")
+        .ok_or_else(|| ParseError::missing(index, line, "` = ` after the node name"))?;
+    let (left_side, right_side) = links
+        .strip_prefix('(')
+        .and_then(|links| links.strip_suffix(')'))
+        .and_then(|links| links.split_once(", "))
+        .ok_or_else(|| ParseError::at(index, line, links, "expected `(<left>, <right>)`"))?;
+    Ok((
+        name.to_string(),
+        Node {
+            left_side: left_side.to_string(),
+            right_side: right_side.to_string(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests_parse_node {
+    use super::*;
+
+    #[test]
+    fn parse_node_01() {
+        assert_eq!(
+            parse_node(2, "AAA = (BBB, CCC)"),
+            Ok((
+                String::from("AAA"),
+                Node {
+                    left_side: String::from("BBB"),
+                    right_side: String::from("CCC")
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_node_malformed() {
+        assert_eq!(
+            parse_node(2, "AAA = (BBB CCC)"),
+            Err(ParseError {
+                line: 3,
+                column: 7,
+                text: String::from("(BBB CCC)"),
+                reason: String::from("expected `(<left>, <right>)`"),
+            })
+        );
+        assert_eq!(
+            parse_node(2, "AAA (BBB, CCC)"),
+            Err(ParseError::missing(
+                2,
+                "AAA (BBB, CCC)",
+                "` = ` after the node name"
+            ))
+        );
+    }
+}
+
+/// The instructions, then the nodes. Every instruction and link has to lead
+/// somewhere, so that walking the network never gets stuck.
+fn parse_network(content: &str) -> Result<Network, ParseError> {
+    let mut lines = content.lines().enumerate();
+    let first_line = lines.next().map_or("", |(_, line)| line);
+    let instructions = parse_instructions(0, first_line)?;
+    if instructions.is_empty() {
+        return Err(ParseError::missing(0, first_line, "instructions"));
+    }
+
+    let node_lines: Vec<(usize, &str)> = lines.filter(|(_, line)| !line.is_empty()).collect();
+    let mut nodes = HashMap::new();
+    for (index, line) in &node_lines {
+        let (name, node) = parse_node(*index, line)?;
+        if nodes.contains_key(&name) {
+            return Err(ParseError::at(*index, line, &name, "node defined twice"));
+        }
+        nodes.insert(name, node);
+    }
+
+    for (index, line) in &node_lines {
+        // Links are looked for after the node name, which may be one of them
+        let (_, links) = line.split_once(" = ").unwrap();
+        let (left_side, right_side) = links.split_once(", ").unwrap();
+        for link in [&left_side[1..], &right_side[..right_side.len() - 1]] {
+            if !nodes.contains_key(link) {
+                return Err(ParseError::at(
+                    *index,
+                    line,
+                    link,
+                    "link to an undefined node",
+                ));
+            }
+        }
+    }
+
+    Ok(Network {
+        instructions,
+        nodes,
+    })
+}
+
+#[cfg(test)]
+mod tests_parse_network {
+    use super::*;
+
+    #[test]
+    fn parse_network_malformed() {
+        assert_eq!(
+            parse_network("LR\n\n11A = (11B, XXX)\n11B = (11A, 11A)\n").err(),
+            Some(ParseError {
+                line: 3,
+                column: 13,
+                text: String::from("XXX"),
+                reason: String::from("link to an undefined node"),
+            })
+        );
+        assert_eq!(
+            parse_network("\n\nAAA = (AAA, AAA)").err(),
+            Some(ParseError::missing(0, "", "instructions"))
+        );
+        assert_eq!(
+            parse_network("L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)")
+                .err()
+                .map(|error| (error.line, error.reason)),
+            Some((4, String::from("node defined twice")))
+        );
+    }
+}
+
+impl Network {
+    /// The node reached by following the given instruction from a node
+    fn follow(&self, node: &Node, instruction: &Direction) -> (&String, &Node) {
+        self.nodes
+            .get_key_value(match instruction {
+                Direction::Left => &node.left_side,
+                Direction::Right => &node.right_side,
+            })
+            .unwrap()
+    }
+}
+
+/// The walk of a ghost: after `cycle.start` steps, it goes around the same
+/// (node, instruction index) states every `cycle.length` steps
+struct Ghost {
+    cycle: Cycle<(String, usize)>,
+    /// Every step, up to the end of the first time around, at which the ghost
+    /// stands on an arrival node
+    arrivals: Vec<usize>,
+}
+
+fn is_arrival(name: &str) -> bool {
+    name.ends_with('Z')
+}
+
+impl Network {
+    /// Walk from a node until the walk loops
+    fn walk(&self, from: &str) -> Ghost {
+        let cycle = find_cycle((from.to_string(), 0), |(name, index)| {
+            let (next, _) = self.follow(&self.nodes[name], &self.instructions[*index]);
+            (next.clone(), (index + 1) % self.instructions.len())
+        });
+        let arrivals = (0..cycle.start + cycle.length)
+            .filter(|step| is_arrival(&cycle.nth(*step).0))
+            .collect();
+        Ghost { cycle, arrivals }
+    }
+}
+
+/// Combine `step ≡ r1 (mod m1)` and `step ≡ r2 (mod m2)` into a single
+/// congruence, if both can hold at once (the moduli need not be coprime)
+fn combine((r1, m1): (i128, i128), (r2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let ExtendedGcd { gcd, x, .. } = m1.extended_gcd(&m2);
+    if (r2 - r1) % gcd != 0 {
+        return None;
+    }
+    let modulus = m1 / gcd * m2;
+    // r1 + m1 * k ≡ r2 (mod m2), with x the inverse of m1 / gcd modulo m2 / gcd
+    let k = ((r2 - r1) / gcd * x).rem_euclid(m2 / gcd);
+    Some(((r1 + m1 * k).rem_euclid(modulus), modulus))
+}
+
+#[cfg(test)]
+mod tests_combine {
+    use super::*;
+
+    #[test]
+    fn combine_coprime() {
+        assert_eq!(combine((2, 3), (3, 5)), Some((8, 15)));
+    }
+
+    #[test]
+    fn combine_not_coprime() {
+        assert_eq!(combine((2, 4), (0, 6)), Some((6, 12)));
+        assert_eq!(combine((1, 4), (0, 6)), None);
+    }
+}
+
+/// The first step at which every ghost stands on an arrival node at once
+fn get_arrival_step(ghosts: &[Ghost]) -> Option<usize> {
+    // Until every ghost walks in its loop, each step is looked at
+    let in_loops = ghosts.iter().map(|ghost| ghost.cycle.start).max()?;
+    if let Some(step) = (0..in_loops).find(|step| {
+        ghosts
+            .iter()
+            .all(|ghost| is_arrival(&ghost.cycle.nth(*step).0))
+    }) {
+        return Some(step);
+    }
+
+    // Then a ghost arrives whenever it goes through one of the arrivals of its
+    // loop again
+    let mut congruences = vec![(0, 1)];
+    for ghost in ghosts {
+        let length = ghost.cycle.length as i128;
+        let residues: Vec<i128> = ghost
+            .arrivals
+            .iter()
+            .filter(|step| **step >= ghost.cycle.start)
+            .map(|step| *step as i128 % length)
+            .collect();
+        congruences = congruences
+            .iter()
+            .flat_map(|congruence| {
+                residues
+                    .iter()
+                    .filter_map(|residue| combine(*congruence, (*residue, length)))
+            })
+            .collect();
+        congruences.sort();
+        congruences.dedup();
+    }
+    debug!("Arrival congruences: {:?}", congruences);
+
+    let in_loops = in_loops as i128;
+    congruences
+        .into_iter()
+        .map(|(residue, modulus)| {
+            // The first step from `in_loops` on
+            residue + Integer::div_ceil(&(in_loops - residue).max(0), &modulus) * modulus
+        })
+        .min()
+        .map(|step| step as usize)
+}
+
+/// Whether the least common multiple of the loop lengths is the answer: it is
+/// when each ghost only arrives once per loop, at its very end
+fn is_lcm_enough(ghosts: &[Ghost]) -> bool {
+    ghosts
+        .iter()
+        .all(|ghost| ghost.arrivals == [ghost.cycle.length])
+}
+
+/// Starting nodes (ending with `A`) are boxes and arrival nodes (ending with
+/// `Z`) double circles
+impl ToDot for Network {
+    fn to_dot(&self) -> Dot {
+        let mut dot = Dot::digraph("network");
+        for (name, node) in &self.nodes {
+            if name.ends_with('A') {
+                dot.node(name, Shape::Box);
+            } else if name.ends_with('Z') {
+                dot.node(name, Shape::DoubleCircle);
+            }
+            dot.labeled_edge(name, &node.left_side, Direction::Left)
+                .labeled_edge(name, &node.right_side, Direction::Right);
+        }
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests_to_dot {
+    use super::*;
+
+    #[test]
+    fn to_dot_01() {
+        let network = parse_network(
+            "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)",
+        )
+        .unwrap();
+        assert_eq!(
+            network.to_dot().to_string(),
+            r#"digraph "network" {
+    "AAA" [shape=box];
+    "BBB" [shape=ellipse];
+    "ZZZ" [shape=doublecircle];
+    "AAA" -> "BBB" [label="L"];
+    "AAA" -> "BBB" [label="R"];
+    "BBB" -> "AAA" [label="L"];
+    "BBB" -> "ZZZ" [label="R"];
+    "ZZZ" -> "ZZZ" [label="L"];
+    "ZZZ" -> "ZZZ" [label="R"];
+}
+"#
+        );
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_network(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        let mut steps = 0;
+        let mut state = input.nodes.get_key_value("AAA")?;
+        // Past as many steps as there are (node, instruction) states, the walk
+        // goes around in circles
+        let states = input.nodes.len() * input.instructions.len();
+        for instruction in input.instructions.iter().cycle() {
+            if state.0 == "ZZZ" {
+                break;
+            }
+            if steps > states {
+                info!("ZZZ cannot be reached from AAA");
+                return None;
+            }
+            trace!(
+                "Step:{:0>5} Instruction:{} State:{} = ({},{})",
+                steps,
+                instruction,
+                state.0,
+                state.1.left_side,
+                state.1.right_side
+            );
+            steps += 1;
+            state = input.follow(state.1, instruction);
+        }
+        Some(steps)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let mut starting_nodes: Vec<&String> =
+            input.nodes.keys().filter(|k| k.ends_with('A')).collect();
+        starting_nodes.sort();
+        debug!(
+            "{} starting nodes: {:?}",
+            starting_nodes.len(),
+            starting_nodes
+        );
+
+        let ghosts: Vec<Ghost> = starting_nodes.iter().map(|name| input.walk(name)).collect();
+        for (name, ghost) in starting_nodes.iter().zip(&ghosts) {
+            debug!(
+                "{}: loops every {} steps from step {}, arrives at steps {:?}",
+                name, ghost.cycle.length, ghost.cycle.start, ghost.arrivals
+            );
+        }
+        if !is_lcm_enough(&ghosts) {
+            info!("The LCM of the loop lengths is not the answer for this input");
+        }
+
+        get_arrival_step(&ghosts)
+    }
+
+    fn dot(input: &Self::Input) -> Option<Dot> {
+        Some(input.to_dot())
+    }
+}
+
+#[cfg(test)]
+mod tests_day08 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day08_example_part1() {
+        let input = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(
+            solve::<Day08>(input, &[1]),
+            Ok(vec![Some(String::from("6"))])
+        );
+    }
+
+    #[test]
+    fn day08_part1_unreachable() {
+        let input = "\
+L
+
+AAA = (BBB, ZZZ)
+BBB = (AAA, AAA)
+ZZZ = (ZZZ, ZZZ)";
+        assert_eq!(solve::<Day08>(input, &[1]), Ok(vec![None]));
+    }
+
+    #[test]
+    fn day08_example_part2() {
+        let input = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+        assert_eq!(
+            solve::<Day08>(input, &[2]),
+            Ok(vec![Some(String::from("6"))])
+        );
+    }
+
+    #[test]
+    fn day08_part2_lcm_not_enough() {
+        // 11A loops over 11B and 11Z, arriving every even step, and 22A over
+        // 22Z, 22B and 22C, arriving at steps 1, 4, 7… : the LCM (6) is wrong
+        let input = "\
+L
+
+11A = (11B, XXX)
+11B = (11Z, XXX)
+11Z = (11B, XXX)
+22A = (22Z, XXX)
+22Z = (22B, XXX)
+22B = (22C, XXX)
+22C = (22Z, XXX)
+XXX = (XXX, XXX)";
+        let network = parse_network(input).unwrap();
+        let ghosts = vec![network.walk("11A"), network.walk("22A")];
+        assert!(!is_lcm_enough(&ghosts));
+        assert_eq!(get_arrival_step(&ghosts), Some(4));
+    }
+
+    #[test]
+    fn day08_part2_never() {
+        let input = "\
+L
+
+11A = (11Z, XXX)
+11Z = (11B, XXX)
+11B = (11Z, XXX)
+22A = (22B, XXX)
+22B = (22Z, XXX)
+22Z = (22B, XXX)
+XXX = (XXX, XXX)";
+        assert_eq!(solve::<Day08>(input, &[2]), Ok(vec![None]));
+    }
+}
@@ -0,0 +1,225 @@
+use std::fmt::Display;
+use std::vec;
+
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+};
+
+fn parse_line(index: usize, line: &str) -> Result<Vec<i32>, ParseError> {
+    let mut out = vec![];
+    for block in line.split_whitespace() {
+        out.push(parse_field(index, line, block, "value")?);
+    }
+    if out.is_empty() {
+        return Err(ParseError::missing(index, line, "values"));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests_parse_line {
+    use super::*;
+
+    #[test]
+    fn parse_line_01() {
+        assert_eq!(
+            parse_line(0, &String::from("0 -3 6 9 12 15")),
+            Ok(vec![0, -3, 6, 9, 12, 15])
+        );
+    }
+
+    #[test]
+    fn parse_line_malformed() {
+        assert_eq!(
+            parse_line(4, "0 3 six"),
+            Err(ParseError {
+                line: 5,
+                column: 5,
+                text: String::from("six"),
+                reason: String::from("invalid value: invalid digit found in string"),
+            })
+        );
+        assert_eq!(parse_line(4, ""), Err(ParseError::missing(4, "", "values")));
+    }
+}
+
+fn get_diff_list(input: Vec<i32>) -> Vec<Vec<i32>> {
+    let mut tmp = input.clone();
+    let mut out = vec![];
+    out.push(tmp.clone());
+    let mut acc: i32 = 0;
+    while tmp.clone().iter().filter(|e| **e != 0).count() > 0 {
+        let mut inner_tmp = vec![];
+        for (index, element) in tmp.iter().enumerate() {
+            if index > 0 {
+                inner_tmp.push(element - acc);
+            }
+            acc = *element;
+        }
+        out.push(inner_tmp.clone());
+        tmp = inner_tmp;
+        acc = 0;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests_get_diff_list {
+    use super::*;
+
+    #[test]
+    fn get_diff_list_01() {
+        assert_eq!(
+            get_diff_list(vec![0, 3, 6, 9, 12, 15]),
+            vec![
+                vec![0, 3, 6, 9, 12, 15],
+                vec![3, 3, 3, 3, 3],
+                vec![0, 0, 0, 0]
+            ]
+        );
+    }
+    #[test]
+    fn get_diff_list_02() {
+        assert_eq!(
+            get_diff_list(vec![10, 13, 16, 21, 30, 45, 68]),
+            vec![
+                vec![10, 13, 16, 21, 30, 45, 68],
+                vec![3, 3, 5, 9, 15, 23],
+                vec![0, 2, 4, 6, 8],
+                vec![2, 2, 2, 2],
+                vec![0, 0, 0]
+            ]
+        );
+    }
+}
+
+fn predict_next_number(diff_list: Vec<Vec<i32>>) -> i32 {
+    diff_list
+        .iter()
+        .rev()
+        // A history which never settles to zeros runs out of differences
+        .fold(0, |acc, list| list.last().copied().unwrap_or(0) + acc)
+}
+
+#[cfg(test)]
+mod tests_predict_next_number {
+    use super::*;
+
+    #[test]
+    fn predict_next_number_01() {
+        assert_eq!(
+            predict_next_number(vec![
+                vec![0, 3, 6, 9, 12, 15],
+                vec![3, 3, 3, 3, 3],
+                vec![0, 0, 0, 0]
+            ]),
+            18
+        );
+    }
+
+    #[test]
+    fn predict_next_number_02() {
+        assert_eq!(
+            predict_next_number(vec![
+                vec![10, 13, 16, 21, 30, 45],
+                vec![3, 3, 5, 9, 15],
+                vec![0, 2, 4, 6],
+                vec![2, 2, 2],
+                vec![0, 0]
+            ]),
+            68
+        );
+    }
+
+    #[test]
+    fn predict_next_number_unsettled() {
+        assert_eq!(predict_next_number(get_diff_list(vec![1, 5])), 9);
+    }
+}
+
+fn predict_previous_number(diff_list: Vec<Vec<i32>>) -> i32 {
+    diff_list
+        .iter()
+        .rev()
+        .fold(0, |acc, list| list.first().copied().unwrap_or(0) - acc)
+}
+
+#[cfg(test)]
+mod tests_predict_previous_number {
+    use super::*;
+
+    #[test]
+    fn predict_previous_number_01() {
+        assert_eq!(
+            predict_previous_number(vec![
+                vec![0, 3, 6, 9, 12, 15],
+                vec![3, 3, 3, 3, 3],
+                vec![0, 0, 0, 0]
+            ]),
+            -3
+        );
+    }
+
+    #[test]
+    fn predict_previous_number_02() {
+        assert_eq!(
+            predict_previous_number(vec![
+                vec![10, 13, 16, 21, 30, 45],
+                vec![3, 3, 5, 9, 15],
+                vec![0, 2, 4, 6],
+                vec![2, 2, 2],
+                vec![0, 0]
+            ]),
+            5
+        );
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse_line(index, line))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(
+            input
+                .iter()
+                .cloned()
+                .map(get_diff_list)
+                .fold(0, |acc, line| acc + predict_next_number(line)),
+        )
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(
+            input
+                .iter()
+                .cloned()
+                .map(get_diff_list)
+                .fold(0, |acc, line| acc + predict_previous_number(line)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests_day09 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day09_example() {
+        assert_eq!(
+            solve::<Day09>("0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45", &[1, 2]),
+            Ok(vec![Some(String::from("114")), Some(String::from("2"))])
+        );
+    }
+}
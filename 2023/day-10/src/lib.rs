@@ -0,0 +1,395 @@
+use std::fmt::Display;
+
+use aoc_core::grid::Grid;
+use aoc_core::{debug, parse::ParseError, solution::Solution, trace};
+use colored::Colorize;
+use geo::{point, Contains, LineString, Polygon};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+enum PipeType {
+    NorthSouth,
+    EastWest,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    StartingPosition,
+}
+
+impl Display for PipeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PipeType::NorthSouth => write!(f, "│"),
+            PipeType::EastWest => write!(f, "─"),
+            PipeType::NorthEast => write!(f, "└"),
+            PipeType::NorthWest => write!(f, "┘"),
+            PipeType::SouthWest => write!(f, "┐"),
+            PipeType::SouthEast => write!(f, "┌"),
+            PipeType::Ground => write!(f, "."),
+            PipeType::StartingPosition => write!(f, "S"),
+        }
+    }
+}
+
+impl TryFrom<char> for PipeType {
+    type Error = ();
+
+    fn try_from(char: char) -> Result<Self, Self::Error> {
+        match char {
+            '|' => Ok(PipeType::NorthSouth),
+            '-' => Ok(PipeType::EastWest),
+            'L' => Ok(PipeType::NorthEast),
+            'J' => Ok(PipeType::NorthWest),
+            '7' => Ok(PipeType::SouthWest),
+            'F' => Ok(PipeType::SouthEast),
+            '.' => Ok(PipeType::Ground),
+            'S' => Ok(PipeType::StartingPosition),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_pipe_type_try_from {
+    use super::*;
+
+    #[test]
+    fn pipe_type_try_from_01() {
+        assert_eq!(PipeType::try_from('|'), Ok(PipeType::NorthSouth));
+    }
+
+    #[test]
+    fn pipe_type_try_from_02() {
+        assert_eq!(PipeType::try_from('-'), Ok(PipeType::EastWest));
+    }
+
+    #[test]
+    fn pipe_type_try_from_03() {
+        assert_eq!(PipeType::try_from('L'), Ok(PipeType::NorthEast));
+    }
+
+    #[test]
+    fn pipe_type_try_from_04() {
+        assert_eq!(PipeType::try_from('J'), Ok(PipeType::NorthWest));
+    }
+
+    #[test]
+    fn pipe_type_try_from_05() {
+        assert_eq!(PipeType::try_from('7'), Ok(PipeType::SouthWest));
+    }
+
+    #[test]
+    fn pipe_type_try_from_06() {
+        assert_eq!(PipeType::try_from('F'), Ok(PipeType::SouthEast));
+    }
+
+    #[test]
+    fn pipe_type_try_from_07() {
+        assert_eq!(PipeType::try_from('.'), Ok(PipeType::Ground));
+    }
+
+    #[test]
+    fn pipe_type_try_from_08() {
+        assert_eq!(PipeType::try_from('S'), Ok(PipeType::StartingPosition));
+    }
+
+    #[test]
+    fn pipe_type_try_from_09() {
+        assert_eq!(PipeType::try_from('X'), Err(()));
+    }
+}
+
+type Coordinates = (usize, usize);
+type Map = Grid<PipeType>;
+
+fn get_next_step(current: &Coordinates, coming_from: &Coordinates, map: &Map) -> Coordinates {
+    match &map[*current] {
+        PipeType::NorthSouth => {
+            if (current.1 as i32) - (coming_from.1 as i32) > 0_i32 {
+                (current.0, current.1 + 1)
+            } else {
+                (current.0, current.1 - 1)
+            }
+        }
+        PipeType::EastWest => {
+            if (current.0 as i32) - (coming_from.0 as i32) > 0_i32 {
+                (current.0 + 1, current.1)
+            } else {
+                (current.0 - 1, current.1)
+            }
+        }
+        PipeType::NorthEast => {
+            if (current.1 as i32) - (coming_from.1 as i32) > 0_i32 {
+                (current.0 + 1, current.1)
+            } else {
+                (current.0, current.1 - 1)
+            }
+        }
+        PipeType::NorthWest => {
+            if (current.1 as i32) - (coming_from.1 as i32) > 0_i32 {
+                (current.0 - 1, current.1)
+            } else {
+                (current.0, current.1 - 1)
+            }
+        }
+        PipeType::SouthWest => {
+            if (current.1 as i32) - (coming_from.1 as i32) < 0_i32 {
+                (current.0 - 1, current.1)
+            } else {
+                (current.0, current.1 + 1)
+            }
+        }
+        PipeType::SouthEast => {
+            if (current.1 as i32) - (coming_from.1 as i32) < 0_i32 {
+                (current.0 + 1, current.1)
+            } else {
+                (current.0, current.1 + 1)
+            }
+        }
+        PipeType::Ground => panic!("{1}: {:?}", "Ground encountered", "Fatal Error"),
+        PipeType::StartingPosition => *current,
+    }
+}
+
+#[cfg(test)]
+mod tests_get_next_step {
+    use super::*;
+
+    #[test]
+    fn get_next_step_01() {
+        assert_eq!(
+            get_next_step(&(1, 0), &(0, 0), &"---".parse().unwrap()),
+            (2, 0)
+        );
+    }
+}
+
+pub struct Pipes {
+    map: Map,
+    starting_position: Coordinates,
+}
+
+fn parse_pipes(content: &str) -> Result<Pipes, ParseError> {
+    let map: Map = content.parse()?;
+    let starting_position = map
+        .position(|pipe| *pipe == PipeType::StartingPosition)
+        .ok_or_else(|| {
+            ParseError::line(
+                0,
+                content.lines().next().unwrap_or_default(),
+                "no starting position `S` in the map",
+            )
+        })?;
+
+    Ok(Pipes {
+        map,
+        starting_position: (starting_position.x, starting_position.y),
+    })
+}
+
+#[cfg(test)]
+mod tests_parse_pipes {
+    use super::*;
+
+    #[test]
+    fn parse_pipes_01() {
+        let pipes = parse_pipes("|-J.\n7FSL").unwrap();
+
+        assert_eq!(pipes.map.width(), 4);
+        assert_eq!(pipes.map.height(), 2);
+        assert_eq!(pipes.map[(2, 0)], PipeType::NorthWest);
+        assert_eq!(pipes.map[(3, 1)], PipeType::NorthEast);
+        assert_eq!(pipes.starting_position, (2, 1));
+    }
+
+    #[test]
+    fn parse_pipes_02() {
+        assert!(parse_pipes("|-J.\n7F.L").is_err());
+        assert!(parse_pipes("|-J.\n7XSL").is_err());
+    }
+}
+
+/// Follow the loop from the starting position, once replaced by the given pipe type
+/// and coming from `prev`, until getting back to it.
+///
+/// Returns every visited tile, the starting position being both first and last.
+fn walk_loop(
+    map: &Map,
+    starting_position: Coordinates,
+    starting_pipe: PipeType,
+    prev: Coordinates,
+) -> Vec<Coordinates> {
+    let mut updated_map = map.clone();
+    updated_map[starting_position] = starting_pipe;
+
+    let mut tmp_prev = prev;
+    let mut stash = starting_position;
+    let mut tmp_current = get_next_step(&starting_position, &tmp_prev, &updated_map);
+    tmp_prev = stash;
+
+    let mut path = vec![tmp_prev, tmp_current];
+    loop {
+        stash = tmp_current;
+        tmp_current = get_next_step(&tmp_current, &tmp_prev, &updated_map);
+        path.push(tmp_current);
+        tmp_prev = stash;
+        if tmp_current == starting_position {
+            break;
+        }
+    }
+    path
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Pipes;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_pipes(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        let (x, y) = input.starting_position;
+        Some(
+            [
+                (PipeType::NorthSouth, (x, y + 1)),
+                (PipeType::EastWest, (x + 1, y)),
+                (PipeType::NorthEast, (x, y - 1)),
+                (PipeType::NorthWest, (x, y - 1)),
+                (PipeType::SouthEast, (x, y + 1)),
+                (PipeType::SouthWest, (x, y + 1)),
+            ]
+            .into_iter()
+            .map(|(starting_pipe, prev)| {
+                walk_loop(&input.map, input.starting_position, starting_pipe, prev).len() - 1
+            })
+            .max()
+            .unwrap()
+                // We don't want the total path length, but only the steps required to go to
+                // the furthermost tile, hence half the total path (since beginning = end
+                // = starting_position)
+                / 2,
+        )
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let mut map = input.map.clone();
+        let starting_position = input.starting_position;
+
+        // We know from part 1 that N-S gives the right number of steps
+        let path = walk_loop(
+            &map,
+            starting_position,
+            PipeType::NorthWest,
+            (starting_position.0, starting_position.1 + 1),
+        );
+        debug!("Furthest tile: {}", (path.len() - 1) / 2);
+
+        let line_string_vec: Vec<(f32, f32)> =
+            path.iter().map(|(x, y)| (*x as f32, *y as f32)).collect();
+
+        let line_string: LineString<f32> = line_string_vec.clone().into();
+        let polygon = Polygon::new(line_string, vec![]);
+
+        // Get rid of StartingPosition for consistency
+        map[starting_position] = PipeType::NorthWest;
+
+        let mut inside_loop = 0;
+        let mut is_inside = false;
+        for y in 0..map.height() {
+            let mut row = String::new();
+            for x in 0..map.width() {
+                let mut printed = false;
+                let pt = &map[(x, y)];
+
+                // Tests triggered when ON THE BOUNDARY
+                if line_string_vec.contains(&(x as f32, y as f32)) {
+                    match pt {
+                        &PipeType::NorthSouth => {
+                            is_inside = !is_inside;
+                            row.push_str(&"|".bright_yellow().bold().to_string());
+                            printed = true;
+                        }
+
+                        PipeType::NorthEast => {
+                            row.push_str(&"└".bright_yellow().bold().to_string());
+                            printed = true;
+                            // In the case of NE we have to check
+                            // all the following chars to ensure that we are not
+                            // in a "pseudo NS". Indeed └-----┐ is
+                            // the same as | for our search purposes
+                            let mut inner_x = x + 1;
+                            while map.get((inner_x, y)) == Some(&PipeType::EastWest) {
+                                inner_x += 1;
+                            }
+                            // Coming out of this we have to cases:
+                            // └-----┐ -> We have a pseudo "|" and we should flip is_inside
+                            // or
+                            // └-----┘ -> We have a pseudo "-" and we should NOT flip is_inside
+                            // Any other case we have a consistency error
+                            match map.get((inner_x, y)) {
+                                Some(PipeType::SouthWest) => {
+                                    is_inside = !is_inside;
+                                }
+                                Some(PipeType::NorthWest) => {}
+                                a => {
+                                    panic!("NE Consistency error: {:?}", a)
+                                }
+                            }
+                        }
+
+                        PipeType::SouthEast => {
+                            row.push_str(&"┌".bright_yellow().bold().to_string());
+                            printed = true;
+                            // In the case of SE we have to check
+                            // all the following chars to ensure that we are not
+                            // in a "pseudo NS". Indeed ┌-----┘ is
+                            // the same as | for our search purposes
+                            let mut inner_x = x + 1;
+                            while map.get((inner_x, y)) == Some(&PipeType::EastWest) {
+                                inner_x += 1;
+                            }
+                            // Coming out of this we have to cases:
+                            // ┌-----┘ -> We have a pseudo "|" and we should flip is_inside
+                            // or
+                            // ┌-----┐ -> We have a pseudo "-" and we should NOT flip is_inside
+                            // Any other case we have a consistency error
+                            match map.get((inner_x, y)) {
+                                Some(PipeType::SouthWest) => {}
+                                Some(PipeType::NorthWest) => {
+                                    is_inside = !is_inside;
+                                }
+                                a => {
+                                    panic!("SE Consistency error: {:?}", a)
+                                }
+                            }
+                        }
+                        _ => {
+                            row.push_str(&format!("{}", pt).bold().blue().to_string());
+                            printed = true;
+                        }
+                    }
+                }
+
+                // Tests triggered when INSIDE THE BOUNDARY
+                if polygon.contains(&point! {x: x as f32, y: y as f32}) {
+                    if is_inside {
+                        inside_loop += 1;
+                        row.push_str(&"I".red().to_string());
+                        printed = true;
+                    } else {
+                        row.push_str(&"O".blue().to_string());
+                        printed = true;
+                    }
+                }
+                if !printed {
+                    row.push_str(&pt.to_string());
+                }
+            }
+            trace!("{}", row);
+        }
+        Some(inside_loop)
+    }
+}
@@ -0,0 +1,349 @@
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_core::{coords, debug, display, trace};
+use aoc_core::{parse::ParseError, solution::Solution};
+use itertools::Itertools;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub enum TileType {
+    Galaxy(u32),
+}
+
+fn parse_char(char: char, galaxy_count: u32) -> Option<TileType> {
+    match char {
+        '#' => Some(TileType::Galaxy(galaxy_count + 1)),
+        _ => None,
+    }
+}
+
+impl Display for TileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            // Galaxy count goes wayyyy over 10 (even over 100…)
+            TileType::Galaxy(_) => write!(f, "#"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_parse_char {
+    use super::*;
+
+    #[test]
+    fn parse_char_01() {
+        assert_eq!(parse_char('#', 32), Some(TileType::Galaxy(33)));
+    }
+
+    #[test]
+    fn parse_char_02() {
+        assert_eq!(parse_char('X', 2), None);
+    }
+}
+
+type Coordinates = (u32, u32);
+type Map = HashMap<Coordinates, TileType>;
+
+/// Parse a given line at given height into a given Map
+///
+/// Return the count of new galaxies added to update the general counter
+fn parse_line(line: &str, height: &u32, hm: &mut Map, galaxy_count: u32) -> u32 {
+    let mut tmp_galaxy_count = galaxy_count;
+    line.chars()
+        .map(|c| {
+            let new_tile = parse_char(c, tmp_galaxy_count);
+            if let Some(TileType::Galaxy(_)) = new_tile {
+                tmp_galaxy_count += 1;
+            }
+            new_tile
+        })
+        .enumerate()
+        .for_each(|(i, p)| {
+            if let Some(thing) = p {
+                hm.insert((i as u32, *height), thing);
+            }
+        });
+    tmp_galaxy_count
+}
+
+#[cfg(test)]
+mod tests_parse_line {
+    use super::*;
+
+    #[test]
+    fn tests_parse_line_01() {
+        let mut hm: Map = HashMap::new();
+        let result = parse_line("..#.", &13, &mut hm, 3);
+
+        assert_eq!(
+            hm,
+            HashMap::from_iter(vec![((2, 13), TileType::Galaxy(4)),])
+        );
+        assert_eq!(result, 4);
+    }
+}
+
+fn get_bounding_rect(map: &Map) -> (u32, u32) {
+    let end = coords::get_bounding_rect(map.keys().cloned()).end();
+    (end.x, end.y)
+}
+
+/// Used for troubleshooting
+#[allow(dead_code)]
+fn display_map(map: &Map) {
+    display::display_map(
+        &coords::get_bounding_rect(map.keys().cloned()),
+        |c| match map.get(&(c.x, c.y)) {
+            Some(g) => format!("{}", g),
+            None => ".".to_string(),
+        },
+    );
+}
+
+/// How far a coordinate moves once every empty line (or column) before it has grown
+fn get_expanse(coordinate: u32, empty: &[u32], count_expanse: u32) -> u32 {
+    empty.iter().filter(|e| **e < coordinate).count() as u32 * count_expanse
+}
+
+// Galaxies are moved all at once, so that a moved galaxy never lands on
+// one that has not moved yet
+fn expand_height(map: &mut Map, empty_lines: &[u32], count_expanse: u32) {
+    *map = map
+        .drain()
+        .map(|((x, y), value)| ((x, y + get_expanse(y, empty_lines, count_expanse)), value))
+        .collect();
+}
+
+#[cfg(test)]
+mod tests_expand_height {
+    use super::*;
+
+    #[test]
+    fn expand_height_01() {
+        let input = "#...\n....\n.#..\n...#\n";
+
+        let mut hm: Map = {
+            let mut tmp = HashMap::new();
+            let mut galaxy_count: u32 = 0;
+            for (index, line) in input.lines().enumerate() {
+                galaxy_count = parse_line(line, &(index as u32), &mut tmp, galaxy_count);
+            }
+            tmp
+        };
+
+        display_map(&hm);
+        dbg!(&hm);
+
+        expand_height(&mut hm, &[1_u32], 2);
+
+        display_map(&hm);
+
+        dbg!(&hm);
+        assert_eq!(hm.len(), 3);
+    }
+
+    #[test]
+    fn expand_height_02() {
+        let input = "..#...\n......\n.#....\n.#....\n......\n.....\n....#.\n.....#\n";
+
+        let mut hm: Map = {
+            let mut tmp = HashMap::new();
+            let mut galaxy_count: u32 = 0;
+            for (index, line) in input.lines().enumerate() {
+                galaxy_count = parse_line(line, &(index as u32), &mut tmp, galaxy_count);
+            }
+            tmp
+        };
+
+        dbg!(&hm);
+
+        expand_height(&mut hm, &[1_u32, 4_u32, 5_u32], 100);
+
+        dbg!(&hm);
+
+        assert_eq!(hm.len(), 5);
+    }
+}
+
+fn expand_width(map: &mut Map, empty_columns: &[u32], count_expanse: u32) {
+    *map = map
+        .drain()
+        .map(|((x, y), value)| ((x + get_expanse(x, empty_columns, count_expanse), y), value))
+        .collect();
+}
+
+fn calc_path((x1, y1): &(u64, u64), (x2, y2): &(u64, u64)) -> u64 {
+    ((*y2 as i64 - *y1 as i64).abs() + (*x2 as i64 - *x1 as i64).abs()) as u64
+}
+
+#[cfg(test)]
+mod tests_calc_path {
+    use super::*;
+
+    #[test]
+    fn calc_path_01() {
+        assert_eq!(calc_path(&(0_u64, 0_u64), &(1_u64, 1_u64)), 2);
+    }
+
+    #[test]
+    fn calc_path_02() {
+        assert_eq!(calc_path(&(1_u64, 1_u64), &(0_u64, 0_u64)), 2);
+    }
+
+    #[test]
+    fn calc_path_03() {
+        assert_eq!(calc_path(&(1_u64, 6_u64), &(5_u64, 11_u64)), 9);
+    }
+
+    #[test]
+    fn calc_path_04() {
+        assert_eq!(calc_path(&(5_u64, 11_u64), &(1_u64, 6_u64)), 9);
+    }
+}
+
+/// Expand every empty line and column by `count_expanse`, then sum the shortest
+/// paths between every pair of galaxies
+fn get_sum_of_shortest_paths(galaxies: &Map, count_expanse: u32) -> u64 {
+    if galaxies.is_empty() {
+        return 0;
+    }
+    let mut map = galaxies.clone();
+
+    let empty_lines: Vec<u32> = {
+        let mut tmp = vec![];
+        let br = get_bounding_rect(&map);
+        // Horizontal scan, for drawing and empty lines registry
+        for y in 0..=br.1 {
+            let mut to_add = true;
+            for x in 0..=br.0 {
+                if let Some(TileType::Galaxy(_)) = map.get(&(x, y)) {
+                    to_add = false;
+                    // No need to keep going, here
+                    break;
+                }
+            }
+            if to_add {
+                tmp.push(y);
+            }
+        }
+        tmp
+    };
+
+    let empty_columns: Vec<u32> = {
+        let mut tmp = vec![];
+        let br = get_bounding_rect(&map);
+        // Vertical scan, this time for empty lines registry only
+        for x in 0..=br.0 {
+            let mut to_add = true;
+            for y in 0..=br.1 {
+                if let Some(TileType::Galaxy(_)) = map.get(&(x, y)) {
+                    to_add = false;
+                    // No need to keep going, here
+                    break;
+                }
+            }
+            if to_add {
+                tmp.push(x);
+            }
+        }
+        tmp
+    };
+
+    debug!("Empty columns: {} {:?}", empty_columns.len(), empty_columns);
+    debug!("Empty lines: {} {:?}", empty_lines.len(), empty_lines);
+    debug!("Galaxy count: {}", map.len());
+
+    // Time to expand !
+    trace!("Expanding universe width…");
+    expand_width(&mut map, &empty_columns, count_expanse);
+
+    trace!("Expanding universe height…");
+    expand_height(&mut map, &empty_lines, count_expanse);
+
+    trace!("Done expanding, pfiou !");
+
+    map.keys()
+        .map(|coords| (coords.0 as u64, coords.1 as u64))
+        .combinations(2)
+        .fold(0_u64, |acc, v| {
+            acc + calc_path(&(v[0].0, v[0].1), &(v[1].0, v[1].1))
+        })
+}
+
+#[cfg(test)]
+mod tests_get_sum_of_shortest_paths {
+    use super::*;
+
+    #[test]
+    fn get_sum_of_shortest_paths_example() {
+        let map = Day11::parse(
+            "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....",
+        )
+        .unwrap();
+        assert_eq!(get_sum_of_shortest_paths(&map, 1), 374);
+        assert_eq!(get_sum_of_shortest_paths(&map, 9), 1030);
+        assert_eq!(get_sum_of_shortest_paths(&map, 99), 8410);
+    }
+
+    #[test]
+    fn get_sum_of_shortest_paths_no_galaxy() {
+        let map = Day11::parse("...\n...").unwrap();
+        assert_eq!(get_sum_of_shortest_paths(&map, 1), 0);
+    }
+
+    #[test]
+    fn parse_malformed() {
+        assert_eq!(
+            Day11::parse("..#\n.x."),
+            Err(ParseError {
+                line: 2,
+                column: 2,
+                text: String::from("x"),
+                reason: String::from("expected `.` or `#`"),
+            })
+        );
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut map = HashMap::new();
+        let mut galaxy_count: u32 = 0;
+        for (index, line) in input.lines().enumerate() {
+            if let Some((offset, c)) = line.char_indices().find(|(_, c)| !".#".contains(*c)) {
+                return Err(ParseError::at(
+                    index,
+                    line,
+                    &line[offset..offset + c.len_utf8()],
+                    "expected `.` or `#`",
+                ));
+            }
+            galaxy_count = parse_line(line, &(index as u32), &mut map, galaxy_count);
+        }
+        Ok(map)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(get_sum_of_shortest_paths(input, 1))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        // Every empty line/column need to be replaced with
+        // `count_expanse` + THE ORIGINAL LINE !!!
+        // Hence no 1_000_000 :'D
+        Some(get_sum_of_shortest_paths(input, 999_999))
+    }
+}
@@ -0,0 +1,526 @@
+use std::fmt::Display;
+
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+    trace,
+};
+use itertools::{repeat_n, Itertools};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Row {
+    spring_list: String,
+    expected_broken_list: Vec<usize>,
+}
+
+fn parse_line(index: usize, line: &str) -> Result<Row, ParseError> {
+    let mut split = line.split_whitespace();
+    let spring_list = split
+        .next()
+        .ok_or_else(|| ParseError::missing(index, line, "springs"))?;
+    if let Some((offset, c)) = spring_list
+        .char_indices()
+        .find(|(_, c)| !".#?".contains(*c))
+    {
+        return Err(ParseError::at(
+            index,
+            line,
+            &spring_list[offset..offset + c.len_utf8()],
+            "expected `.`, `#` or `?`",
+        ));
+    }
+    let groups = split
+        .next()
+        .ok_or_else(|| ParseError::missing(index, line, "group sizes"))?;
+
+    Ok(Row {
+        spring_list: spring_list.to_string(),
+        expected_broken_list: groups
+            .split(',')
+            .map(
+                |group| match parse_field(index, line, group, "group size")? {
+                    0 => Err(ParseError::at(index, line, group, "empty group")),
+                    size => Ok(size),
+                },
+            )
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+#[cfg(test)]
+mod tests_parse_line {
+    use super::*;
+
+    #[test]
+    fn tests_parse_line_01() {
+        assert_eq!(
+            parse_line(0, "#.#.### 1,1,3"),
+            Ok(Row {
+                spring_list: "#.#.###".to_string(),
+                expected_broken_list: vec![1, 1, 3]
+            })
+        );
+    }
+
+    #[test]
+    fn parse_line_malformed() {
+        assert_eq!(
+            parse_line(2, "#.x.### 1,1,3"),
+            Err(ParseError {
+                line: 3,
+                column: 3,
+                text: String::from("x"),
+                reason: String::from("expected `.`, `#` or `?`"),
+            })
+        );
+        assert_eq!(
+            parse_line(2, "#.#.### 1,,3").map(|_| ()),
+            Err(ParseError {
+                line: 3,
+                column: 11,
+                text: String::new(),
+                reason: String::from("invalid group size: cannot parse integer from empty string"),
+            })
+        );
+        assert_eq!(
+            parse_line(2, "#.#.###"),
+            Err(ParseError::missing(2, "#.#.###", "group sizes"))
+        );
+    }
+}
+
+trait Unfold {
+    fn unfold(&mut self, fold_size: usize);
+}
+
+impl Unfold for Row {
+    fn unfold(&mut self, fold_size: usize) {
+        if fold_size > 0 {
+            self.spring_list = repeat_n(self.spring_list.clone(), fold_size).join("?");
+
+            self.expected_broken_list = repeat_n(self.expected_broken_list.clone(), fold_size)
+                .map(|e| e.iter().join(","))
+                .join(",")
+                .split(',')
+                .map(|c| c.parse().unwrap())
+                .collect_vec();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_unfold {
+    use super::*;
+
+    #[test]
+    fn unfold_01() {
+        let mut row = parse_line(0, ".# 1").unwrap();
+        row.unfold(5);
+        assert_eq!(row.spring_list, ".#?.#?.#?.#?.#");
+        assert_eq!(row.expected_broken_list, vec![1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn unfold_02() {
+        let mut row = parse_line(0, "???.### 1,1,3").unwrap();
+        row.unfold(5);
+        assert_eq!(row.spring_list, "???.###????.###????.###????.###????.###");
+        assert_eq!(
+            row.expected_broken_list,
+            vec![1, 1, 3, 1, 1, 3, 1, 1, 3, 1, 1, 3, 1, 1, 3]
+        );
+    }
+}
+
+#[cfg(test)]
+trait Cohesive {
+    fn is_cohesive(&self) -> bool;
+}
+
+#[cfg(test)]
+impl Cohesive for Row {
+    fn is_cohesive(&self) -> bool {
+        self.spring_list
+            .chars()
+            .chunk_by(|e| *e == '#')
+            .into_iter()
+            .filter(|(b, _)| *b)
+            .map(|(_, g)| g.count())
+            .collect::<Vec<usize>>()
+            == self.expected_broken_list
+    }
+}
+
+#[cfg(test)]
+mod tests_is_cohesive {
+    use super::*;
+
+    #[test]
+    fn is_cohesive_01() {
+        assert!(parse_line(0, "#.#.### 1,1,3").unwrap().is_cohesive());
+        assert!(!parse_line(0, "##..### 1,1,3").unwrap().is_cohesive());
+    }
+}
+
+/// Brute force: check every possible replacement of the «Unknown» springs, only
+/// kept to check the faster counts against
+#[cfg(test)]
+fn calc_permutations(row: &Row) -> u32 {
+    // Creating all possible permutations of Functional/Broken to cover
+    // every «Unknown» state
+    repeat_n(['.', '#'].iter(), row.spring_list.matches('?').count())
+        .multi_cartesian_product()
+        // Checking every permutation to check whether it produces a cohesive Row
+        .map(|permutation| {
+            let mut tmp_perm = permutation.clone();
+            tmp_perm.reverse();
+            let tmp_row = Row {
+                spring_list: row
+                    .spring_list
+                    .chars()
+                    .map(|spring| match spring {
+                        '?' => *tmp_perm.pop().unwrap(),
+                        x => x,
+                    })
+                    .collect(),
+                expected_broken_list: row.expected_broken_list.clone(),
+            };
+            tmp_row.is_cohesive()
+        })
+        // Count how many cohesive rows result of all that
+        .filter(|e| *e)
+        .count() as u32
+}
+
+#[cfg(test)]
+mod tests_calc_permutations {
+    use super::*;
+
+    #[test]
+    fn calc_permutations_01() {
+        assert_eq!(
+            calc_permutations(&parse_line(0, "???.### 1,1,3").unwrap()),
+            1
+        );
+    }
+
+    #[test]
+    fn calc_permutations_02() {
+        assert_eq!(
+            calc_permutations(&parse_line(0, " .??..??...?##. 1,1,3").unwrap()),
+            4
+        );
+    }
+
+    #[test]
+    fn calc_permutations_03() {
+        assert_eq!(
+            calc_permutations(&parse_line(0, "?###???????? 3,2,1").unwrap()),
+            10
+        );
+    }
+}
+
+/// Where the springs after a group of `len` broken springs starting at `i`
+/// begin, skipping the operational one that has to follow it, or `None` if
+/// such a group cannot start there
+fn group_end(springs: &[u8], i: usize, len: usize) -> Option<usize> {
+    let end = i + len;
+    if end > springs.len() || springs[i..end].contains(&b'.') {
+        return None;
+    }
+    match springs.get(end) {
+        None => Some(end),
+        Some(b'#') => None,
+        Some(_) => Some(end + 1),
+    }
+}
+
+/// `counts[i * width + g]` is how many arrangements of `springs[i..]` form
+/// exactly `groups[g..]`
+struct Table {
+    width: usize,
+    counts: Vec<u64>,
+}
+
+impl Table {
+    /// Filled from the end of the row, each count only needing the ones of
+    /// the springs after it
+    fn new(springs: &[u8], groups: &[usize]) -> Table {
+        let width = groups.len() + 1;
+        let mut table = Table {
+            width,
+            counts: vec![0; (springs.len() + 1) * width],
+        };
+        // Nothing left to arrange: only the lack of groups fits
+        table.counts[springs.len() * width + groups.len()] = 1;
+
+        for i in (0..springs.len()).rev() {
+            for g in 0..width {
+                let mut count = 0;
+                // The spring is operational
+                if springs[i] != b'#' {
+                    count += table.get(i + 1, g);
+                }
+                // The spring starts the next group
+                if springs[i] != b'.' && g < groups.len() {
+                    if let Some(end) = group_end(springs, i, groups[g]) {
+                        count += table.get(end, g + 1);
+                    }
+                }
+                table.counts[i * width + g] = count;
+            }
+        }
+        table
+    }
+
+    fn get(&self, i: usize, g: usize) -> u64 {
+        self.counts[i * self.width + g]
+    }
+}
+
+/// How many ways the unknown springs can be replaced so that the broken ones
+/// form exactly the given groups
+fn count_arrangements(springs: &[u8], groups: &[usize]) -> u64 {
+    Table::new(springs, groups).get(0, 0)
+}
+
+#[cfg(test)]
+mod tests_count_arrangements {
+    use super::*;
+
+    #[test]
+    fn count_arrangements_01() {
+        assert_eq!(count_arrangements(b".", &[1]), 0);
+    }
+
+    #[test]
+    fn count_arrangements_02() {
+        assert_eq!(count_arrangements(b"#", &[1]), 1);
+    }
+
+    #[test]
+    fn count_arrangements_03() {
+        assert_eq!(count_arrangements(b"#.#", &[1, 1]), 1);
+    }
+
+    #[test]
+    fn count_arrangements_04() {
+        assert_eq!(count_arrangements(b"#?#", &[1]), 0);
+    }
+
+    #[test]
+    fn count_arrangements_05() {
+        let row = parse_line(0, ".###.##..#.. 3,2,1").unwrap();
+        assert_eq!(
+            count_arrangements(row.spring_list.as_bytes(), &row.expected_broken_list),
+            1
+        );
+    }
+
+    #[test]
+    fn count_arrangements_06() {
+        let row = parse_line(0, "???#.##..#.. 3,2,1").unwrap();
+        assert_eq!(
+            count_arrangements(row.spring_list.as_bytes(), &row.expected_broken_list),
+            1
+        );
+    }
+
+    #[test]
+    fn count_arrangements_no_group() {
+        assert_eq!(count_arrangements(b"?.?", &[]), 1);
+        assert_eq!(count_arrangements(b"?#?", &[]), 0);
+    }
+}
+
+/// Every concrete arrangement of a row, as a row without unknown springs.
+/// Meant to debug small rows: they are built one by one, following the
+/// counts table so that no dead end is ever explored.
+pub struct Arrangements<'a> {
+    row: &'a Row,
+    table: Table,
+    // What is left to arrange: from which spring, with which group, and the
+    // springs chosen so far
+    stack: Vec<(usize, usize, Vec<u8>)>,
+}
+
+impl Row {
+    pub fn arrangements(&self) -> Arrangements<'_> {
+        let table = Table::new(self.spring_list.as_bytes(), &self.expected_broken_list);
+        let stack = if table.get(0, 0) > 0 {
+            vec![(0, 0, vec![])]
+        } else {
+            vec![]
+        };
+        Arrangements {
+            row: self,
+            table,
+            stack,
+        }
+    }
+}
+
+impl Iterator for Arrangements<'_> {
+    type Item = Row;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let springs = self.row.spring_list.as_bytes();
+        let groups = &self.row.expected_broken_list;
+        while let Some((i, g, chosen)) = self.stack.pop() {
+            if i == springs.len() {
+                return Some(Row {
+                    spring_list: String::from_utf8(chosen).unwrap(),
+                    expected_broken_list: groups.clone(),
+                });
+            }
+            // The group is pushed last, so that groups are placed as early as
+            // possible first
+            if springs[i] != b'#' && self.table.get(i + 1, g) > 0 {
+                let mut chosen = chosen.clone();
+                chosen.push(b'.');
+                self.stack.push((i + 1, g, chosen));
+            }
+            if springs[i] != b'.' && g < groups.len() {
+                if let Some(end) = group_end(springs, i, groups[g]) {
+                    if self.table.get(end, g + 1) > 0 {
+                        let mut chosen = chosen;
+                        chosen.extend(std::iter::repeat_n(b'#', groups[g]));
+                        chosen.extend(std::iter::repeat_n(b'.', end - i - groups[g]));
+                        self.stack.push((end, g + 1, chosen));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests_arrangements {
+    use super::*;
+
+    #[test]
+    fn arrangements_01() {
+        let row = parse_line(0, "?###???????? 3,2,1").unwrap();
+        let arrangements = row.arrangements().collect_vec();
+        assert_eq!(arrangements.len(), 10);
+        assert_eq!(arrangements[0].spring_list, ".###.##.#...");
+        assert!(arrangements.iter().all(|row| row.is_cohesive()));
+        assert!(arrangements.iter().all_unique());
+    }
+
+    #[test]
+    fn arrangements_match_brute_force() {
+        for line in [
+            "???.### 1,1,3",
+            ".??..??...?##. 1,1,3",
+            "?#?#?#?#?#?#?#? 1,3,1,6",
+            "????.#...#... 4,1,1",
+            "????.######..#####. 1,6,5",
+            "??? 4",
+        ] {
+            let row = parse_line(0, line).unwrap();
+            let arrangements = row.arrangements().collect_vec();
+            assert!(arrangements.iter().all(|row| row.is_cohesive()), "{}", line);
+            assert_eq!(
+                arrangements.len() as u32,
+                calc_permutations(&row),
+                "{}",
+                line
+            );
+        }
+    }
+}
+
+fn calc(row: &Row) -> u64 {
+    count_arrangements(row.spring_list.as_bytes(), &row.expected_broken_list)
+}
+
+#[cfg(test)]
+mod tests_calc {
+    use super::*;
+
+    #[test]
+    fn calc_01() {
+        assert_eq!(calc(&parse_line(0, "???.### 1,1,3").unwrap()), 1);
+    }
+
+    #[test]
+    fn calc_02() {
+        assert_eq!(calc(&parse_line(0, ".??..??...?##. 1,1,3").unwrap()), 4);
+    }
+
+    #[test]
+    fn calc_03() {
+        assert_eq!(calc(&parse_line(0, "?###???????? 3,2,1").unwrap()), 10);
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Row>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(index, line)| parse_line(index, line))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(input.iter().map(calc).sum::<u64>())
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let mut row_list = input.clone();
+
+        for row in row_list.iter_mut() {
+            row.unfold(5);
+        }
+        trace!("Unfolded {} rows", row_list.len());
+
+        Some(
+            row_list
+                .iter()
+                .enumerate()
+                .map(|(index, row)| {
+                    trace!("Computing permutations for row #{}", index);
+                    calc(row)
+                })
+                .sum::<u64>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests_day12 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day12_example() {
+        let input = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+        assert_eq!(
+            solve::<Day12>(input, &[1, 2]),
+            Ok(vec![Some(String::from("21")), Some(String::from("525152"))])
+        );
+    }
+
+    #[test]
+    fn day12_trailing_blank_line() {
+        assert_eq!(
+            solve::<Day12>("???.### 1,1,3\n\n", &[1]),
+            Ok(vec![Some(String::from("1"))])
+        );
+    }
+}
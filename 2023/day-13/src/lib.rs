@@ -1,9 +1,6 @@
-use std::fs;
+use std::fmt::Display;
 
-fn get_file_content(file_path: &str) -> String {
-    println!("Loading input file: {}", file_path);
-    fs::read_to_string(file_path).expect("Cannot load file")
-}
+use aoc_core::{debug, info, parse::ParseError, solution::Solution, trace};
 
 type Coords = (usize, usize);
 
@@ -137,13 +134,13 @@ fn check_mirror_hypothesis(
         }
     }
     if ok {
-        return (mirror_position, maybe_smudge);
+        (mirror_position, maybe_smudge)
     } else {
-        return (0, MirrorCompare::TooManySmudges);
+        (0, MirrorCompare::TooManySmudges)
     }
 }
 
-fn count_above(unclean_block: &str) -> (usize, MirrorCompare) {
+fn count_smudged_above(unclean_block: &str) -> (usize, MirrorCompare) {
     let block = unclean_block.replace(" ", "");
 
     let lines: Vec<&str> = block.split_whitespace().collect();
@@ -151,7 +148,7 @@ fn count_above(unclean_block: &str) -> (usize, MirrorCompare) {
     // There's a trick here: due to the skip, index is actually
     // the real index - 1
     for (index, line) in lines.iter().skip(1).enumerate() {
-        let maybe_smudge = are_equal_modulo_one_smudge(*line, lines[index], index + 1);
+        let maybe_smudge = are_equal_modulo_one_smudge(line, lines[index], index + 1);
         match maybe_smudge {
             // No need to check if it's a mirror
             // if we already have too many smudged on our hands
@@ -170,17 +167,17 @@ fn count_above(unclean_block: &str) -> (usize, MirrorCompare) {
             }
         }
     }
-    return (0, MirrorCompare::TooManySmudges);
+    (0, MirrorCompare::TooManySmudges)
 }
 
 #[cfg(test)]
-mod tests_count_above {
+mod tests_count_smudged_above {
     use super::*;
 
     #[test]
-    fn count_above_01() {
+    fn count_smudged_above_01() {
         assert_eq!(
-            count_above(
+            count_smudged_above(
                 "
 #.##..##.
 ..#.##.#.
@@ -195,9 +192,9 @@ mod tests_count_above {
     }
 
     #[test]
-    fn count_above_02() {
+    fn count_smudged_above_02() {
         assert_eq!(
-            count_above(
+            count_smudged_above(
                 "
     #...##..#
     #....#..#
@@ -212,9 +209,9 @@ mod tests_count_above {
     }
 
     #[test]
-    fn count_above_03() {
+    fn count_smudged_above_03() {
         assert_eq!(
-            count_above(
+            count_smudged_above(
                 "
 .##..#..##..#.#
 ..##....#.#..#.
@@ -232,9 +229,9 @@ mod tests_count_above {
     }
 
     #[test]
-    fn count_above_04() {
+    fn count_smudged_above_04() {
         assert_eq!(
-            count_above(
+            count_smudged_above(
                 "
 ..##....##..###
 .#.##..##.#...#
@@ -255,7 +252,7 @@ mod tests_count_above {
 
 fn rotate(block: &str) -> Vec<String> {
     let lines = block.split_whitespace();
-    let first_line = lines.clone().nth(0).unwrap();
+    let first_line = lines.clone().next().unwrap();
     let mut columns: Vec<String> = vec![];
     for i in 0..first_line.len() {
         let column = lines
@@ -337,10 +334,112 @@ mod tests_rotate {
     }
 }
 
-// Count left work by first rotating the block by 90 degrees and then apply the
-// same algorithm as if it was a regular block. It's "above" count is thus
-// its "left" count.
-fn count_left(unclean_block: &str) -> (usize, MirrorCompare) {
+fn count_above(unclean_block: &str) -> usize {
+    let block = unclean_block.replace(" ", "");
+
+    let lines: Vec<&str> = block.split_whitespace().collect();
+
+    // There's a trick here: due to the skip, index is actually
+    // the real index - 1
+    for (index, line) in lines.iter().skip(1).enumerate() {
+        // Comparing the current line and the one directly above it will give us
+        // the possible mirror position as an index.
+        if *line == lines[index] {
+            let mirror_position = index + 1;
+            let mut spread = 1;
+            let mut ok = true;
+            loop {
+                if (mirror_position as i32) - 1 - (spread as i32) < 0 {
+                    break;
+                }
+                match (
+                    lines.get(mirror_position - 1 - spread),
+                    lines.get(mirror_position + spread),
+                ) {
+                    (Some(a), Some(b)) => {
+                        if a != b {
+                            ok = false;
+                            break;
+                        } else {
+                            spread += 1;
+                        }
+                    }
+                    _ => {
+                        // No reason to pursue since at least one side is missing
+                        // for a comparison
+                        break;
+                    }
+                }
+            }
+            if ok {
+                return mirror_position;
+            }
+        }
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod tests_count_above {
+    use super::*;
+
+    #[test]
+    fn count_above_01() {
+        assert_eq!(
+            count_above(
+                "
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"
+            ),
+            4
+        );
+    }
+
+    #[test]
+    fn count_above_02() {
+        assert_eq!(
+            count_above(
+                "
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#."
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn count_above_03() {
+        assert_eq!(
+            count_above(
+                "
+#..##.#
+...##..
+###..##
+.#....#
+#.#..#.
+#.#..#.
+.#....#
+###..##
+...##.."
+            ),
+            5
+        );
+    }
+}
+
+// Works the same as `count_smudged_left`, see below
+fn count_left(unclean_block: &str) -> usize {
     count_above(rotate(&unclean_block.replace(" ", "")).join("\n").as_str())
 }
 
@@ -353,6 +452,73 @@ mod tests_count_left {
         assert_eq!(
             count_left(
                 "
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#."
+            ),
+            5
+        );
+    }
+
+    #[test]
+    fn count_left_02() {
+        assert_eq!(
+            count_left(
+                "
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn count_left_03() {
+        assert_eq!(
+            count_left(
+                "
+#....####.##.####
+.##.###...##...##
+#..#....######...
+#####..#.####.#..
+.##.#..#.#..#.#..
+#..###.##....##.#
+#..##...#.##.#...
+#..#...###..###..
+.##.#..#.#..#.#..
+.##.##....##....#
+#..##.###....###."
+            ),
+            11
+        );
+    }
+}
+
+// Count left work by first rotating the block by 90 degrees and then apply the
+// same algorithm as if it was a regular block. It's "above" count is thus
+// its "left" count.
+fn count_smudged_left(unclean_block: &str) -> (usize, MirrorCompare) {
+    count_smudged_above(rotate(&unclean_block.replace(" ", "")).join("\n").as_str())
+}
+
+#[cfg(test)]
+mod tests_count_smudged_left {
+    use super::*;
+
+    #[test]
+    fn count_smudged_left_01() {
+        assert_eq!(
+            count_smudged_left(
+                "
     #...##..#
     #....#..#
     ..##..###
@@ -366,9 +532,9 @@ mod tests_count_left {
     }
 
     #[test]
-    fn count_left_02() {
+    fn count_smudged_left_02() {
         assert_eq!(
-            count_left(
+            count_smudged_left(
                 "
 .########..
 ###.##.####
@@ -385,47 +551,163 @@ mod tests_count_left {
     }
 }
 
-fn main() {
-    let content = get_file_content("assets/input");
-    let blocks = content.split("\n\n");
+/// Patterns are separated by blank lines, and must be rectangles of `.` and `#`
+fn parse_patterns(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut patterns = vec![];
+    let mut pattern: Vec<&str> = vec![];
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            if !pattern.is_empty() {
+                patterns.push(pattern.join("\n"));
+                pattern.clear();
+            }
+            continue;
+        }
+        if let Some((offset, c)) = line.char_indices().find(|(_, c)| !".#".contains(*c)) {
+            return Err(ParseError::at(
+                index,
+                line,
+                &line[offset..offset + c.len_utf8()],
+                "expected `.` or `#`",
+            ));
+        }
+        if let Some(first_line) = pattern.first() {
+            if first_line.len() != line.len() {
+                return Err(ParseError::line(
+                    index,
+                    line,
+                    format!("expected {} tiles, like the lines above", first_line.len()),
+                ));
+            }
+        }
+        pattern.push(line);
+    }
+    if !pattern.is_empty() {
+        patterns.push(pattern.join("\n"));
+    }
+    Ok(patterns)
+}
+
+#[cfg(test)]
+mod tests_parse_patterns {
+    use super::*;
+
+    #[test]
+    fn parse_patterns_01() {
+        assert_eq!(
+            parse_patterns("#.\n.#\n\n\n##\n"),
+            Ok(vec![String::from("#.\n.#"), String::from("##")])
+        );
+    }
+
+    #[test]
+    fn parse_patterns_malformed() {
+        assert_eq!(
+            parse_patterns("#.\n.#\n\n##\n#"),
+            Err(ParseError::line(
+                4,
+                "#",
+                "expected 2 tiles, like the lines above"
+            ))
+        );
+        assert_eq!(
+            parse_patterns("#.\n.O"),
+            Err(ParseError {
+                line: 2,
+                column: 2,
+                text: String::from("O"),
+                reason: String::from("expected `.` or `#`"),
+            })
+        );
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_patterns(input)
+    }
 
-    println!(
-        "Result: {:?}",
-        blocks
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(
+            input
+                .iter()
+                .enumerate()
+                .map(|(index, b)| {
+                    let left = count_left(b);
+                    let above = count_above(b);
+                    debug!("Block: {}, Left: {}, Above: {}", index, left, above);
+                    left + 100 * above
+                })
+                .sum::<usize>(),
+        )
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        input
+            .iter()
             .enumerate()
             .map(|(index, b)| {
-                println!("");
-                println!("Handling block #{}", index);
-                let above = count_above(b);
-                let left = count_left(b);
+                trace!("Handling block #{}", index);
+                let above = count_smudged_above(b);
+                let left = count_smudged_left(b);
 
-                println!("Index: {}, Above: {:?}, Left: {:?}", index, above, left);
+                trace!("Index: {}, Above: {:?}, Left: {:?}", index, above, left);
 
                 let (a, l) = match (above.1, left.1) {
-                    (MirrorCompare::NoSmudge, MirrorCompare::NoSmudge) => {
-                        panic!("No smudge on either side !")
-                    }
                     (MirrorCompare::NoSmudge, MirrorCompare::OneSmudge(_)) => (0, left.0),
                     (MirrorCompare::OneSmudge(_), MirrorCompare::NoSmudge) => (above.0, 0),
 
-                    (MirrorCompare::NoSmudge, MirrorCompare::TooManySmudges) => panic!("DERP"),
-                    (MirrorCompare::TooManySmudges, MirrorCompare::NoSmudge) => panic!("DERP"),
-
                     (MirrorCompare::OneSmudge(_), MirrorCompare::TooManySmudges) => (above.0, 0),
                     (MirrorCompare::TooManySmudges, MirrorCompare::OneSmudge(_)) => (0, left.0),
 
-                    (MirrorCompare::OneSmudge(_), MirrorCompare::OneSmudge(_)) => {
-                        panic!("Don't know what to do !")
-                    }
-                    (MirrorCompare::TooManySmudges, MirrorCompare::TooManySmudges) => {
-                        panic!("Don't know what to do !")
+                    // No smudge, or more than one way to clean it
+                    (above_smudge, left_smudge) => {
+                        info!(
+                            "Block {} has no single smudge (above: {:?}, left: {:?})",
+                            index, above_smudge, left_smudge
+                        );
+                        return None;
                     }
                 };
 
                 let result = l + 100 * a;
-                println!("Result is: {}", result);
-                result
+                debug!("Block: {}, Result: {}", index, result);
+                Some(result)
             })
-            .sum::<usize>()
-    );
+            .sum::<Option<usize>>()
+    }
+}
+
+#[cfg(test)]
+mod tests_day13 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day13_example() {
+        let input = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+        assert_eq!(
+            solve::<Day13>(input, &[1, 2]),
+            Ok(vec![Some(String::from("405")), Some(String::from("400"))])
+        );
+    }
 }
@@ -0,0 +1,385 @@
+use std::fmt::Display;
+
+use aoc_core::{cycle::find_cycle, debug, grid::Grid, parse::ParseError, solution::Solution};
+
+fn roll_left(line: &str) -> String {
+    let mut new_line: Vec<char> = line.chars().collect();
+    for (x, c) in line.chars().enumerate() {
+        if c == 'O' && x > 0 {
+            let mut tmp = x;
+            for xi in (0..=x - 1).rev() {
+                match new_line[xi] {
+                    'O' | '#' => {
+                        break;
+                    }
+                    _ => {}
+                }
+                tmp = xi;
+            }
+            if tmp != x {
+                new_line[tmp] = 'O';
+                new_line[x] = '.'
+            }
+        }
+    }
+    new_line.iter().collect::<String>()
+}
+
+#[cfg(test)]
+mod tests_roll_left {
+    use super::*;
+
+    #[test]
+    fn roll_left_01() {
+        assert_eq!(roll_left(".....O..##"), "O.......##");
+    }
+
+    #[test]
+    fn roll_left_02() {
+        assert_eq!(roll_left("..O..O..##"), "OO......##");
+    }
+
+    #[test]
+    fn roll_left_03() {
+        assert_eq!(roll_left("..O#.O..##"), "O..#O...##");
+    }
+
+    #[test]
+    fn roll_left_04() {
+        assert_eq!(roll_left("..O#.O..#O"), "O..#O...#O");
+    }
+
+    #[test]
+    fn roll_left_05() {
+        assert_eq!(roll_left("#........O"), "#O........");
+    }
+}
+
+/// Rotates 90 degrees counter-clockwise
+fn rotate_ccw(block: &str) -> String {
+    let lines = block.split_whitespace();
+    let first_line = lines.clone().next().unwrap();
+    let mut columns: Vec<String> = vec![];
+    for i in (0..first_line.len()).rev() {
+        let column = lines
+            .clone()
+            .map(|l| l.to_string().chars().nth(i).unwrap().to_string())
+            // .rev()
+            .collect();
+        columns.push(column);
+    }
+    columns.join("\n")
+}
+
+#[cfg(test)]
+mod tests_rotate_ccw {
+    use super::*;
+
+    #[test]
+    fn rotate_ccw_01() {
+        let result = rotate_ccw(
+            "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....",
+        );
+        let expected = String::from(
+            ".#.O.#O...
+....#.....
+....O#.O#.
+..#...O.#.
+#.#..O#.##
+.#.O......
+.O.#......
+.O...#O..O
+...OO....O
+OO.O.O..##",
+        );
+        assert_eq!(result, expected)
+    }
+}
+
+/// Rotates 90 degrees clockwise
+fn rotate_cw(block: &str) -> String {
+    let lines = block.split_whitespace();
+    let first_line = lines.clone().next().unwrap();
+    let mut columns: Vec<String> = vec![];
+    for i in 0..first_line.len() {
+        let column: String = lines
+            .clone()
+            .map(|l| l.to_string().chars().nth(i).unwrap().to_string())
+            .rev()
+            .collect();
+        columns.push(column);
+    }
+    columns.join("\n")
+}
+
+#[cfg(test)]
+mod tests_rotate_cw {
+    use super::*;
+
+    #[test]
+    fn rotate_cw_01() {
+        let result = rotate_cw(
+            ".#.O.#O...
+....#.....
+....O#.O#.
+..#...O.#.
+#.#..O#.##
+.#.O......
+.O.#......
+.O...#O..O
+...OO....O
+OO.O.O..##",
+        );
+
+        for line in result.lines() {
+            println!("{}", line);
+        }
+        println!();
+
+        let expected = String::from(
+            "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....",
+        );
+        assert_eq!(result, expected)
+    }
+}
+
+/// Rotates the block, then rolls every rock of the resulting lines to the left
+fn rotate_and_roll(block: &str, rotate: fn(&str) -> String) -> String {
+    rotate(block).lines().map(|l| roll_left(l) + "\n").collect()
+}
+
+/// Rolls every rock to the north, the result being oriented (top) to West
+fn roll_north(block: &str) -> String {
+    rotate_and_roll(block, rotate_ccw)
+}
+
+#[cfg(test)]
+mod tests_roll_north {
+    use super::*;
+
+    #[test]
+    fn roll_north_01() {
+        let result = roll_north(
+            "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....",
+        );
+        let expected = String::from(
+            ".#O..#O...
+....#.....
+O....#O.#.
+..#O....#.
+#.#O..#.##
+.#O.......
+O..#......
+O....#OO..
+OOO.......
+OOOO....##
+",
+        );
+        assert_eq!(result, expected)
+    }
+}
+
+fn count_weight(block: &str) -> usize {
+    let mut acc = 0;
+    for line in block.lines() {
+        for (i, c) in line.chars().enumerate() {
+            if c == 'O' {
+                acc += line.len() - i
+            }
+        }
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests_count_weight {
+    use super::*;
+
+    #[test]
+    fn count_weight_01() {
+        let result = count_weight(
+            ".#O..#O...
+....#.....
+O....#O.#.
+..#O....#.
+#.#O..#.##
+.#O.......
+O..#......
+O....#OO..
+OOO.......
+OOOO....##
+",
+        );
+        let expected = 136;
+        assert_eq!(result, expected)
+    }
+}
+
+fn cycle(block: &str, cycle_count: usize) -> String {
+    // Block is assumed oriented (top) to South:
+    //      S
+    //      |
+    // E <-   -> W
+    //      |
+    //      N
+    // This way it's easier to cycle.
+
+    // The rocks end up going through the same positions again and again, so
+    // only the cycles up to the first repeated position are run
+    let cycles = find_cycle(String::from(block), |tmp| {
+        // Roll order is :
+
+        // - North
+        let tmp = rotate_and_roll(tmp, rotate_cw);
+
+        // - West
+        let tmp = rotate_and_roll(&tmp, rotate_cw);
+
+        // - South
+        let tmp = rotate_and_roll(&tmp, rotate_cw);
+
+        // - East
+        rotate_and_roll(&tmp, rotate_cw)
+    });
+    debug!(
+        "Positions repeat every {} cycles after cycle {}",
+        cycles.length, cycles.start
+    );
+
+    // Finalize a return to North for weight counting
+    rotate_cw(cycles.nth(cycle_count))
+}
+
+#[cfg(test)]
+mod tests_cycle {
+    use super::*;
+
+    const BLOCK: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn cycle_01() {
+        let result = cycle(&rotate_ccw(&rotate_ccw(BLOCK)), 1);
+        let expected = String::from(
+            "\
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....",
+        );
+        assert_eq!(rotate_ccw(&rotate_ccw(&rotate_ccw(&result))), expected)
+    }
+
+    #[test]
+    fn cycle_billion() {
+        let result = cycle(&rotate_ccw(&rotate_ccw(BLOCK)), 1_000_000_000);
+        assert_eq!(count_weight(&result), 64)
+    }
+}
+
+/// A tile of the platform, only used to check it when parsing it
+struct Tile;
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        if ".#O".contains(c) {
+            Ok(Tile)
+        } else {
+            Err(())
+        }
+    }
+}
+
+fn parse_platform(input: &str) -> Result<String, ParseError> {
+    let platform: Grid<Tile> = input.parse()?;
+    if platform.width() == 0 {
+        return Err(ParseError::missing(0, "", "platform"));
+    }
+    Ok(input.to_string())
+}
+
+#[cfg(test)]
+mod tests_parse_platform {
+    use super::*;
+
+    #[test]
+    fn parse_platform_malformed() {
+        let error = parse_platform("O.#\n.X.").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.reason, "unknown tile");
+        let error = parse_platform("O.#\n.#").unwrap_err();
+        assert_eq!(
+            (error.line, error.reason),
+            (2, String::from("expected 3 tiles, got 2"))
+        );
+        assert!(parse_platform("\n").is_err());
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_platform(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(count_weight(roll_north(input).as_str()))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let cycle_count = 1_000_000_000;
+
+        Some(count_weight(&cycle(
+            &rotate_ccw(&rotate_ccw(input)),
+            cycle_count,
+        )))
+    }
+}
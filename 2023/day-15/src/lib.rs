@@ -1,9 +1,10 @@
-use std::fs;
+use std::fmt::Display;
 
-fn get_file_content(file_path: &str) -> String {
-    println!("Loading input file: {}", file_path);
-    fs::read_to_string(file_path).expect("Cannot load file")
-}
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+    trace,
+};
 
 fn hash(input: &str) -> usize {
     input
@@ -65,6 +66,10 @@ mod tests_hash {
     fn hash_12() {
         assert_eq!(hash("ot=7"), 231);
     }
+    #[test]
+    fn hash_13() {
+        assert_eq!(hash("rn=1"), 30);
+    }
 }
 
 type Box<'a> = Vec<(&'a str, usize)>;
@@ -82,11 +87,8 @@ fn process<'a>(base_box: Box<'a>, instruction: &'a str) -> Box<'a> {
     } else if instruction.contains("-") {
         let mut split = instruction.split("-");
         let lens_name = split.next().unwrap();
-        match new_box.iter().enumerate().find(|(_, l)| l.0 == lens_name) {
-            Some((index, _)) => {
-                new_box.remove(index);
-            }
-            None => {}
+        if let Some((index, _)) = new_box.iter().enumerate().find(|(_, l)| l.0 == lens_name) {
+            new_box.remove(index);
         };
     } else {
         panic!("Invalid instruction");
@@ -101,20 +103,20 @@ mod tests_process {
     #[test]
     fn process_add() {
         let my_box: Box = vec![];
-        assert_eq!(process(my_box, &"rn=1"), vec![("rn", 1)]);
+        assert_eq!(process(my_box, "rn=1"), vec![("rn", 1)]);
     }
 
     #[test]
     fn process_remove() {
         let my_box: Box = vec![("rn", 1)];
-        assert_eq!(process(my_box, &"rn-"), vec![]);
+        assert_eq!(process(my_box, "rn-"), vec![]);
     }
 
     #[test]
     fn process_replace() {
         let my_box: Box = vec![("rn", 1), ("qb", 2), ("fo", 3)];
         assert_eq!(
-            process(my_box, &"qb=4"),
+            process(my_box, "qb=4"),
             vec![("rn", 1), ("qb", 4), ("fo", 3)]
         );
     }
@@ -122,15 +124,14 @@ mod tests_process {
     #[test]
     fn process_remove_among_other() {
         let my_box: Box = vec![("rn", 1), ("qb", 2), ("fo", 3)];
-        assert_eq!(process(my_box, &"qb-"), vec![("rn", 1), ("fo", 3)]);
+        assert_eq!(process(my_box, "qb-"), vec![("rn", 1), ("fo", 3)]);
     }
 }
 
-fn process_multiple<'a>(boxes: &Vec<Box<'a>>, instructions: Vec<&'a str>) -> Vec<Box<'a>> {
-    let mut out_boxes: Vec<Box<'a>> = boxes.clone();
+fn process_multiple<'a>(boxes: &[Box<'a>], instructions: Vec<&'a str>) -> Vec<Box<'a>> {
+    let mut out_boxes: Vec<Box<'a>> = boxes.to_vec();
     for instruction in instructions {
-        println!("");
-        println!("Instruction: {}", instruction);
+        trace!("Instruction: {}", instruction);
         let split_char = {
             if instruction.contains("=") {
                 "="
@@ -156,7 +157,7 @@ mod tests_process_multiple {
     fn process_multiple_01() {
         assert_eq!(
             process_multiple(
-                &vec![vec![], vec![], vec![], vec![]],
+                &[vec![], vec![], vec![], vec![]],
                 vec![
                     "rn=1", "cm-", "qp=3", "cm=2", "qp-", "pc=4", "ot=9", "ab=5", "pc-", "pc=6",
                     "ot=7"
@@ -203,22 +204,109 @@ mod tests_calc_lens_power {
     }
 }
 
-fn main() {
-    let content = get_file_content("assets/input");
+/// Check a `<label>=<focal length>` or `<label>-` step of the sequence on `line`
+fn check_step(line: &str, step: &str) -> Result<(), ParseError> {
+    let expected = "expected `<label>=<focal length>` or `<label>-`";
+    let operation_start = step
+        .find(['=', '-'])
+        .filter(|&start| start > 0)
+        .ok_or_else(|| ParseError::at(0, line, step, expected))?;
+    let operation = &step[operation_start..];
+    if operation == "-" {
+        Ok(())
+    } else if let Some(focal_length) = operation.strip_prefix('=') {
+        parse_field::<usize>(0, line, focal_length, "focal length").map(|_| ())
+    } else {
+        Err(ParseError::at(0, line, step, expected))
+    }
+}
+
+#[cfg(test)]
+mod tests_check_step {
+    use super::*;
+
+    #[test]
+    fn check_step_01() {
+        assert_eq!(check_step("rn=1,cm-", "rn=1"), Ok(()));
+        assert_eq!(check_step("rn=1,cm-", "cm-"), Ok(()));
+    }
+
+    #[test]
+    fn check_step_malformed() {
+        let line = "rn=1,cm-,qp=x,ab";
+        assert_eq!(
+            check_step(line, &line[9..13]),
+            Err(ParseError {
+                line: 1,
+                column: 13,
+                text: String::from("x"),
+                reason: String::from("invalid focal length: invalid digit found in string"),
+            })
+        );
+        assert_eq!(
+            check_step(line, &line[14..]),
+            Err(ParseError::at(
+                0,
+                line,
+                &line[14..],
+                "expected `<label>=<focal length>` or `<label>-`"
+            ))
+        );
+        assert!(check_step("cm-3", "cm-3").is_err());
+        assert!(check_step("=3", "=3").is_err());
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
 
-    let mut boxes: Vec<Box> = vec![];
-    for _ in 0..256 {
-        boxes.push(vec![]);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::missing(0, "", "initialization sequence"))?;
+        line.split(',')
+            .map(|step| check_step(line, step).map(|_| String::from(step)))
+            .collect()
     }
 
-    let instructions: Vec<&str> = content.lines().next().unwrap().split(',').collect();
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(input.iter().map(|step| hash(step)).sum::<usize>())
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let mut boxes: Vec<Box> = vec![];
+        for _ in 0..256 {
+            boxes.push(vec![]);
+        }
+
+        let instructions: Vec<&str> = input.iter().map(String::as_str).collect();
 
-    println!(
-        "Result: {:?}",
-        process_multiple(&boxes, instructions)
-            .iter()
-            .enumerate()
-            .map(|(box_index, curr_box)| calc_lens_power(curr_box, box_index))
-            .sum::<usize>()
-    );
+        Some(
+            process_multiple(&boxes, instructions)
+                .iter()
+                .enumerate()
+                .map(|(box_index, curr_box)| calc_lens_power(curr_box, box_index))
+                .sum::<usize>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests_day15 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day15_example() {
+        assert_eq!(
+            solve::<Day15>(
+                "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7",
+                &[1, 2]
+            ),
+            Ok(vec![Some(String::from("1320")), Some(String::from("145"))])
+        );
+    }
 }
@@ -0,0 +1,349 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_core::{
+    direction::{Compass, Direction},
+    display,
+    grid::Grid,
+    trace,
+};
+use aoc_core::{parse::ParseError, solution::Solution};
+use colored::Colorize;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct Tile {
+    content: char,
+    energized: usize,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(content: char) -> Result<Self, Self::Error> {
+        if ".-|/\\".contains(content) {
+            Ok(Tile {
+                content,
+                energized: 0,
+            })
+        } else {
+            Err(())
+        }
+    }
+}
+
+type Coords = aoc_core::coords::Coords<usize>;
+type Map = Grid<Tile>;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
+struct Ray {
+    starting_point: Coords,
+    direction: Direction,
+}
+
+fn parse_map(lines: &str) -> Result<Map, ParseError> {
+    let map: Map = lines.parse()?;
+    if map.width() == 0 {
+        return Err(ParseError::missing(0, "", "contraption"));
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests_parse_map {
+    use super::*;
+
+    #[test]
+    fn parse_map_malformed() {
+        let error = parse_map(".|.\n.x.").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.reason, "unknown tile");
+        assert_eq!(
+            parse_map("\n"),
+            Err(ParseError::missing(0, "", "contraption"))
+        );
+    }
+}
+
+/// Bottom-right corner of the map
+fn get_bounding_rect(map: &Map) -> (usize, usize) {
+    (map.width() - 1, map.height() - 1)
+}
+
+fn count_energized(map: &Map) -> usize {
+    map.iter().map(|(_, t)| t.energized.clamp(0, 1)).sum()
+}
+
+fn display_map(map: &Map) {
+    display::display_map(&map.bounding_rect(), |c| {
+        let tmp = &map[*c];
+        match tmp.energized {
+            0 => tmp.content.to_string().blue(),
+            1 => tmp.content.to_string().bright_yellow().bold(),
+            _ => tmp.content.to_string().to_string().bright_yellow().bold(),
+        }
+    });
+}
+
+fn update_tile(map: &mut Map, direction: Direction, coords: &Coords) -> (Tile, Vec<Ray>) {
+    let tile = &mut map[*coords];
+
+    tile.energized = 1;
+
+    let new_directions = match (tile.content, direction.is_vertical()) {
+        ('.', _) | ('-', false) | ('|', true) => vec![],
+        ('-', true) | ('|', false) => vec![direction.turn_left(), direction.turn_right()],
+        ('/', true) | ('\\', false) => vec![direction.turn_right()],
+        ('/', false) | ('\\', true) => vec![direction.turn_left()],
+        _ => panic!("Invalid tile"),
+    };
+    let new_rays = new_directions
+        .into_iter()
+        .map(|direction| Ray {
+            starting_point: *coords,
+            direction,
+        })
+        .collect();
+    (tile.clone(), new_rays)
+}
+
+fn cast_ray(map: &mut Map, starting_ray: Ray) {
+    let rect = map.bounding_rect();
+    let mut orders: Vec<Ray> = vec![starting_ray];
+    let mut done_rays: HashSet<Ray> = HashSet::new();
+
+    while let Some(ray) = orders.pop() {
+        if done_rays.contains(&ray) {
+            continue;
+        } else {
+            done_rays.insert(ray.clone());
+        }
+        map[ray.starting_point].energized += 1;
+        trace!("Handling {:?}", ray);
+
+        // Go straight until a mirror or a splitter sends the ray elsewhere
+        let mut position = ray.starting_point;
+        while let Some(next) = ray.direction.step(position, &rect) {
+            let (_, new_orders) = update_tile(map, ray.direction, &next);
+            if !new_orders.is_empty() {
+                orders.extend(new_orders);
+                break;
+            }
+            position = next;
+        }
+    }
+}
+
+/// Count the tiles energized by a single ray, leaving the map untouched
+fn count_energized_by(map: &Map, ray: Ray) -> usize {
+    let mut tmp_map = map.clone();
+    cast_ray(&mut tmp_map, ray);
+    count_energized(&tmp_map)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        let mut map = input.clone();
+
+        display_map(&map);
+
+        trace!("Casting Rays…");
+        cast_ray(
+            &mut map,
+            Ray {
+                starting_point: Coords::new(0, 0),
+                direction: Direction::South,
+            },
+        );
+        trace!("Done casting rays !");
+
+        display_map(&map);
+
+        Some(count_energized(&map))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let map = input;
+
+        trace!("Energizing…");
+
+        let br = get_bounding_rect(map);
+        let rays = (0..=br.1)
+            .map(|y| (Coords::new(0, y), Direction::East))
+            .chain((0..=br.0).map(|x| (Coords::new(x, br.1), Direction::North)))
+            .chain((0..=br.0).map(|x| (Coords::new(x, 0), Direction::South)))
+            .chain((0..=br.1).map(|y| (Coords::new(br.0, y), Direction::West)));
+        let energized_values: Vec<usize> = rays
+            .map(|(starting_point, direction)| {
+                count_energized_by(
+                    map,
+                    Ray {
+                        starting_point,
+                        direction,
+                    },
+                )
+            })
+            .collect();
+
+        trace!("Done casting rays !");
+
+        energized_values.into_iter().max()
+    }
+}
+
+#[cfg(test)]
+mod tests_cast_ray {
+    use super::*;
+    use aoc_core::input::get_file_content;
+
+    #[test]
+    fn cast_ray_right() {
+        let mut map: Map = parse_map(".-..").unwrap();
+        cast_ray(
+            &mut map,
+            Ray {
+                starting_point: Coords::new(0, 0),
+                direction: Direction::East,
+            },
+        );
+        assert_eq!(count_energized(&map), 4);
+    }
+
+    #[test]
+    fn cast_ray_left() {
+        let mut map: Map = parse_map("..-.").unwrap();
+        cast_ray(
+            &mut map,
+            Ray {
+                starting_point: Coords::new(3, 0),
+                direction: Direction::West,
+            },
+        );
+        assert_eq!(count_energized(&map), 4);
+    }
+
+    #[test]
+    fn cast_ray_up() {
+        let mut map: Map = parse_map(
+            ".
+.
+|
+.",
+        )
+        .unwrap();
+        cast_ray(
+            &mut map,
+            Ray {
+                starting_point: Coords::new(0, 3),
+                direction: Direction::North,
+            },
+        );
+        assert_eq!(count_energized(&map), 4);
+    }
+
+    #[test]
+    fn cast_ray_down() {
+        let mut map: Map = parse_map(
+            ".
+|
+.
+.",
+        )
+        .unwrap();
+        cast_ray(
+            &mut map,
+            Ray {
+                starting_point: Coords::new(0, 0),
+                direction: Direction::South,
+            },
+        );
+        assert_eq!(count_energized(&map), 4);
+    }
+
+    #[test]
+    fn cast_ray_integration_test_00() {
+        let mut map: Map = parse_map(
+            "\\...\\.............
+.............|/...
+....\\......-.....|
+|.....-....\\.|....
+............../.|.
+.-.-...|....-.-...
+..........\\.....|.
+...../............
+......\\......\\....
+.....|./..........
+...../...../......
+..\\...............
+....|.........-.|.
+.........-........
+.............|....
+................./",
+        )
+        .unwrap();
+        cast_ray(
+            &mut map,
+            Ray {
+                starting_point: Coords::new(0, 0),
+                direction: Direction::South,
+            },
+        );
+        display_map(&map);
+        assert_eq!(count_energized(&map), 16);
+    }
+
+    #[test]
+    fn cast_ray_integration_test_01() {
+        let mut map: Map = parse_map(
+            "\\........-.........\\................................|...
+......-/.............|-.../.....|...........././..\\.....
+-.........................|.....\\...................|.\\.
+.......-........../.......\\.........|..../........-.-|..",
+        )
+        .unwrap();
+        cast_ray(
+            &mut map,
+            Ray {
+                starting_point: Coords::new(0, 0),
+                direction: Direction::South,
+            },
+        );
+        display_map(&map);
+        assert_eq!(count_energized(&map), 89);
+    }
+
+    #[test]
+    #[ignore = "needs assets/input_alternate, which is not committed"]
+    fn cast_ray_integration_test_02() {
+        let mut map: Map = parse_map(&get_file_content("assets/input_alternate").unwrap()).unwrap();
+        cast_ray(
+            &mut map,
+            Ray {
+                starting_point: Coords::new(0, 0),
+                direction: Direction::East,
+            },
+        );
+        assert_eq!(count_energized(&map), 101);
+    }
+
+    #[test]
+    #[ignore = "needs assets/input_short, which is not committed"]
+    fn cast_ray_integration_test_03() {
+        let mut map: Map = parse_map(&get_file_content("assets/input_short").unwrap()).unwrap();
+        cast_ray(
+            &mut map,
+            Ray {
+                starting_point: Coords::new(0, 0),
+                direction: Direction::East,
+            },
+        );
+        assert_eq!(count_energized(&map), 46);
+    }
+}
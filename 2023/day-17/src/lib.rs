@@ -0,0 +1,406 @@
+use std::{fmt::Display, str::FromStr};
+
+use aoc_core::{
+    direction::{Compass, Direction},
+    display::display_map,
+    grid::Grid,
+};
+use aoc_core::{parse::ParseError, solution::Solution};
+use colored::Colorize;
+use pathfinding::prelude::dijkstra;
+
+// i32 to account for inexistent, but requested nonetheless, negative values
+type Coords = aoc_core::coords::Coords<i32>;
+
+/// Heat lost when entering a city block
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct HeatLoss(usize);
+
+impl TryFrom<char> for HeatLoss {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        c.to_digit(10).map(|d| HeatLoss(d as usize)).ok_or(())
+    }
+}
+
+type Map = Grid<HeatLoss>;
+
+fn parse_map(lines: &str) -> Result<Map, ParseError> {
+    lines.parse()
+}
+
+fn display_map_with_path(map: &Map, path: &[Node]) {
+    display_map(&map.bounding_rect(), |coords| {
+        let tmp = map[*coords].0;
+        if let Some(n) = path.iter().find(|n| {
+            n.current
+                == Coords {
+                    x: coords.x as i32,
+                    y: coords.y as i32,
+                }
+        }) {
+            // A single character, to prevent offsetting the grid
+            std::char::from_digit(n.straight as u32, 36)
+                .unwrap_or('+')
+                .to_ascii_uppercase()
+                .to_string()
+                .bright_yellow()
+                .bold()
+        } else {
+            tmp.to_string().blue()
+        }
+    });
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
+struct Node {
+    current: Coords,
+    previous: Coords,
+    direction: Direction,
+    straight: usize,
+}
+
+/// How a crucible moves.
+///
+/// A crucible has to move `min_run` blocks in a straight line before turning
+/// (or stopping), and cannot move more than `max_run` blocks in a straight
+/// line. Each turn loses `turn_penalty` more heat.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Crucible {
+    pub min_run: usize,
+    pub max_run: usize,
+    pub turn_penalty: usize,
+}
+
+impl Crucible {
+    /// The crucible of part 1
+    pub const REGULAR: Crucible = Crucible {
+        min_run: 1,
+        max_run: 3,
+        turn_penalty: 0,
+    };
+    /// The ultra crucible of part 2
+    pub const ULTRA: Crucible = Crucible {
+        min_run: 4,
+        max_run: 10,
+        turn_penalty: 0,
+    };
+}
+
+impl Display for Crucible {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.min_run, self.max_run)?;
+        if self.turn_penalty > 0 {
+            write!(f, "+{}", self.turn_penalty)?;
+        }
+        Ok(())
+    }
+}
+
+/// Either `regular`, `ultra` or `<min run>-<max run>`, optionally followed by
+/// `+<turn penalty>`
+impl FromStr for Crucible {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid crucible `{}`, expected e.g. `4-10+2`", s);
+        match s {
+            "regular" => return Ok(Crucible::REGULAR),
+            "ultra" => return Ok(Crucible::ULTRA),
+            _ => {}
+        }
+        let (runs, turn_penalty) = s.split_once('+').unwrap_or((s, "0"));
+        let (min_run, max_run) = runs.split_once('-').ok_or_else(invalid)?;
+        let crucible = Crucible {
+            min_run: min_run.parse().map_err(|_| invalid())?,
+            max_run: max_run.parse().map_err(|_| invalid())?,
+            turn_penalty: turn_penalty.parse().map_err(|_| invalid())?,
+        };
+        if crucible.max_run == 0 || crucible.min_run > crucible.max_run {
+            return Err(invalid());
+        }
+        Ok(crucible)
+    }
+}
+
+#[cfg(test)]
+mod tests_crucible {
+    use super::*;
+
+    #[test]
+    fn crucible_from_str() {
+        assert_eq!("regular".parse(), Ok(Crucible::REGULAR));
+        assert_eq!("4-10".parse(), Ok(Crucible::ULTRA));
+        assert_eq!(
+            "2-5+3".parse(),
+            Ok(Crucible {
+                min_run: 2,
+                max_run: 5,
+                turn_penalty: 3
+            })
+        );
+        assert!("5-2".parse::<Crucible>().is_err());
+        assert!("0-0".parse::<Crucible>().is_err());
+        assert!("3".parse::<Crucible>().is_err());
+    }
+
+    #[test]
+    fn crucible_display() {
+        let crucible: Crucible = "2-5+3".parse().unwrap();
+        assert_eq!(crucible.to_string(), "2-5+3");
+        assert_eq!(Crucible::ULTRA.to_string(), "4-10");
+    }
+}
+
+/// The nodes a crucible can reach from the current one, along with the heat
+/// lost getting there.
+fn get_successors(map: &Map, current_node: &Node, crucible: &Crucible) -> Vec<(Node, usize)> {
+    let current = current_node.current;
+    let direction = current_node.direction;
+    let straight = current_node.straight;
+
+    Direction::ALL
+        .iter()
+        .filter(|&&new_direction| new_direction != direction.reverse())
+        .filter(|&&new_direction| {
+            if new_direction == direction {
+                straight < crucible.max_run
+            } else {
+                straight >= crucible.min_run
+            }
+        })
+        .filter_map(|&new_direction| {
+            let next = new_direction.moved(current, 1);
+            let penalty = if new_direction == direction {
+                0
+            } else {
+                crucible.turn_penalty
+            };
+            map.get(next).map(|HeatLoss(heat_loss)| {
+                (
+                    Node {
+                        current: next,
+                        previous: current,
+                        direction: new_direction,
+                        straight: if new_direction == direction {
+                            straight + 1
+                        } else {
+                            1
+                        },
+                    },
+                    heat_loss + penalty,
+                )
+            })
+        })
+        .collect()
+}
+
+/// The path of least heat loss, and how much is lost along it
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Route {
+    heat_loss: usize,
+    path: Vec<Node>,
+}
+
+/// Find the route losing the least heat from the top left to the bottom right
+/// block, or `None` if the crucible cannot get there
+fn get_minimal_heat_loss(map: &Map, crucible: &Crucible) -> Option<Route> {
+    let end = Coords {
+        x: map.width() as i32 - 1,
+        y: map.height() as i32 - 1,
+    };
+
+    // Two possibilities, going from the top to the bottom
+    // or from the left to the right
+    let starting_nodes = [
+        Node {
+            current: Coords { x: 0, y: 0 },
+            previous: Coords { x: 0, y: -1 },
+            direction: Direction::South,
+            straight: 0,
+        },
+        Node {
+            current: Coords { x: 0, y: 0 },
+            previous: Coords { x: -1, y: 0 },
+            direction: Direction::East,
+            straight: 0,
+        },
+    ];
+
+    starting_nodes
+        .iter()
+        .filter_map(|starting_node| {
+            dijkstra(
+                starting_node,
+                |node| get_successors(map, node, crucible),
+                |p| {
+                    p.current == end
+                    // We have to ensure that even the last node is conform to the
+                    // movement constraints
+                    && p.straight >= crucible.min_run
+                    && p.straight <= crucible.max_run
+                },
+            )
+        })
+        .min_by_key(|(_, heat_loss)| *heat_loss)
+        .map(|(path, heat_loss)| Route { heat_loss, path })
+}
+
+/// The minimal heat loss with the given crucible, showing its route at trace
+/// level. Lets the runner try other crucibles than the ones of both parts.
+pub fn solve_with(map: &Map, crucible: &Crucible) -> Option<usize> {
+    let route = get_minimal_heat_loss(map, crucible)?;
+    display_map_with_path(map, &route.path);
+    Some(route.heat_loss)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        solve_with(input, &Crucible::REGULAR)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        solve_with(input, &Crucible::ULTRA)
+    }
+}
+
+#[cfg(test)]
+mod tests_get_minimal_heat_loss {
+    use super::*;
+
+    #[test]
+    fn get_minimal_heat_loss_01() {
+        let map = parse_map(
+            "\
+11199
+12199
+99199
+99131
+99111",
+        )
+        .unwrap();
+        assert_eq!(solve_with(&map, &Crucible::REGULAR), Some(9))
+    }
+
+    #[test]
+    fn get_minimal_heat_loss_02() {
+        let map = parse_map(
+            "\
+1991119
+1111919
+9119911
+9999991",
+        )
+        .unwrap();
+        assert_eq!(solve_with(&map, &Crucible::REGULAR), Some(11))
+    }
+
+    #[test]
+    fn get_minimal_heat_loss_03() {
+        let map = parse_map(
+            "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533",
+        )
+        .unwrap();
+        assert_eq!(solve_with(&map, &Crucible::REGULAR), Some(102))
+    }
+
+    #[test]
+    fn get_minimal_heat_loss_ultra_01() {
+        let map = parse_map(
+            "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991",
+        )
+        .unwrap();
+        assert_eq!(solve_with(&map, &Crucible::ULTRA), Some(71))
+    }
+
+    #[test]
+    fn get_minimal_heat_loss_ultra_02() {
+        let map = parse_map(
+            "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533",
+        )
+        .unwrap();
+        assert_eq!(solve_with(&map, &Crucible::ULTRA), Some(94))
+    }
+
+    #[test]
+    fn get_minimal_heat_loss_path() {
+        let map = parse_map(
+            "\
+11199
+99199
+99111",
+        )
+        .unwrap();
+        let route = get_minimal_heat_loss(&map, &Crucible::REGULAR).unwrap();
+        assert_eq!(route.heat_loss, 6);
+        assert_eq!(
+            route
+                .path
+                .iter()
+                .map(|node| (node.current.x, node.current.y))
+                .collect::<Vec<_>>(),
+            vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (3, 2), (4, 2)]
+        );
+    }
+
+    #[test]
+    fn get_minimal_heat_loss_turn_penalty() {
+        let map = parse_map(
+            "\
+11199
+99199
+99111",
+        )
+        .unwrap();
+        // Runs of at most 3 blocks need at least two turns to get there
+        let crucible = "1-3+1".parse().unwrap();
+        assert_eq!(solve_with(&map, &crucible), Some(8));
+    }
+
+    #[test]
+    fn get_minimal_heat_loss_unreachable() {
+        let map = parse_map("11\n11").unwrap();
+        let crucible = "3-3".parse().unwrap();
+        assert_eq!(solve_with(&map, &crucible), None);
+    }
+}
@@ -0,0 +1,389 @@
+use std::fmt::Display;
+
+use aoc_core::direction::{Compass, Direction};
+use aoc_core::{
+    debug,
+    parse::{parse_field, ParseError},
+    solution::Solution,
+    trace,
+};
+use regex::Regex;
+
+/// The last digit of a color
+fn parse_color_direction(digit: &str) -> Result<Direction, &'static str> {
+    match digit {
+        "3" => Ok(Direction::North),
+        "1" => Ok(Direction::South),
+        "2" => Ok(Direction::West),
+        "0" => Ok(Direction::East),
+        _ => Err("Invalid character"),
+    }
+}
+
+type Coords = aoc_core::coords::Coords<i64>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Map {
+    perimeter: Vec<(i64, i64)>,
+}
+
+type Instruction = (Direction, usize);
+
+/// Both plans of the input: the one that is written, and the one hidden in the
+/// colors
+#[derive(Debug, PartialEq, Clone)]
+pub struct DigPlan {
+    plan: Vec<Instruction>,
+    color_plan: Vec<Instruction>,
+}
+
+fn get_plan_values_from_line(index: usize, line: &str) -> Result<Instruction, ParseError> {
+    let mut split = line.split_whitespace();
+    let direction = split
+        .next()
+        .ok_or_else(|| ParseError::missing(index, line, "direction"))?;
+    let count = split
+        .next()
+        .ok_or_else(|| ParseError::missing(index, line, "distance"))?;
+    Ok((
+        direction
+            .parse::<Direction>()
+            .map_err(|_| ParseError::at(index, line, direction, "unknown direction"))?,
+        parse_field(index, line, count, "distance")?,
+    ))
+}
+
+#[cfg(test)]
+mod tests_get_plan_values_from_line {
+    use super::*;
+
+    #[test]
+    fn get_plan_values_from_line_tests() {
+        assert_eq!(
+            get_plan_values_from_line(0, "R 6 (#70c710)").unwrap(),
+            (Direction::East, 6)
+        );
+        assert_eq!(
+            get_plan_values_from_line(0, "U 12 (#7a21e3)").unwrap(),
+            (Direction::North, 12)
+        );
+    }
+}
+
+fn get_values_from_line(index: usize, line: &str) -> Result<Instruction, ParseError> {
+    let capture = Regex::new(r"\(#([0-9a-f]+)([0-9])\)")
+        .unwrap()
+        .captures(line)
+        .ok_or_else(|| ParseError::line(index, line, "expected a `(#<hexadecimal>)` color"))?;
+    let (distance, direction) = (capture.get(1).unwrap(), capture.get(2).unwrap());
+    Ok((
+        parse_color_direction(direction.as_str())
+            .map_err(|_| ParseError::at(index, line, direction.as_str(), "unknown direction"))?,
+        usize::from_str_radix(distance.as_str(), 16).map_err(|error| {
+            ParseError::at(
+                index,
+                line,
+                distance.as_str(),
+                format!("invalid distance: {}", error),
+            )
+        })?,
+    ))
+}
+
+#[cfg(test)]
+mod tests_get_values_from_line {
+    use super::*;
+
+    #[test]
+    fn get_values_from_line_tests() {
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#70c710)").unwrap(),
+            (Direction::East, 461937)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#0dc571)").unwrap(),
+            (Direction::South, 56407)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#5713f0)").unwrap(),
+            (Direction::East, 356671)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#d2c081)").unwrap(),
+            (Direction::South, 863240)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#59c680)").unwrap(),
+            (Direction::East, 367720)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#411b91)").unwrap(),
+            (Direction::South, 266681)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#8ceee2)").unwrap(),
+            (Direction::West, 577262)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#caa173)").unwrap(),
+            (Direction::North, 829975)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#1b58a2)").unwrap(),
+            (Direction::West, 112010)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#caa171)").unwrap(),
+            (Direction::South, 829975)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#7807d2)").unwrap(),
+            (Direction::West, 491645)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#a77fa3)").unwrap(),
+            (Direction::North, 686074)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#015232)").unwrap(),
+            (Direction::West, 5411)
+        );
+        assert_eq!(
+            get_values_from_line(0, "X 9 (#7a21e3)").unwrap(),
+            (Direction::North, 500254)
+        );
+    }
+}
+
+fn parse_dig_plan(lines: &str) -> Result<DigPlan, ParseError> {
+    Ok(DigPlan {
+        plan: lines
+            .lines()
+            .enumerate()
+            .map(|(index, line)| get_plan_values_from_line(index, line))
+            .collect::<Result<_, _>>()?,
+        color_plan: lines
+            .lines()
+            .enumerate()
+            .map(|(index, line)| get_values_from_line(index, line))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+#[cfg(test)]
+mod tests_parse_dig_plan {
+    use super::*;
+
+    #[test]
+    fn parse_dig_plan_malformed() {
+        assert_eq!(
+            parse_dig_plan("R 6 (#70c710)\nQ 5 (#0dc571)"),
+            Err(ParseError {
+                line: 2,
+                column: 1,
+                text: String::from("Q"),
+                reason: String::from("unknown direction"),
+            })
+        );
+        assert_eq!(
+            parse_dig_plan("R 6 (#70c710)\nD 5"),
+            Err(ParseError::line(
+                1,
+                "D 5",
+                "expected a `(#<hexadecimal>)` color"
+            ))
+        );
+        assert_eq!(
+            parse_dig_plan("R 6 (#70c717)"),
+            Err(ParseError {
+                line: 1,
+                column: 12,
+                text: String::from("7"),
+                reason: String::from("unknown direction"),
+            })
+        );
+        assert_eq!(
+            parse_dig_plan("R"),
+            Err(ParseError::missing(0, "R", "distance"))
+        );
+    }
+}
+
+fn build_map(plan: &[Instruction]) -> Map {
+    let mut perimeter_vec: Vec<(i64, i64)> = vec![];
+    let mut current_point = Coords { x: 5, y: 5 };
+
+    perimeter_vec.push((current_point.x, current_point.y));
+    for (direction, count) in plan.iter().cloned() {
+        current_point = direction.moved(current_point, count as i64);
+        perimeter_vec.push((current_point.x, current_point.y));
+    }
+
+    Map {
+        perimeter: perimeter_vec.clone(),
+    }
+}
+
+trait Fill {
+    fn fill(&mut self) -> u64;
+}
+
+impl Fill for Map {
+    fn fill(&mut self) -> u64 {
+        // dbg!(&self.perimeter);
+        let tmp_perimeter = self.perimeter[1..].to_vec();
+
+        let length = self.perimeter.iter().zip(tmp_perimeter.iter()).fold(
+            0. as u32,
+            |acc, ((x1, y1), (x2, y2))| {
+                acc + (x2 - x1).unsigned_abs() as u32 + (y2 - y1).unsigned_abs() as u32
+            },
+        ) / 2;
+
+        debug!("Perimeter length: {}", length);
+
+        let area: i64 = self
+            .perimeter
+            .iter()
+            .zip(tmp_perimeter.iter())
+            .fold(0. as i64, |acc, ((x1, y1), (x2, y2))| {
+                acc + (*x1 * *y2) - (*x2 * *y1)
+            })
+            / 2;
+
+        debug!("Inner area: {}", area);
+
+        area.unsigned_abs() + length as u64 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests_fill {
+    use super::*;
+
+    #[test]
+    fn fill_01() {
+        let mut map = build_map(
+            &parse_dig_plan(
+                "\
+R 5 (#000020)
+D 3 (#000021)
+L 3 (#000022)
+U 5 (#000023)",
+            )
+            .unwrap()
+            .color_plan,
+        );
+        assert_eq!(map.fill(), 9);
+    }
+
+    #[test]
+    fn fill_02() {
+        let mut map = build_map(
+            &parse_dig_plan(
+                "\
+D 2 (#000021)
+R 2 (#000020)
+U 2 (#000023)
+R 2 (#000020)
+U 2 (#000023)
+L 2 (#000022)
+D 2 (#000021)
+L 2 (#000022)",
+            )
+            .unwrap()
+            .color_plan,
+        );
+        assert_eq!(map.fill(), 17);
+    }
+
+    #[test]
+    fn fill_plan() {
+        let plan = parse_dig_plan(
+            "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)",
+        )
+        .unwrap();
+        assert_eq!(build_map(&plan.plan).fill(), 62);
+
+        let plan = parse_dig_plan(
+            "\
+D 4 (#000000)
+R 1 (#000000)
+D 1 (#000000)
+R 4 (#000000)
+R 5 (#000000)
+U 5 (#000000)
+L 4 (#000000)
+D 3 (#000000)
+L 3 (#000000)
+U 3 (#000000)
+L 3 (#000000)",
+        )
+        .unwrap();
+        assert_eq!(build_map(&plan.plan).fill(), 59);
+    }
+
+    #[test]
+    fn fill_03() {
+        let mut map = build_map(
+            &parse_dig_plan(
+                "\
+    R 6 (#70c710)
+    D 5 (#0dc571)
+    L 2 (#5713f0)
+    D 2 (#d2c081)
+    R 2 (#59c680)
+    D 2 (#411b91)
+    L 5 (#8ceee2)
+    U 2 (#caa173)
+    L 1 (#1b58a2)
+    U 2 (#caa171)
+    R 2 (#7807d2)
+    U 3 (#a77fa3)
+    L 2 (#015232)
+    U 2 (#7a21e3)",
+            )
+            .unwrap()
+            .color_plan,
+        );
+        assert_eq!(map.fill(), 952408144115);
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = DigPlan;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_dig_plan(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(build_map(&input.plan).fill())
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        trace!("Building map…");
+        let mut map = build_map(&input.color_plan);
+
+        trace!("Filling map…");
+        let count = map.fill();
+
+        Some(count)
+    }
+}
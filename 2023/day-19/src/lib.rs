@@ -0,0 +1,905 @@
+use std::fmt::Display;
+
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    range_set::RangeSet,
+    solution::Solution,
+};
+
+/// One of the 4 ratings of a part
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<char> for Category {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'x' => Ok(Category::X),
+            'm' => Ok(Category::M),
+            'a' => Ok(Category::A),
+            's' => Ok(Category::S),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Comparison {
+    LessThan,
+    GreaterThan,
+}
+
+/// Such as `a<2006`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Condition {
+    category: Category,
+    comparison: Comparison,
+    value: usize,
+}
+
+/// Where a rule sends a part
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Target {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+/// Such as `a<2006:qkq`, or `rfg` when there is no condition
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Rule {
+    condition: Option<Condition>,
+    target: Target,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Part {
+    x_rating: usize,
+    m_rating: usize,
+    a_rating: usize,
+    s_rating: usize,
+}
+
+impl Part {
+    fn rating(&self, category: Category) -> usize {
+        match category {
+            Category::X => self.x_rating,
+            Category::M => self.m_rating,
+            Category::A => self.a_rating,
+            Category::S => self.s_rating,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Content {
+    workflows: Vec<Workflow>,
+    parts: Vec<Part>,
+}
+
+fn parse_condition(index: usize, line: &str, text: &str) -> Result<Condition, ParseError> {
+    let mut chars = text.chars();
+    let category = chars
+        .next()
+        .and_then(|c| Category::try_from(c).ok())
+        .ok_or_else(|| ParseError::at(index, line, text, "expected one of `x`, `m`, `a`, `s`"))?;
+    let comparison = match chars.next() {
+        Some('<') => Comparison::LessThan,
+        Some('>') => Comparison::GreaterThan,
+        _ => return Err(ParseError::at(index, line, text, "expected `<` or `>`")),
+    };
+
+    Ok(Condition {
+        category,
+        comparison,
+        value: parse_field(index, line, &text[2..], "rating")?,
+    })
+}
+
+fn parse_target(index: usize, line: &str, text: &str) -> Result<Target, ParseError> {
+    match text {
+        "A" => Ok(Target::Accept),
+        "R" => Ok(Target::Reject),
+        name if !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase()) => {
+            Ok(Target::Workflow(name.to_string()))
+        }
+        _ => Err(ParseError::at(index, line, text, "invalid workflow name")),
+    }
+}
+
+fn parse_rule(index: usize, line: &str, text: &str) -> Result<Rule, ParseError> {
+    match text.split_once(':') {
+        Some((condition, target)) => Ok(Rule {
+            condition: Some(parse_condition(index, line, condition)?),
+            target: parse_target(index, line, target)?,
+        }),
+        None => Ok(Rule {
+            condition: None,
+            target: parse_target(index, line, text)?,
+        }),
+    }
+}
+
+/// `px{a<2006:qkq,m>2090:A,rfg}`
+fn parse_workflow(index: usize, line: &str) -> Result<Workflow, ParseError> {
+    let (name, rules) = line
+        .split_once('{')
+        .ok_or_else(|| ParseError::missing(index, line, "`{` after the workflow name"))?;
+    let rules = rules
+        .strip_suffix('}')
+        .ok_or_else(|| ParseError::missing(index, line, "`}` after the rules"))?;
+    if name.is_empty() {
+        return Err(ParseError::line(index, line, "missing workflow name"));
+    }
+
+    let rules = rules
+        .split(',')
+        .map(|rule| parse_rule(index, line, rule))
+        .collect::<Result<Vec<Rule>, ParseError>>()?;
+    // Only the last rule can (and has to) apply to every part
+    if let Some(rule) = rules[..rules.len() - 1]
+        .iter()
+        .find(|rule| rule.condition.is_none())
+    {
+        let text = match &rule.target {
+            Target::Workflow(name) => name.as_str(),
+            Target::Accept => "A",
+            Target::Reject => "R",
+        };
+        return Err(ParseError::at(
+            index,
+            line,
+            text,
+            "unreachable rules after this one",
+        ));
+    }
+    if rules.last().unwrap().condition.is_some() {
+        return Err(ParseError::missing(index, line, "fallback rule"));
+    }
+
+    Ok(Workflow {
+        name: name.to_string(),
+        rules,
+    })
+}
+
+/// `{x=787,m=2655,a=1222,s=2876}`
+fn parse_part(index: usize, line: &str) -> Result<Part, ParseError> {
+    let ratings = line
+        .strip_prefix('{')
+        .and_then(|ratings| ratings.strip_suffix('}'))
+        .ok_or_else(|| ParseError::line(index, line, "expected `{x=…,m=…,a=…,s=…}`"))?;
+
+    let mut values = [None; 4];
+    for rating in ratings.split(',') {
+        let (category, value) = rating
+            .split_once('=')
+            .ok_or_else(|| ParseError::at(index, line, rating, "expected `<category>=<rating>`"))?;
+        let slot = match category {
+            "x" => 0,
+            "m" => 1,
+            "a" => 2,
+            "s" => 3,
+            _ => return Err(ParseError::at(index, line, category, "unknown category")),
+        };
+        values[slot] = Some(parse_field(index, line, value, "rating")?);
+    }
+    let [Some(x_rating), Some(m_rating), Some(a_rating), Some(s_rating)] = values else {
+        return Err(ParseError::line(
+            index,
+            line,
+            "every category needs a rating",
+        ));
+    };
+
+    Ok(Part {
+        x_rating,
+        m_rating,
+        a_rating,
+        s_rating,
+    })
+}
+
+fn parse_content(input: &str) -> Result<Content, ParseError> {
+    let mut workflows = vec![];
+    let mut parts = vec![];
+    // Remember where each workflow was defined, to report unknown targets
+    let mut workflow_lines = vec![];
+
+    let mut lines = input.lines().enumerate();
+    for (index, line) in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        let workflow = parse_workflow(index, line)?;
+        if workflows.iter().any(|w: &Workflow| w.name == workflow.name) {
+            return Err(ParseError::at(
+                index,
+                line,
+                &workflow.name,
+                "workflow defined twice",
+            ));
+        }
+        workflows.push(workflow);
+        workflow_lines.push((index, line));
+    }
+    for (index, line) in lines.filter(|(_, line)| !line.is_empty()) {
+        parts.push(parse_part(index, line)?);
+    }
+
+    for (workflow, &(index, line)) in workflows.iter().zip(&workflow_lines) {
+        for rule in &workflow.rules {
+            if let Target::Workflow(name) = &rule.target {
+                if !workflows.iter().any(|w| &w.name == name) {
+                    return Err(ParseError::at(
+                        index,
+                        line,
+                        target_text(line, name),
+                        "unknown workflow",
+                    ));
+                }
+            }
+        }
+    }
+    let Some(start) = workflows.iter().position(|w| w.name == "in") else {
+        return Err(ParseError {
+            line: 1,
+            column: 1,
+            text: String::new(),
+            reason: String::from("missing workflow `in`"),
+        });
+    };
+    if let Some((from, name)) =
+        find_cycle(&workflows, start, &mut vec![Visit::New; workflows.len()])
+    {
+        let (index, line) = workflow_lines[from];
+        return Err(ParseError::at(
+            index,
+            line,
+            target_text(line, name),
+            "workflow cycle, parts sent there would never be sorted",
+        ));
+    }
+
+    Ok(Content { workflows, parts })
+}
+
+/// Where `name` is the target of a rule in a workflow line, rather than part of
+/// the workflow name or of another target
+fn target_text<'a>(line: &'a str, name: &str) -> &'a str {
+    line.match_indices(name)
+        .find(|(offset, _)| {
+            matches!(line[..*offset].chars().last(), Some(':' | ',' | '{'))
+                && matches!(line[offset + name.len()..].chars().next(), Some(',' | '}'))
+        })
+        .map_or(line, |(_, text)| text)
+}
+
+/// Where a depth-first search over the workflows stands with each of them
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Visit {
+    New,
+    InProgress,
+    Done,
+}
+
+/// A rule leading back to a workflow the search is still going through, as the
+/// index of the workflow holding it and the name it leads to. Every target is
+/// known to exist.
+fn find_cycle<'a>(
+    workflows: &'a [Workflow],
+    current: usize,
+    visits: &mut [Visit],
+) -> Option<(usize, &'a str)> {
+    visits[current] = Visit::InProgress;
+    for rule in &workflows[current].rules {
+        let Target::Workflow(name) = &rule.target else {
+            continue;
+        };
+        let next = workflows.iter().position(|w| &w.name == name).unwrap();
+        match visits[next] {
+            Visit::InProgress => return Some((current, name)),
+            Visit::New => {
+                if let Some(cycle) = find_cycle(workflows, next, visits) {
+                    return Some(cycle);
+                }
+            }
+            Visit::Done => {}
+        }
+    }
+    visits[current] = Visit::Done;
+    None
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        assert_eq!(
+            parse_content(
+                "\
+px{a<2006:qkq,m>2090:A,rfg}
+in{a>1716:R,A}
+qkq{x<2:A,R}
+rfg{A}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}"
+            )
+            .unwrap(),
+            Content {
+                workflows: vec![
+                    Workflow {
+                        name: "px".to_string(),
+                        rules: vec![
+                            Rule {
+                                condition: Some(Condition {
+                                    category: Category::A,
+                                    comparison: Comparison::LessThan,
+                                    value: 2006
+                                }),
+                                target: Target::Workflow("qkq".to_string())
+                            },
+                            Rule {
+                                condition: Some(Condition {
+                                    category: Category::M,
+                                    comparison: Comparison::GreaterThan,
+                                    value: 2090
+                                }),
+                                target: Target::Accept
+                            },
+                            Rule {
+                                condition: None,
+                                target: Target::Workflow("rfg".to_string())
+                            }
+                        ]
+                    },
+                    Workflow {
+                        name: "in".to_string(),
+                        rules: vec![
+                            Rule {
+                                condition: Some(Condition {
+                                    category: Category::A,
+                                    comparison: Comparison::GreaterThan,
+                                    value: 1716
+                                }),
+                                target: Target::Reject
+                            },
+                            Rule {
+                                condition: None,
+                                target: Target::Accept
+                            }
+                        ]
+                    },
+                    Workflow {
+                        name: "qkq".to_string(),
+                        rules: vec![
+                            Rule {
+                                condition: Some(Condition {
+                                    category: Category::X,
+                                    comparison: Comparison::LessThan,
+                                    value: 2
+                                }),
+                                target: Target::Accept
+                            },
+                            Rule {
+                                condition: None,
+                                target: Target::Reject
+                            }
+                        ]
+                    },
+                    Workflow {
+                        name: "rfg".to_string(),
+                        rules: vec![Rule {
+                            condition: None,
+                            target: Target::Accept
+                        }]
+                    }
+                ],
+                parts: vec![
+                    Part {
+                        x_rating: 787,
+                        m_rating: 2655,
+                        a_rating: 1222,
+                        s_rating: 2876
+                    },
+                    Part {
+                        x_rating: 1679,
+                        m_rating: 44,
+                        a_rating: 2067,
+                        s_rating: 496
+                    }
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn parse_content_malformed() {
+        let error = |input: &str| {
+            let error = parse_content(input).unwrap_err();
+            (error.line, error.column, error.reason)
+        };
+        assert_eq!(
+            error("in{a=2006:A,R}"),
+            (1, 4, String::from("expected `<` or `>`"))
+        );
+        assert_eq!(
+            error("in{q<2006:A,R}"),
+            (1, 4, String::from("expected one of `x`, `m`, `a`, `s`"))
+        );
+        assert_eq!(
+            error("in{a<20x6:A,R}"),
+            (
+                1,
+                6,
+                String::from("invalid rating: invalid digit found in string")
+            )
+        );
+        assert_eq!(
+            error("in{a<2006:A}"),
+            (1, 13, String::from("missing fallback rule"))
+        );
+        assert_eq!(
+            error("in{A,a<2006:R,R}"),
+            (1, 4, String::from("unreachable rules after this one"))
+        );
+        assert_eq!(
+            error("in{a<2006:px,R}"),
+            (1, 11, String::from("unknown workflow"))
+        );
+        assert_eq!(
+            error("in{R}\n\n{x=1,m=2,a=3}"),
+            (3, 1, String::from("every category needs a rating"))
+        );
+    }
+
+    #[test]
+    fn parse_content_cycle() {
+        let error = parse_content("in{x<5:in,A}").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 8));
+        assert_eq!(
+            error.reason,
+            "workflow cycle, parts sent there would never be sorted"
+        );
+        let error = parse_content("in{x<5:px,A}\npx{qk}\nqk{m>2:A,px}").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 10));
+        // Only the workflows reachable from `in` matter
+        assert!(parse_content("in{x<5:px,A}\npx{R}\nqk{qk}").is_ok());
+        // Reaching the same workflow twice is not a cycle
+        assert!(parse_content("in{x<5:px,qk}\nqk{px}\npx{A}").is_ok());
+    }
+
+    #[test]
+    fn parse_content_duplicate() {
+        let error = parse_content("in{x<5:px,A}\npx{A}\npx{R}").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.reason, "workflow defined twice");
+    }
+}
+
+impl Condition {
+    fn matches(&self, part: &Part) -> bool {
+        let rating = part.rating(self.category);
+        match self.comparison {
+            Comparison::LessThan => rating < self.value,
+            Comparison::GreaterThan => rating > self.value,
+        }
+    }
+
+    /// Split `ratings` into the ones matching this condition, and the others
+    fn split(&self, ratings: &RangeSet<usize>) -> (RangeSet<usize>, RangeSet<usize>) {
+        match self.comparison {
+            Comparison::LessThan => ratings.split_at(self.value),
+            Comparison::GreaterThan => {
+                let (below, above) = ratings.split_at(self.value + 1);
+                (above, below)
+            }
+        }
+    }
+}
+
+/// Target of the first rule matching `part`
+fn get_next_destination<'a>(rules: &'a [Rule], part: &Part) -> &'a Target {
+    &rules
+        .iter()
+        .find(|rule| rule.condition.is_none_or(|c| c.matches(part)))
+        .expect("The last rule of a workflow has no condition")
+        .target
+}
+
+#[cfg(test)]
+mod tests_get_next_destination {
+    use super::*;
+
+    fn rules(text: &str) -> Vec<Rule> {
+        parse_workflow(0, &format!("in{{{}}}", text)).unwrap().rules
+    }
+
+    fn part(x_rating: usize) -> Part {
+        Part {
+            x_rating,
+            m_rating: 0,
+            a_rating: 0,
+            s_rating: 0,
+        }
+    }
+
+    #[test]
+    fn execute_instructions_01() {
+        assert_eq!(
+            get_next_destination(&rules("x>10:A,R"), &part(11)),
+            &Target::Accept
+        );
+    }
+
+    #[test]
+    fn execute_instructions_02() {
+        assert_eq!(
+            get_next_destination(&rules("x<10:A,R"), &part(11)),
+            &Target::Reject
+        );
+    }
+
+    #[test]
+    fn execute_instructions_03() {
+        assert_eq!(
+            get_next_destination(&rules("x<10:A,x>10:px,R"), &part(11)),
+            &Target::Workflow(String::from("px"))
+        );
+    }
+}
+
+fn find_workflow<'a>(workflows: &'a [Workflow], name: &str) -> &'a Workflow {
+    workflows
+        .iter()
+        .find(|w| w.name == name)
+        .unwrap_or_else(|| panic!("No workflow named {}", name))
+}
+
+/// Whether `part` ends up accepted, starting from the `in` workflow
+fn execute_workflows(workflows: &[Workflow], part: &Part) -> bool {
+    let mut workflow = find_workflow(workflows, "in");
+    loop {
+        match get_next_destination(&workflow.rules, part) {
+            Target::Accept => return true,
+            Target::Reject => return false,
+            Target::Workflow(name) => workflow = find_workflow(workflows, name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_execute_workflows {
+    use super::*;
+
+    #[test]
+    fn execute_workflows_01() {
+        let content = parse_content(
+            "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}",
+        )
+        .unwrap();
+        assert_eq!(
+            content
+                .parts
+                .iter()
+                .map(|part| execute_workflows(&content.workflows, part))
+                .collect::<Vec<bool>>(),
+            vec![true, false, true, false, true]
+        )
+    }
+}
+
+/// Every rating each category can still have
+#[derive(Debug, PartialEq, Clone)]
+struct Combinations {
+    x: RangeSet<usize>,
+    m: RangeSet<usize>,
+    a: RangeSet<usize>,
+    s: RangeSet<usize>,
+}
+
+impl Combinations {
+    fn ratings_mut(&mut self, category: Category) -> &mut RangeSet<usize> {
+        match category {
+            Category::X => &mut self.x,
+            Category::M => &mut self.m,
+            Category::A => &mut self.a,
+            Category::S => &mut self.s,
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.x.len() * self.m.len() * self.a.len() * self.s.len()
+    }
+}
+
+/// Where the rules send each subset of `current_combinations`
+fn count_parts_that_satisfies_instructions<'a>(
+    rules: &'a [Rule],
+    current_combinations: &Combinations,
+) -> Vec<(&'a Target, Combinations)> {
+    let mut remaining = current_combinations.clone();
+
+    rules
+        .iter()
+        .map(|rule| match rule.condition {
+            Some(condition) => {
+                let ratings = remaining.ratings_mut(condition.category);
+                let (matching, others) = condition.split(ratings);
+                *ratings = others;
+
+                let mut sent = remaining.clone();
+                *sent.ratings_mut(condition.category) = matching;
+                (&rule.target, sent)
+            }
+            None => (&rule.target, remaining.clone()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_count_parts_that_satisfies_instructions {
+    use super::*;
+
+    fn rules(text: &str) -> Vec<Rule> {
+        parse_workflow(0, &format!("in{{{}}}", text)).unwrap().rules
+    }
+
+    fn all() -> Combinations {
+        Combinations {
+            x: RangeSet::from(1..=4000),
+            m: RangeSet::from(1..=4000),
+            a: RangeSet::from(1..=4000),
+            s: RangeSet::from(1..=4000),
+        }
+    }
+
+    #[test]
+    fn count_parts_that_satisfies_instructions_01() {
+        let rules = rules("x>10:A,R");
+        let collection = count_parts_that_satisfies_instructions(&rules, &all());
+        assert_eq!(collection[0].1.x.len(), 3990);
+        assert_eq!(collection[0].1.m.len(), 4000);
+        assert_eq!(collection[0].1.a.len(), 4000);
+        assert_eq!(collection[0].1.s.len(), 4000);
+    }
+
+    #[test]
+    fn count_parts_that_satisfies_instructions_02() {
+        let rules = rules("x<10:A,R");
+        let collection = count_parts_that_satisfies_instructions(&rules, &all());
+        assert_eq!(collection[0].1.x.len(), 9);
+        assert_eq!(collection[0].1.m.len(), 4000);
+        assert_eq!(collection[0].1.a.len(), 4000);
+        assert_eq!(collection[0].1.s.len(), 4000);
+    }
+
+    #[test]
+    fn count_parts_that_satisfies_instructions_03() {
+        let rules = rules("x<10:png,m>10:R,A");
+        let collection = count_parts_that_satisfies_instructions(&rules, &all());
+        assert_eq!(collection.len(), 3);
+
+        assert_eq!(collection[0].0, &Target::Workflow(String::from("png")));
+        assert_eq!(collection[0].1.x.len(), 9);
+        assert_eq!(collection[0].1.m.len(), 4000);
+        assert_eq!(collection[0].1.a.len(), 4000);
+        assert_eq!(collection[0].1.s.len(), 4000);
+
+        assert_eq!(collection[1].1.x.len(), 3991);
+        assert_eq!(collection[1].1.m.len(), 3990);
+        assert_eq!(collection[1].1.a.len(), 4000);
+        assert_eq!(collection[1].1.s.len(), 4000);
+
+        assert_eq!(collection[2].1.x.len(), 3991);
+        assert_eq!(collection[2].1.m.len(), 10);
+        assert_eq!(collection[2].1.a.len(), 4000);
+        assert_eq!(collection[2].1.s.len(), 4000);
+    }
+}
+
+fn calc_combinations(input: &[(&Target, Combinations)]) -> usize {
+    input
+        .iter()
+        .filter(|(target, _)| **target == Target::Accept)
+        .map(|(_, c)| c.count())
+        .sum()
+}
+
+/// Follow `target` with every subset of `combinations` until each one is
+/// either accepted or rejected
+fn get_combinations<'a>(
+    workflows: &'a [Workflow],
+    target: &'a Target,
+    combinations: &Combinations,
+) -> Vec<(&'a Target, Combinations)> {
+    let Target::Workflow(name) = target else {
+        return vec![(target, combinations.clone())];
+    };
+
+    count_parts_that_satisfies_instructions(&find_workflow(workflows, name).rules, combinations)
+        .iter()
+        // Sets that no part can match lead nowhere
+        .filter(|(_, c)| c.count() > 0)
+        .flat_map(|(t, c)| get_combinations(workflows, t, c))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_get_combinations {
+    use super::*;
+
+    fn all() -> Combinations {
+        Combinations {
+            x: RangeSet::from(1..=4000),
+            m: RangeSet::from(1..=4000),
+            a: RangeSet::from(1..=4000),
+            s: RangeSet::from(1..=4000),
+        }
+    }
+
+    #[test]
+    fn get_combinations_01() {
+        let content = parse_content(
+            "\
+in{x<1351:A,qqz}
+qqz{m<10:A,R}",
+        )
+        .unwrap();
+        let start = Target::Workflow(String::from("in"));
+        let collection = get_combinations(&content.workflows, &start, &all());
+        assert_eq!(collection.len(), 3);
+
+        assert_eq!(collection[0].0, &Target::Accept);
+        assert_eq!(collection[0].1.x.len(), 1350);
+        assert_eq!(collection[0].1.m.len(), 4000);
+        assert_eq!(collection[0].1.a.len(), 4000);
+        assert_eq!(collection[0].1.s.len(), 4000);
+
+        assert_eq!(collection[1].0, &Target::Accept);
+        assert_eq!(collection[1].1.x.len(), 2650);
+        assert_eq!(collection[1].1.m.len(), 9);
+        assert_eq!(collection[1].1.a.len(), 4000);
+        assert_eq!(collection[1].1.s.len(), 4000);
+
+        assert_eq!(collection[2].0, &Target::Reject);
+        assert_eq!(collection[2].1.x.len(), 2650);
+        assert_eq!(collection[2].1.m.len(), 3991);
+        assert_eq!(collection[2].1.a.len(), 4000);
+        assert_eq!(collection[2].1.s.len(), 4000);
+
+        assert_eq!(calc_combinations(&collection), 86781600000000);
+    }
+
+    #[test]
+    fn get_combinations_02() {
+        let content = parse_content(
+            "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}",
+        )
+        .unwrap();
+        let start = Target::Workflow(String::from("in"));
+        let collection = get_combinations(&content.workflows, &start, &all());
+        assert_eq!(calc_combinations(&collection), 167409079868000);
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        let data = input;
+
+        Some(
+            // For each part
+            data.parts
+                .iter()
+                // Only keep the `A`ccepted parts
+                .filter(|part| execute_workflows(&data.workflows, part))
+                // Sum their internal rating
+                .map(|p| p.x_rating + p.m_rating + p.a_rating + p.s_rating)
+                // Sum this for all accepted parts
+                .sum::<usize>(),
+        )
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let data = input;
+
+        Some(calc_combinations(&get_combinations(
+            &data.workflows,
+            &Target::Workflow(String::from("in")),
+            &Combinations {
+                x: RangeSet::from(1..=4000),
+                m: RangeSet::from(1..=4000),
+                a: RangeSet::from(1..=4000),
+                s: RangeSet::from(1..=4000),
+            },
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests_day19 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day19_example() {
+        assert_eq!(
+            solve::<Day19>(
+                "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}",
+                &[1, 2]
+            ),
+            Ok(vec![
+                Some(String::from("19114")),
+                Some(String::from("167409079868000"))
+            ])
+        );
+    }
+}
@@ -0,0 +1,608 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+};
+
+use aoc_core::{
+    debug,
+    dot::{Dot, Shape, ToDot},
+    parse::ParseError,
+    solution::Solution,
+};
+use num::integer::lcm;
+use regex::Regex;
+
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+enum Pulse {
+    High,
+    Low,
+}
+
+impl Display for Pulse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pulse::High => write!(f, "HIGH"),
+            Pulse::Low => write!(f, "low"),
+        }
+    }
+}
+
+type ModuleName = String;
+
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+struct FlipFlop {
+    name: String,
+    outputs: Vec<ModuleName>,
+    state: bool,
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+struct Conjunction {
+    name: ModuleName,
+    outputs: Vec<ModuleName>,
+    inputs: Vec<(ModuleName, Pulse)>,
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+struct Broadcaster {
+    name: String,
+    outputs: Vec<ModuleName>,
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+enum Module {
+    F(FlipFlop),
+    C(Conjunction),
+    B(Broadcaster),
+}
+
+type ModuleMap = HashMap<ModuleName, Module>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Content {
+    modules: ModuleMap,
+}
+
+fn parse_content(lines: &str) -> Result<Content, ParseError> {
+    let re_flipflop = Regex::new(r"^%(?<name>.+) -> (?<targets>.*)$").unwrap();
+    let re_conjunction = Regex::new(r"^&(?<name>.+) -> (?<targets>.*)$").unwrap();
+    let re_broadcaster = Regex::new(r"^broadcaster -> (?<targets>.*)$").unwrap();
+
+    let parse_targets = |targets: &str| {
+        targets
+            .split(",")
+            .map(|e| e.replace(" ", ""))
+            .collect::<Vec<ModuleName>>()
+    };
+
+    let mut modules: ModuleMap = HashMap::new();
+    for (index, line) in lines.split("\n").enumerate() {
+        let module = line.trim();
+        // Final \n in file
+        if module.is_empty() {
+            continue;
+        }
+
+        if let Some(caps) = re_flipflop.captures(module) {
+            modules.insert(
+                caps["name"].to_string(),
+                Module::F(FlipFlop {
+                    name: caps["name"].to_string(),
+                    outputs: parse_targets(&caps["targets"]),
+                    state: false,
+                }),
+            );
+        } else if let Some(caps) = re_conjunction.captures(module) {
+            modules.insert(
+                caps["name"].to_string(),
+                Module::C(Conjunction {
+                    name: caps["name"].to_string(),
+                    outputs: parse_targets(&caps["targets"]),
+                    inputs: vec![
+                    // Will be handled next
+                    ],
+                }),
+            );
+        } else if let Some(caps) = re_broadcaster.captures(module) {
+            modules.insert(
+                "broadcaster".to_string(),
+                Module::B(Broadcaster {
+                    name: "broadcaster".to_string(),
+                    outputs: parse_targets(&caps["targets"]),
+                }),
+            );
+        } else {
+            return Err(ParseError::line(
+                index,
+                line,
+                "expected `%name -> targets`, `&name -> targets` or `broadcaster -> targets`",
+            ));
+        }
+    }
+
+    let tmp_modules = modules.clone();
+
+    // Conjunction input update
+    tmp_modules
+        .values()
+        .map(|m| match m {
+            Module::C(c) => Some(c),
+            _ => None,
+        })
+        .filter(|x| x.is_some())
+        .map(|x| if let Some(y) = x { y } else { panic!("WAT") })
+        .for_each(|c| {
+            let list = modules
+                .clone()
+                .values()
+                .map(|n| match n {
+                    Module::F(f) => {
+                        if f.outputs.contains(&c.name) {
+                            f.name.clone()
+                        } else {
+                            "".to_owned()
+                        }
+                    }
+                    Module::C(d) => {
+                        if d.outputs.contains(&c.name) {
+                            d.name.clone()
+                        } else {
+                            "".to_owned()
+                        }
+                    }
+                    Module::B(b) => {
+                        if b.outputs.contains(&c.name) {
+                            "broadcaster".to_owned()
+                        } else {
+                            "".to_owned()
+                        }
+                    }
+                })
+                .filter(|name| !name.is_empty())
+                .map(|n| (n, Pulse::Low))
+                .collect::<Vec<(String, Pulse)>>();
+
+            let mut_c = modules.get_mut(&c.name).unwrap();
+            if let Module::C(m_c) = mut_c {
+                m_c.inputs = list.clone();
+            }
+        });
+
+    Ok(Content { modules })
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        let content = parse_content(
+            "\
+%vh -> qc, pb
+&pb -> gf, gv
+broadcaster -> hd, zj
+",
+        );
+        assert_eq!(
+            content,
+            Ok(Content {
+                modules: HashMap::from([
+                    (
+                        "vh".to_string(),
+                        Module::F(FlipFlop {
+                            name: "vh".to_string(),
+                            outputs: vec!["qc".to_string(), "pb".to_string()],
+                            state: false
+                        })
+                    ),
+                    (
+                        "pb".to_string(),
+                        Module::C(Conjunction {
+                            name: "pb".to_string(),
+                            outputs: vec!["gf".to_string(), "gv".to_string()],
+                            inputs: vec![("vh".to_string(), Pulse::Low)]
+                        })
+                    ),
+                    (
+                        "broadcaster".to_string(),
+                        Module::B(Broadcaster {
+                            name: "broadcaster".to_string(),
+                            outputs: vec!["hd".to_string(), "zj".to_string()]
+                        })
+                    )
+                ])
+            })
+        );
+    }
+
+    #[test]
+    fn parse_content_02() {
+        assert_eq!(
+            parse_content("broadcaster -> a\n%a -> b\nb -> a\n"),
+            Err(ParseError {
+                line: 3,
+                column: 1,
+                text: String::from("b -> a"),
+                reason: String::from(
+                    "expected `%name -> targets`, `&name -> targets` or `broadcaster -> targets`"
+                ),
+            })
+        );
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+struct SendPulse {
+    to: ModuleName,
+    pulse: Pulse,
+    from: ModuleName,
+}
+
+impl Display for SendPulse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}->{}", self.from, self.pulse, self.to)
+    }
+}
+
+type PulseQueue = VecDeque<SendPulse>;
+
+trait ReceivePulse {
+    fn receive_pulse(&mut self, queue: &mut PulseQueue, pulse: &Pulse, input: String);
+}
+
+impl ReceivePulse for Module {
+    fn receive_pulse(&mut self, queue: &mut PulseQueue, pulse: &Pulse, input: String) {
+        match self {
+            Module::F(f) => {
+                match pulse {
+                    Pulse::High => {
+                        // Nothing happens
+                    }
+                    // Flip the flop
+                    Pulse::Low => {
+                        let tmp = {
+                            if f.state {
+                                f.state = false;
+                                &Pulse::Low
+                            } else {
+                                f.state = true;
+                                &Pulse::High
+                            }
+                        };
+                        // And send the appropriate pulse to outputs
+                        f.outputs.iter().for_each(|m| {
+                            queue.push_back(SendPulse {
+                                to: m.to_owned(),
+                                pulse: tmp.clone(),
+                                from: f.name.clone(),
+                            })
+                        });
+                    }
+                }
+            }
+            Module::C(c) => {
+                // Mutate the appropriate input on the Conjunction with given pulse
+                c.inputs.iter_mut().find(|m| *m.0 == input).unwrap().1 = pulse.clone();
+
+                c.outputs.iter().for_each(|m| {
+                    let toto = SendPulse {
+                        to: m.to_owned(),
+                        pulse: if c.inputs.iter().filter(|i| i.1 == Pulse::Low).count() == 0 {
+                            Pulse::Low
+                        } else {
+                            Pulse::High
+                        },
+                        from: c.name.clone(),
+                    };
+                    queue.push_back(toto)
+                });
+            }
+            Module::B(b) => {
+                // Retransmit the same pulse to outputs
+                b.outputs.iter().for_each(|m| {
+                    queue.push_back(SendPulse {
+                        to: m.to_owned(),
+                        pulse: pulse.clone(),
+                        from: b.name.clone(),
+                    })
+                });
+            }
+        };
+    }
+}
+
+/// Deliver every pulse in the queue, including the ones it triggers, and
+/// return them in the order they were sent
+fn handle_pulse_queue(map: &mut ModuleMap, queue: &mut PulseQueue) -> Vec<SendPulse> {
+    let mut sent = vec![];
+    while let Some(element) = queue.pop_front() {
+        if let Some(x) = map.get_mut(&element.to) {
+            x.receive_pulse(queue, &element.pulse, element.from.clone())
+        }
+        sent.push(element);
+    }
+
+    sent
+}
+
+fn press(map: &mut ModuleMap) -> Vec<SendPulse> {
+    let mut queue: PulseQueue = VecDeque::from(vec![SendPulse {
+        to: "broadcaster".to_string(),
+        pulse: Pulse::Low,
+        from: "BUTTON".to_string(),
+    }]);
+    handle_pulse_queue(map, &mut queue)
+}
+
+fn push_the_button(map: &mut ModuleMap, button_count: usize) -> (usize, usize) {
+    let mut low_pulse_total = 0;
+    let mut high_pulse_total = 0;
+    for _ in 1..=button_count {
+        let sent = press(map);
+        let low_pulse_count = sent.iter().filter(|p| p.pulse == Pulse::Low).count();
+
+        low_pulse_total += low_pulse_count;
+        high_pulse_total += sent.len() - low_pulse_count;
+    }
+
+    (low_pulse_total, high_pulse_total)
+}
+
+#[cfg(test)]
+mod tests_push_the_button {
+
+    use super::*;
+
+    #[test]
+    fn push_the_button_01() {
+        let mut content = parse_content(
+            "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+",
+        )
+        .unwrap();
+        let (low, high) = push_the_button(&mut content.modules, 1000);
+        assert_eq!(low * high, 32000000);
+    }
+
+    #[test]
+    fn push_the_button_02() {
+        let mut content = parse_content(
+            "\
+    broadcaster -> a
+    %a -> inv, con
+    &inv -> b
+    %b -> con
+    &con -> output
+    ",
+        )
+        .unwrap();
+
+        let (low, high) = push_the_button(&mut content.modules, 1000);
+        assert_eq!(low * high, 11687500);
+    }
+}
+
+/// Give up looking for cycles after this many presses
+const MAX_PRESSES: usize = 100_000;
+
+/// How the conjunction feeding a module ends up sending it a low pulse
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct CycleAnalysis {
+    /// The conjunction whose output is the target module
+    feeder: ModuleName,
+    /// How often each input of `feeder` sends it a high pulse, by input name
+    periods: Vec<(ModuleName, usize)>,
+    /// The first press when every input has just sent a high pulse
+    presses: usize,
+}
+
+/// The conjunction which is the only module sending pulses to `target`
+fn find_feeder<'a>(map: &'a ModuleMap, target: &str) -> Option<&'a Conjunction> {
+    let mut feeders = map.values().filter(|module| {
+        let outputs = match module {
+            Module::F(f) => &f.outputs,
+            Module::C(c) => &c.outputs,
+            Module::B(b) => &b.outputs,
+        };
+        outputs.iter().any(|output| output == target)
+    });
+    match (feeders.next(), feeders.next()) {
+        (Some(Module::C(c)), None) => Some(c),
+        _ => None,
+    }
+}
+
+/// A conjunction sends a low pulse once all its inputs last sent a high one.
+///
+/// Each input of the conjunction feeding `target` is assumed to be the end of
+/// an independent loop, sending a high pulse every `period` presses starting
+/// from the `period`th one: the first press when they all do is their LCM.
+/// `None` when the circuit does not have this shape.
+fn analyse_cycles(map: &mut ModuleMap, target: &str) -> Option<CycleAnalysis> {
+    let feeder = find_feeder(map, target)?;
+    let name = feeder.name.clone();
+    let mut inputs: Vec<ModuleName> = feeder.inputs.iter().map(|(i, _)| i.clone()).collect();
+    inputs.sort();
+
+    // The first two presses on which each input sent a high pulse
+    let mut highs: HashMap<ModuleName, Vec<usize>> = HashMap::new();
+    for button_count in 1..=MAX_PRESSES {
+        for sent in press(map) {
+            if sent.to == name && sent.pulse == Pulse::High {
+                let presses = highs.entry(sent.from).or_default();
+                if presses.last() != Some(&button_count) && presses.len() < 2 {
+                    presses.push(button_count);
+                }
+            }
+        }
+        if inputs
+            .iter()
+            .all(|input| highs.get(input).is_some_and(|presses| presses.len() == 2))
+        {
+            break;
+        }
+    }
+
+    let mut periods = vec![];
+    for input in inputs {
+        let presses = highs.get(&input).filter(|presses| presses.len() == 2)?;
+        let period = presses[1] - presses[0];
+        // A loop with an offset would need more than an LCM
+        if presses[0] != period {
+            return None;
+        }
+        periods.push((input, period));
+    }
+    debug!("{} feeds {}, with periods {:?}", name, target, periods);
+
+    Some(CycleAnalysis {
+        feeder: name,
+        presses: periods.iter().fold(1, |acc, (_, period)| lcm(acc, *period)),
+        periods,
+    })
+}
+
+#[cfg(test)]
+mod tests_analyse_cycles {
+    use super::*;
+
+    // `ia` sends a high pulse every 2 presses and `ic` every 4
+    const CIRCUIT: &str = "\
+broadcaster -> a, b
+%a -> ia
+&ia -> feed
+%b -> c
+%c -> ic
+&ic -> feed
+&feed -> rx";
+
+    #[test]
+    fn analyse_cycles_01() {
+        let mut content = parse_content(CIRCUIT).unwrap();
+        assert_eq!(
+            analyse_cycles(&mut content.modules, "rx"),
+            Some(CycleAnalysis {
+                feeder: String::from("feed"),
+                periods: vec![(String::from("ia"), 2), (String::from("ic"), 4)],
+                presses: 4
+            })
+        );
+    }
+
+    #[test]
+    fn analyse_cycles_matches_simulation() {
+        // Both start from the same, freshly parsed, state
+        let mut modules = parse_content(CIRCUIT).unwrap().modules;
+        let analysis = analyse_cycles(&mut modules.clone(), "rx").unwrap();
+        let presses = (1..=MAX_PRESSES)
+            .find(|_| {
+                press(&mut modules)
+                    .iter()
+                    .any(|sent| sent.to == "rx" && sent.pulse == Pulse::Low)
+            })
+            .unwrap();
+        assert_eq!(analysis.presses, presses);
+    }
+
+    #[test]
+    fn analyse_cycles_no_feeder() {
+        let mut content = parse_content(
+            "\
+broadcaster -> a
+%a -> rx",
+        )
+        .unwrap();
+        assert_eq!(analyse_cycles(&mut content.modules, "rx"), None);
+        assert_eq!(analyse_cycles(&mut content.modules, "nowhere"), None);
+    }
+}
+
+/// Flip-flops are boxes, conjunctions diamonds and the broadcaster a double
+/// circle
+impl ToDot for Content {
+    fn to_dot(&self) -> Dot {
+        let mut dot = Dot::digraph("modules");
+        for (name, module) in &self.modules {
+            let (shape, outputs) = match module {
+                Module::F(f) => (Shape::Box, &f.outputs),
+                Module::C(c) => (Shape::Diamond, &c.outputs),
+                Module::B(b) => (Shape::DoubleCircle, &b.outputs),
+            };
+            dot.node(name, shape);
+            for output in outputs {
+                dot.edge(name, output);
+            }
+        }
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests_to_dot {
+    use super::*;
+
+    #[test]
+    fn to_dot_01() {
+        let content = parse_content(
+            "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output",
+        )
+        .unwrap();
+        assert_eq!(
+            content.to_dot().to_string(),
+            r#"digraph "modules" {
+    "a" [shape=box];
+    "b" [shape=box];
+    "broadcaster" [shape=doublecircle];
+    "con" [shape=diamond];
+    "inv" [shape=diamond];
+    "output" [shape=ellipse];
+    "a" -> "con";
+    "a" -> "inv";
+    "b" -> "con";
+    "broadcaster" -> "a";
+    "con" -> "output";
+    "inv" -> "b";
+}
+"#
+        );
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        let mut data = input.clone();
+
+        let (low, high) = push_the_button(&mut data.modules, 1000);
+
+        Some(low * high)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let mut data = input.clone();
+
+        analyse_cycles(&mut data.modules, "rx").map(|analysis| analysis.presses)
+    }
+
+    fn dot(input: &Self::Input) -> Option<Dot> {
+        Some(input.to_dot())
+    }
+}
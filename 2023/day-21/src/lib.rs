@@ -0,0 +1,593 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+
+use aoc_core::{
+    coords::{get_bounding_rect, Coords},
+    debug,
+    display::display_map,
+    grid::Grid,
+};
+use aoc_core::{parse::ParseError, solution::Solution};
+
+type Map = HashMap<Coords, char>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Content {
+    map: Map,
+    start: Coords,
+    width: isize,
+    height: isize,
+}
+
+impl Content {
+    /// The tile at `coords`, the map repeating itself infinitely in every
+    /// direction
+    fn get_tile(&self, coords: &Coords) -> Option<char> {
+        self.map
+            .get(&Coords {
+                x: coords.x.rem_euclid(self.width),
+                y: coords.y.rem_euclid(self.height),
+            })
+            .copied()
+    }
+}
+
+#[allow(dead_code)]
+fn display(map: &Map) {
+    display_map(&get_bounding_rect(map.keys().cloned()), |c| {
+        *map.get(c).unwrap()
+    });
+}
+
+/// A tile of the garden map: a garden plot, a rock or the starting position
+struct Tile(char);
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        if ".#S".contains(c) {
+            Ok(Tile(c))
+        } else {
+            Err(())
+        }
+    }
+}
+
+fn parse_content(lines: &str) -> Result<Content, ParseError> {
+    let grid: Grid<Tile> = lines.parse()?;
+
+    let mut starts = lines.lines().enumerate().flat_map(|(index, line)| {
+        line.match_indices('S')
+            .map(move |(offset, _)| (index, line, offset))
+    });
+    if starts.next().is_none() {
+        return Err(ParseError::line(
+            0,
+            lines.lines().next().unwrap_or_default(),
+            "no starting position `S` in the map",
+        ));
+    }
+    if let Some((index, line, offset)) = starts.next() {
+        return Err(ParseError::at(
+            index,
+            line,
+            &line[offset..offset + 1],
+            "more than one starting position `S` in the map",
+        ));
+    }
+
+    let map: Map = grid
+        .iter()
+        .map(|(coords, tile)| {
+            (
+                Coords {
+                    x: coords.x as isize,
+                    y: coords.y as isize,
+                },
+                tile.0,
+            )
+        })
+        .collect();
+    let start = *map.iter().find(|(_, c)| **c == 'S').unwrap().0;
+
+    Ok(Content {
+        width: grid.width() as isize,
+        height: grid.height() as isize,
+        map,
+        start,
+    })
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        let content = parse_content(
+            "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+",
+        )
+        .unwrap();
+        assert_eq!(content.map.len(), 121);
+        assert_eq!(content.start, Coords { x: 5, y: 5 });
+        assert_eq!((content.width, content.height), (11, 11));
+    }
+
+    #[test]
+    fn parse_content_no_start() {
+        assert!(parse_content("...\n.#.\n").is_err());
+    }
+
+    #[test]
+    fn parse_content_malformed() {
+        let error = parse_content("\n.S.\n.#\n").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.reason, "expected 3 tiles, got 2");
+        let error = parse_content(".S.\n.#S\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(
+            error.reason,
+            "more than one starting position `S` in the map"
+        );
+        let error = parse_content(".S.\n.O.\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.reason, "unknown tile");
+    }
+
+    #[test]
+    fn parse_content_leading_blank_line() {
+        let content = parse_content("\n.S.\n.#.\n").unwrap();
+        assert_eq!((content.width, content.height), (3, 2));
+        assert_eq!(content.start, Coords { x: 1, y: 0 });
+    }
+}
+
+type Cache = HashMap<(Coords, usize), HashSet<Coords>>;
+
+fn take_n_steps(content: &Content, from: Coords, count: usize) -> HashSet<Coords> {
+    cached_steps(content, from, count, &mut Cache::new())
+}
+
+/// Paths cross each other all the time, so the plots reachable from a given
+/// position in a given number of steps are only computed once
+fn cached_steps(
+    content: &Content,
+    from: Coords,
+    count: usize,
+    cache: &mut Cache,
+) -> HashSet<Coords> {
+    if let Some(plots) = cache.get(&(from, count)) {
+        return plots.clone();
+    }
+    let plots = if count == 0 {
+        HashSet::from_iter(vec![from])
+    } else {
+        HashSet::from_iter(
+            [
+                Coords {
+                    x: (from.x - 1),
+                    y: from.y,
+                },
+                Coords {
+                    x: from.x,
+                    y: (from.y - 1),
+                },
+                Coords {
+                    x: (from.x + 1),
+                    y: from.y,
+                },
+                Coords {
+                    x: from.x,
+                    y: (from.y + 1),
+                },
+            ]
+            .iter()
+            .filter(|test| matches!(content.map.get(test), Some(c) if *c != '#'))
+            .flat_map(|step| cached_steps(content, *step, count - 1, cache))
+            .collect::<Vec<Coords>>(),
+        )
+    };
+    cache.insert((from, count), plots.clone());
+    plots
+}
+
+#[cfg(test)]
+mod tests_take_n_steps {
+    use super::*;
+
+    #[test]
+    fn take_n_steps_01() {
+        let input = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+"
+        .to_string();
+        assert_eq!(
+            take_n_steps(&parse_content(&input).unwrap(), Coords { x: 5, y: 5 }, 2),
+            HashSet::from_iter(vec![
+                Coords { x: 3, y: 5 },
+                Coords { x: 5, y: 5 },
+                Coords { x: 4, y: 6 },
+                Coords { x: 5, y: 3 },
+            ])
+        );
+    }
+
+    #[test]
+    fn take_n_steps_02() {
+        let input = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+"
+        .to_string();
+        assert_eq!(
+            take_n_steps(&parse_content(&input).unwrap(), Coords { x: 5, y: 5 }, 3),
+            HashSet::from_iter(vec![
+                Coords { x: 3, y: 4 },
+                Coords { x: 3, y: 6 },
+                Coords { x: 4, y: 7 },
+                Coords { x: 6, y: 3 },
+                Coords { x: 5, y: 4 },
+                Coords { x: 4, y: 5 },
+            ])
+        );
+    }
+
+    #[test]
+    fn take_n_steps_03() {
+        let input = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+"
+        .to_string();
+        let result = take_n_steps(&parse_content(&input).unwrap(), Coords { x: 5, y: 5 }, 4);
+        assert_eq!(
+            result,
+            HashSet::from_iter(vec![
+                Coords { x: 2, y: 4 },
+                Coords { x: 3, y: 3 },
+                Coords { x: 3, y: 5 },
+                Coords { x: 3, y: 7 },
+                Coords { x: 5, y: 5 },
+                Coords { x: 4, y: 6 },
+                Coords { x: 5, y: 7 },
+                Coords { x: 5, y: 3 },
+                Coords { x: 7, y: 3 },
+            ])
+        );
+    }
+
+    #[test]
+    fn take_n_steps_04() {
+        let input = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+"
+        .to_string();
+        let result = take_n_steps(&parse_content(&input).unwrap(), Coords { x: 5, y: 5 }, 6);
+        assert_eq!(result.len(), 16);
+        assert_eq!(
+            result,
+            HashSet::from_iter([
+                Coords { x: 0, y: 4 },
+                Coords { x: 1, y: 3 },
+                Coords { x: 2, y: 4 },
+                Coords { x: 3, y: 3 },
+                Coords { x: 3, y: 5 },
+                Coords { x: 1, y: 7 },
+                Coords { x: 3, y: 7 },
+                Coords { x: 5, y: 7 },
+                Coords { x: 3, y: 9 },
+                Coords { x: 5, y: 5 },
+                Coords { x: 4, y: 6 },
+                Coords { x: 5, y: 3 },
+                Coords { x: 7, y: 3 },
+                Coords { x: 8, y: 2 },
+                Coords { x: 8, y: 4 },
+                Coords { x: 6, y: 6 },
+            ])
+        );
+    }
+}
+
+type Visited = HashMap<Coords, usize>;
+
+/// How many steps it takes to reach every plot of the infinitely repeated
+/// map, up to `max_steps`
+fn breadth_first_search(content: &Content, starting_point: &Coords, max_steps: usize) -> Visited {
+    let mut queue: VecDeque<(Coords, usize)> = VecDeque::new();
+    let mut visited: Visited = HashMap::new();
+    visited.insert(*starting_point, 0);
+    queue.push_back((*starting_point, 0));
+
+    while let Some((coords, steps)) = queue.pop_front() {
+        if steps == max_steps {
+            continue;
+        }
+        let (x, y) = (coords.x, coords.y);
+
+        [
+            Coords { x, y: y - 1 },
+            Coords { x, y: y + 1 },
+            Coords { x: x - 1, y },
+            Coords { x: x + 1, y },
+        ]
+        .iter()
+        .filter_map(|tmp| {
+            if !visited.contains_key(tmp) {
+                match content.get_tile(tmp) {
+                    Some('#') => None,
+                    Some(_) => {
+                        visited.insert(*tmp, steps + 1);
+                        Some((*tmp, steps + 1))
+                    }
+                    None => None,
+                }
+            } else {
+                None
+            }
+        })
+        .for_each(|c| queue.push_back(c));
+    }
+
+    visited
+}
+
+#[cfg(test)]
+mod tests_bfs {
+    use super::*;
+
+    #[test]
+    fn breadth_first_search_01() {
+        let bfs = breadth_first_search(
+            &parse_content(
+                "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+",
+            )
+            .unwrap(),
+            &Coords { x: 5, y: 5 },
+            10,
+        );
+        assert_eq!(bfs.get(&Coords { x: 5, y: 5 }), Some(&0));
+        assert_eq!(bfs.get(&Coords { x: 6, y: 5 }), None);
+        assert_eq!(bfs.get(&Coords { x: 5, y: 6 }), None);
+        assert_eq!(bfs.get(&Coords { x: 4, y: 5 }), Some(&1));
+        assert_eq!(bfs.get(&Coords { x: 10, y: 0 }), Some(&10));
+    }
+
+    #[test]
+    fn breadth_first_search_beyond_the_map() {
+        let bfs = breadth_first_search(
+            &parse_content(
+                "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+",
+            )
+            .unwrap(),
+            &Coords { x: 5, y: 5 },
+            10,
+        );
+        // Around the rock left of the start, then through the left edge
+        assert_eq!(bfs.get(&Coords { x: -1, y: 5 }), Some(&8));
+        assert_eq!(bfs.get(&Coords { x: -1, y: 4 }), Some(&7));
+        assert_eq!(bfs.get(&Coords { x: -6, y: 5 }), None);
+    }
+}
+
+/// How many plots are reachable in exactly `steps` steps, given how many it
+/// takes to reach each of them: going back and forth, every plot reached in
+/// less steps of the same parity is also reachable
+fn count_plots(visited: &Visited, steps: usize) -> usize {
+    visited
+        .values()
+        .filter(|v| **v <= steps && **v % 2 == steps % 2)
+        .count()
+}
+
+/// Whether the reachable plots grow quadratically with the number of maps
+/// crossed. The map has to be a square with the start in its middle, and with
+/// neither the start row and column nor the edges holding rocks, so that the
+/// farther copies of the map are always reached the same way, straight from the
+/// start.
+fn can_extrapolate(content: &Content) -> bool {
+    let size = content.width;
+    let is_clear = |x: isize, y: isize| content.map.get(&Coords { x, y }) != Some(&'#');
+    content.width == content.height
+        && content.start
+            == (Coords {
+                x: size / 2,
+                y: size / 2,
+            })
+        && (0..size).all(|i| {
+            is_clear(i, content.start.y)
+                && is_clear(content.start.x, i)
+                && is_clear(i, 0)
+                && is_clear(i, size - 1)
+                && is_clear(0, i)
+                && is_clear(size - 1, i)
+        })
+}
+
+/// How many plots of the infinitely repeated map are reachable in exactly
+/// `steps` steps.
+///
+/// Walking `steps = n * size + remainder` steps, the reachable plots are a
+/// quadratic function of `n` if the map allows it (see `can_extrapolate`): it
+/// is found from three walks a few maps long, then evaluated at `n`. Otherwise,
+/// or for walks that short anyway, every plot is walked to: that takes time
+/// and memory growing with the square of `steps`, so walking the 26501365
+/// steps of part 2 on a map that cannot be extrapolated takes far too long.
+///
+/// See https://github.com/villuna/aoc23/wiki/A-Geometric-solution-to-advent-of-code-2023,-day-21
+/// for the geometry behind it
+fn count_reachable_plots(content: &Content, steps: usize) -> usize {
+    // The first copies of the map are not always reached like the farther ones
+    const FIRST_SAMPLE: usize = 2;
+
+    let size = content.width as usize;
+    let (n, remainder) = (steps / size, steps % size);
+    if n <= FIRST_SAMPLE + 2 || !can_extrapolate(content) {
+        debug!("Walking all of the {} steps", steps);
+        return count_plots(&breadth_first_search(content, &content.start, steps), steps);
+    }
+
+    let visited = breadth_first_search(
+        content,
+        &content.start,
+        remainder + (FIRST_SAMPLE + 2) * size,
+    );
+    let [a, b, c] =
+        [0, 1, 2].map(|k| count_plots(&visited, remainder + (FIRST_SAMPLE + k) * size) as i64);
+    debug!("Samples every {} steps: {}, {}, {}", size, a, b, c);
+
+    // Newton's forward differences, from the first sample
+    let k = (n - FIRST_SAMPLE) as i64;
+    (a + k * (b - a) + k * (k - 1) / 2 * (c - 2 * b + a)) as usize
+}
+
+#[cfg(test)]
+mod tests_count_reachable_plots {
+    use super::*;
+
+    #[test]
+    fn count_reachable_plots_01() {
+        let content = parse_content(
+            "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+",
+        )
+        .unwrap();
+        assert!(!can_extrapolate(&content));
+        assert_eq!(count_reachable_plots(&content, 6), 16);
+        assert_eq!(count_reachable_plots(&content, 10), 50);
+        assert_eq!(count_reachable_plots(&content, 50), 1594);
+        assert_eq!(count_reachable_plots(&content, 100), 6536);
+        assert_eq!(count_reachable_plots(&content, 500), 167004);
+    }
+
+    #[test]
+    fn count_reachable_plots_extrapolated() {
+        let content = parse_content(
+            "\
+...........
+......##.#.
+.###..#..#.
+..#.#...#..
+....#.#....
+.....S.....
+.##......#.
+.......##..
+.##.#.####.
+.##...#.##.
+...........
+",
+        )
+        .unwrap();
+        assert!(can_extrapolate(&content));
+        for steps in [60, 64, 71, 99, 150, 203] {
+            let walked = count_plots(
+                &breadth_first_search(&content, &content.start, steps),
+                steps,
+            );
+            assert_eq!(count_reachable_plots(&content, steps), walked);
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(take_n_steps(input, input.start, 64).len())
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(count_reachable_plots(input, 26501365))
+    }
+}
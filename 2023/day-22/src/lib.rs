@@ -1,13 +1,14 @@
-// use hash_map_diff::hash_map_diff;
-use std::{collections::HashMap, fs};
-
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Display;
+use std::ops::Deref;
+
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+    trace,
+};
 use regex::Regex;
 
-fn get_file_content(file_path: &str) -> String {
-    println!("Loading input file: {}", file_path);
-    fs::read_to_string(file_path).expect("Cannot load file")
-}
-
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
 struct Coords {
     x: usize,
@@ -23,8 +24,17 @@ type Blocks = HashMap<usize, Block>;
 // by its index)
 type Grid = HashMap<Coords, usize>;
 
+// Which blocks rest directly on which, once settled
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+struct Supports {
+    // The blocks resting on each block
+    above: HashMap<usize, BTreeSet<usize>>,
+    // The blocks each block rests on, none for those on the ground
+    below: HashMap<usize, BTreeSet<usize>>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Content {
+pub struct Content {
     blocks: Blocks,
     grid: Grid,
     max_x: usize,
@@ -32,8 +42,24 @@ struct Content {
     max_z: usize,
 }
 
-fn parse_content(lines: &str) -> Content {
-    let re_block = Regex::new(r"(?<start_x>[0-9]+),(?<start_y>[0-9]+),(?<start_z>[0-9]+)\~(?<end_x>[0-9]+),(?<end_y>[0-9]+),(?<end_z>[0-9]+)").unwrap();
+/// The stack once every block has fallen as low as it can, along with which
+/// blocks then rest on which
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Settled {
+    content: Content,
+    supports: Supports,
+}
+
+impl Deref for Settled {
+    type Target = Content;
+
+    fn deref(&self) -> &Self::Target {
+        &self.content
+    }
+}
+
+fn parse_content(lines: &str) -> Result<Content, ParseError> {
+    let re_block = Regex::new(r"^(?<start_x>[0-9]+),(?<start_y>[0-9]+),(?<start_z>[0-9]+)\~(?<end_x>[0-9]+),(?<end_y>[0-9]+),(?<end_z>[0-9]+)$").unwrap();
 
     let mut blocks: Blocks = Blocks::new();
     let mut grid: Grid = Grid::new();
@@ -41,95 +67,98 @@ fn parse_content(lines: &str) -> Content {
     let mut max_y = 0;
     let mut max_z = 0;
 
-    lines
-        .split("\n")
-        .filter(|line| !line.is_empty())
-        .enumerate()
-        .for_each(|(index, line)| {
-            let Some(caps) = re_block.captures(line) else {
-                panic!("Invalid block: {}", line);
-            };
-            let start_x = caps["start_x"].parse().unwrap();
-            let start_y = caps["start_y"].parse().unwrap();
-            let start_z = caps["start_z"].parse().unwrap();
-            let end_x = caps["end_x"].parse().unwrap();
-            let end_y = caps["end_y"].parse().unwrap();
-            let end_z = caps["end_z"].parse().unwrap();
-
-            if end_x < start_x || end_y < start_y || end_z < start_z {
-                panic!("End should always be greater than start: {}", line);
-            }
+    for (index, line) in lines.split("\n").enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let Some(caps) = re_block.captures(line) else {
+            return Err(ParseError::line(
+                index,
+                line,
+                "expected `<x>,<y>,<z>~<x>,<y>,<z>`",
+            ));
+        };
+        let field = |name: &str| -> Result<usize, ParseError> {
+            parse_field(index, line, caps.name(name).unwrap().as_str(), "coordinate")
+        };
+        let (start_x, start_y, start_z) = (field("start_x")?, field("start_y")?, field("start_z")?);
+        let (end_x, end_y, end_z) = (field("end_x")?, field("end_y")?, field("end_z")?);
+
+        if end_x < start_x || end_y < start_y || end_z < start_z {
+            return Err(ParseError::line(
+                index,
+                line,
+                "the end of a block cannot be before its start",
+            ));
+        }
+        if [start_x != end_x, start_y != end_y, start_z != end_z]
+            .iter()
+            .filter(|spans| **spans)
+            .count()
+            > 1
+        {
+            return Err(ParseError::line(
+                index,
+                line,
+                "a block only spans along one axis",
+            ));
+        }
+        if start_z == 0 {
+            return Err(ParseError::line(
+                index,
+                line,
+                "the ground is at z = 0, blocks start at z = 1",
+            ));
+        }
 
-            if end_x > max_x {
-                max_x = end_x;
-            }
-            if end_y > max_y {
-                max_y = end_y;
-            }
-            if end_z > max_z {
-                max_z = end_z;
-            }
+        if end_x > max_x {
+            max_x = end_x;
+        }
+        if end_y > max_y {
+            max_y = end_y;
+        }
+        if end_z > max_z {
+            max_z = end_z;
+        }
 
-            let block: Block = (
-                Coords {
-                    x: start_x,
-                    y: start_y,
-                    z: start_z,
-                },
-                Coords {
-                    x: end_x,
-                    y: end_y,
-                    z: end_z,
-                },
-            );
-            let block_id = index + 1;
-            blocks.insert(block_id, block.clone());
-
-            if start_z == end_z {
-                if start_x == end_x {
-                    for y in start_y..=end_y {
-                        grid.insert(
-                            Coords {
-                                x: start_x,
-                                y,
-                                z: start_z,
-                            },
-                            block_id,
-                        );
-                    }
-                } else {
-                    for x in start_x..=end_x {
-                        grid.insert(
-                            Coords {
-                                x,
-                                y: start_y,
-                                z: start_z,
-                            },
-                            block_id,
-                        );
-                    }
-                }
-            } else {
+        let block: Block = (
+            Coords {
+                x: start_x,
+                y: start_y,
+                z: start_z,
+            },
+            Coords {
+                x: end_x,
+                y: end_y,
+                z: end_z,
+            },
+        );
+        let block_id = blocks.len() + 1;
+        blocks.insert(block_id, block.clone());
+
+        // Every axis but one has the same start and end
+        for x in start_x..=end_x {
+            for y in start_y..=end_y {
                 for z in start_z..=end_z {
-                    grid.insert(
-                        Coords {
-                            x: start_x,
-                            y: start_y,
-                            z,
-                        },
-                        block_id,
-                    );
+                    if let Some(other_id) = grid.insert(Coords { x, y, z }, block_id) {
+                        return Err(ParseError::line(
+                            index,
+                            line,
+                            format!("overlaps block {}", other_id),
+                        ));
+                    }
                 }
             }
-        });
+        }
+    }
 
-    Content {
+    Ok(Content {
         blocks,
         grid,
         max_x,
         max_y,
         max_z,
-    }
+    })
 }
 
 #[cfg(test)]
@@ -139,15 +168,41 @@ mod tests_parse_content {
     #[test]
     fn parse_content_01() {
         let content = parse_content(
-            &"\
+            "\
 1,0,1~1,2,1
 ",
-        );
+        )
+        .unwrap();
         assert_eq!(content.blocks.len(), 1);
     }
+
+    #[test]
+    fn parse_content_malformed() {
+        let error = |input: &str| parse_content(input).map(|_| ()).unwrap_err().reason;
+        assert_eq!(error("1,0,1~1,2"), "expected `<x>,<y>,<z>~<x>,<y>,<z>`");
+        assert_eq!(
+            error("1,2,1~1,0,1"),
+            "the end of a block cannot be before its start"
+        );
+        assert_eq!(error("0,0,1~1,1,1"), "a block only spans along one axis");
+        assert_eq!(
+            error("0,0,0~0,0,1"),
+            "the ground is at z = 0, blocks start at z = 1"
+        );
+        assert_eq!(error("0,0,1~0,0,3\n0,0,2~2,0,2"), "overlaps block 1");
+        assert_eq!(
+            parse_content("\n1,0,1~1,99999999999999999999,1").map(|_| ()),
+            Err(ParseError {
+                line: 2,
+                column: 9,
+                text: String::from("99999999999999999999"),
+                reason: String::from("invalid coordinate: number too large to fit in target type"),
+            })
+        );
+    }
 }
 
-fn settle(content: &Content) -> (usize, Content) {
+fn settle(content: &Content) -> (usize, Settled) {
     let mut settled_grid: Grid = content.grid.clone();
     let mut new_content: Content = content.clone();
     let mut moved_blocks = 0;
@@ -167,7 +222,7 @@ fn settle(content: &Content) -> (usize, Content) {
                         let mut lowest_possible_z = start.z;
                         // Iterate for every part of this block
                         for tmp_z in (1..start.z).rev() {
-                            if let Some(_) = settled_grid.get(&Coords { x, y, z: tmp_z }) {
+                            if settled_grid.contains_key(&Coords { x, y, z: tmp_z }) {
                                 break;
                             }
                             lowest_possible_z = tmp_z;
@@ -216,7 +271,7 @@ fn settle(content: &Content) -> (usize, Content) {
                             for block_x in start.x..=end.x {
                                 let mut out_z = z;
                                 for tmp_z in (1..z).rev() {
-                                    if let Some(_) = settled_grid.get(&Coords {
+                                    if settled_grid.contains_key(&Coords {
                                         x: block_x,
                                         y,
                                         z: tmp_z,
@@ -267,7 +322,7 @@ fn settle(content: &Content) -> (usize, Content) {
                             for block_y in start.y..=end.y {
                                 let mut out_z = z;
                                 for tmp_z in (1..z).rev() {
-                                    if let Some(_) = settled_grid.get(&Coords {
+                                    if settled_grid.contains_key(&Coords {
                                         x,
                                         y: block_y,
                                         z: tmp_z,
@@ -316,8 +371,35 @@ fn settle(content: &Content) -> (usize, Content) {
         }
     }
 
-    new_content.grid = settled_grid.clone();
-    (moved_blocks, new_content)
+    let supports = get_supports(&new_content.blocks, &settled_grid);
+    new_content.grid = settled_grid;
+    (
+        moved_blocks,
+        Settled {
+            content: new_content,
+            supports,
+        },
+    )
+}
+
+fn get_supports(blocks: &Blocks, grid: &Grid) -> Supports {
+    let mut supports = Supports::default();
+    for block_id in blocks.keys() {
+        supports.above.insert(*block_id, BTreeSet::new());
+        supports.below.insert(*block_id, BTreeSet::new());
+    }
+    // A block part right under a part of another block supports it
+    for (coords, block_id) in grid {
+        let under = Coords {
+            z: coords.z - 1,
+            ..coords.clone()
+        };
+        if let Some(under_id) = grid.get(&under).filter(|id| *id != block_id) {
+            supports.above.get_mut(under_id).unwrap().insert(*block_id);
+            supports.below.get_mut(block_id).unwrap().insert(*under_id);
+        }
+    }
+    supports
 }
 
 #[cfg(test)]
@@ -327,10 +409,11 @@ mod tests_settle {
     #[test]
     fn settle_vertical_block() {
         let content = parse_content(
-            &"\
+            "\
 1,0,30~1,0,32
 ",
-        );
+        )
+        .unwrap();
         let (_, new_content) = settle(&content);
         assert_eq!(
             new_content.grid,
@@ -345,10 +428,11 @@ mod tests_settle {
     #[test]
     fn settle_horizontal_block_x_axis() {
         let content = parse_content(
-            &"\
+            "\
 1,0,30~3,0,30
 ",
-        );
+        )
+        .unwrap();
         let (_, new_content) = settle(&content);
         assert_eq!(
             new_content.grid,
@@ -363,10 +447,11 @@ mod tests_settle {
     #[test]
     fn settle_horizontal_block_y_axis() {
         let content = parse_content(
-            &"\
+            "\
 7,3,30~7,5,30
 ",
-        );
+        )
+        .unwrap();
         let (_, new_content) = settle(&content);
         assert_eq!(
             new_content.grid,
@@ -381,11 +466,12 @@ mod tests_settle {
     #[test]
     fn settle_vertical_block_on_another_block() {
         let content = parse_content(
-            &"\
+            "\
 1,0,30~1,0,32
 1,0,2~1,0,2
 ",
-        );
+        )
+        .unwrap();
         let (_, new_content) = settle(&content);
         assert_eq!(
             new_content.grid,
@@ -401,11 +487,12 @@ mod tests_settle {
     #[test]
     fn settle_horizontal_block_on_another_block() {
         let content = parse_content(
-            &"\
+            "\
 1,0,5~3,0,5
 2,0,1~2,2,1
 ",
-        );
+        )
+        .unwrap();
         let (_, new_content) = settle(&content);
         assert_eq!(
             new_content.grid,
@@ -423,7 +510,7 @@ mod tests_settle {
     #[test]
     fn settle_test_remove_disintegrable_block() {
         let content = parse_content(
-            &"\
+            "\
 1,0,1~1,2,1
 0,0,2~2,0,2
 0,2,3~2,2,3
@@ -432,17 +519,18 @@ mod tests_settle {
 0,1,6~2,1,6
 1,1,8~1,1,9
 ",
-        );
+        )
+        .unwrap();
         let (_, settled) = settle(&content);
         let tmp_content = &remove_block(&settled, &2);
         // Removing a disintegratable block should not change the setlled grid
-        assert_eq!(settle(&tmp_content).1.grid, tmp_content.grid);
+        assert_eq!(settle(tmp_content).1.grid, tmp_content.grid);
     }
 
     #[test]
     fn settle_test_input() {
         let content = parse_content(
-            &"\
+            "\
 1,0,1~1,2,1
 0,0,2~2,0,2
 0,2,3~2,2,3
@@ -451,7 +539,8 @@ mod tests_settle {
 0,1,6~2,1,6
 1,1,8~1,1,9
 ",
-        );
+        )
+        .unwrap();
         let (_, new_content) = settle(&content);
         assert_eq!(
             new_content.grid,
@@ -480,10 +569,32 @@ mod tests_settle {
         );
     }
 
+    #[test]
+    fn settle_test_supports() {
+        let content = parse_content(
+            "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+",
+        )
+        .unwrap();
+        let (_, new_content) = settle(&content);
+        let ids = |list: &[usize]| list.iter().copied().collect::<BTreeSet<_>>();
+        assert_eq!(new_content.supports.above[&1], ids(&[2, 3]));
+        assert_eq!(new_content.supports.above[&6], ids(&[7]));
+        assert_eq!(new_content.supports.below[&1], ids(&[]));
+        assert_eq!(new_content.supports.below[&6], ids(&[4, 5]));
+    }
+
     #[test]
     fn settle_test_idempotency() {
         let content = parse_content(
-            &"\
+            "\
 1,0,1~1,2,1
 0,0,2~2,0,2
 0,2,3~2,2,3
@@ -492,13 +603,16 @@ mod tests_settle {
 0,1,6~2,1,6
 1,1,8~1,1,9
 ",
-        );
+        )
+        .unwrap();
         let (_, new_content) = settle(&content);
         let (_, new_content2) = settle(&new_content);
         assert_eq!(new_content.grid, new_content2.grid);
     }
 }
 
+// Only used to check the chain reactions against settling the whole stack again
+#[cfg(test)]
 fn remove_block(content: &Content, block_id_to_remove: &usize) -> Content {
     let mut new_content = content.clone();
 
@@ -526,11 +640,12 @@ mod tests_remove_block {
     #[test]
     fn remove_block_01() {
         let content = parse_content(
-            &"\
+            "\
 1,0,5~3,0,5
 2,0,1~2,2,1
 ",
-        );
+        )
+        .unwrap();
         let new_content = remove_block(&content, &1);
         assert_eq!(
             new_content.grid,
@@ -550,29 +665,186 @@ mod tests_remove_block {
     }
 }
 
-fn count_moved_after_disintegration(content: &Content) -> usize {
-    let mut count = 0;
-    let mut index = 0;
+fn count_disintegratable(settled: &Settled) -> usize {
+    let supports = &settled.supports;
+    settled
+        .blocks
+        .keys()
+        // A block can be disintegrated if every block resting on it also rests
+        // on another one
+        .filter(|block_id| {
+            supports.above[block_id]
+                .iter()
+                .all(|above_id| supports.below[above_id].len() > 1)
+        })
+        .count()
+}
 
-    // For each block in content.blocks
-    for block_id in content.blocks.keys() {
-        println!("{}: Testing block {}", index, block_id);
-        // If we remove this block from the original grid
-        let tmp_content = remove_block(&content.clone(), block_id);
+#[cfg(test)]
+mod tests_count_disintegratable {
+    use super::*;
 
-        // And we settle this updated grid, which gives us how many blocks moved
-        let moved_blocks = settle(&tmp_content).0;
+    #[test]
+    fn count_disintegratable_01() {
+        let content = parse_content(
+            "\
+1,0,5~3,0,5
+2,0,1~2,2,1
+",
+        )
+        .unwrap();
+        let (_, new_content) = settle(&content);
+        assert_eq!(count_disintegratable(&new_content), 1);
+    }
 
-        println!(
-            "Disintegrating block {} would move {} blocks",
-            block_id, moved_blocks
+    #[test]
+    fn count_disintegratable_02() {
+        assert_eq!(
+            count_disintegratable(
+                &settle(
+                    &parse_content(
+                        "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+",
+                    )
+                    .unwrap()
+                )
+                .1
+            ),
+            5
         );
-        count += moved_blocks;
+    }
+}
+
+// The ground, as the root of the dominator tree; blocks ids start at 1
+const GROUND: usize = 0;
+
+// The dominator tree of the support graph, rooted at the ground: a block falls
+// when another one is disintegrated if and only if every path from the ground
+// to it goes through the disintegrated one, i.e. if it is in its subtree
+struct ChainReaction {
+    // The immediate dominator of each block
+    parent: HashMap<usize, usize>,
+    depth: HashMap<usize, usize>,
+    // How many other blocks fall when disintegrating each block
+    falling: HashMap<usize, usize>,
+}
 
-        index += 1
+impl ChainReaction {
+    fn new(settled: &Settled) -> ChainReaction {
+        let mut chain_reaction = ChainReaction {
+            parent: HashMap::from([(GROUND, GROUND)]),
+            depth: HashMap::from([(GROUND, 0)]),
+            falling: HashMap::new(),
+        };
+
+        // Supporting blocks are always lower, so going from the bottom to the
+        // top handles them first
+        let mut block_ids: Vec<usize> = settled.blocks.keys().copied().collect();
+        block_ids.sort_by_key(|block_id| (settled.blocks[block_id].0.z, *block_id));
+
+        for block_id in &block_ids {
+            let parent = settled.supports.below[block_id]
+                .iter()
+                .copied()
+                .reduce(|a, b| chain_reaction.common_dominator(a, b))
+                .unwrap_or(GROUND);
+            chain_reaction.parent.insert(*block_id, parent);
+            chain_reaction
+                .depth
+                .insert(*block_id, chain_reaction.depth[&parent] + 1);
+        }
+
+        // Top to bottom, each subtree being complete before reaching its root
+        let mut sizes: HashMap<usize, usize> = HashMap::new();
+        for block_id in block_ids.iter().rev() {
+            let size = sizes.get(block_id).copied().unwrap_or(0);
+            chain_reaction.falling.insert(*block_id, size);
+            *sizes.entry(chain_reaction.parent[block_id]).or_default() += size + 1;
+        }
+
+        chain_reaction
+    }
+
+    fn common_dominator(&self, mut a: usize, mut b: usize) -> usize {
+        while a != b {
+            if self.depth[&a] < self.depth[&b] {
+                (a, b) = (b, a);
+            }
+            a = self.parent[&a];
+        }
+        a
     }
 
-    count
+    // How many other blocks fall when disintegrating this one
+    fn falling(&self, block_id: usize) -> Option<usize> {
+        self.falling.get(&block_id).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests_chain_reaction {
+    use super::*;
+
+    const INPUT: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+    #[test]
+    fn chain_reaction_01() {
+        let (_, content) = settle(&parse_content(INPUT).unwrap());
+        let chain_reaction = ChainReaction::new(&content);
+        assert_eq!(chain_reaction.falling(1), Some(6));
+        assert_eq!(chain_reaction.falling(2), Some(0));
+        assert_eq!(chain_reaction.falling(6), Some(1));
+        assert_eq!(chain_reaction.falling(7), Some(0));
+        assert_eq!(chain_reaction.falling(8), None);
+    }
+
+    #[test]
+    fn chain_reaction_matches_settling_again() {
+        let (_, content) = settle(
+            &parse_content(
+                "\
+0,0,1~2,0,1
+0,0,2~0,2,2
+2,0,3~2,2,3
+0,1,4~2,1,4
+1,1,5~1,1,7
+0,0,8~1,0,8
+1,0,9~1,2,9
+0,2,10~2,2,10
+",
+            )
+            .unwrap(),
+        );
+        let chain_reaction = ChainReaction::new(&content);
+        for block_id in content.blocks.keys() {
+            let (moved, _) = settle(&remove_block(&content, block_id));
+            assert_eq!(chain_reaction.falling(*block_id), Some(moved));
+        }
+    }
+}
+
+fn count_moved_after_disintegration(settled: &Settled) -> usize {
+    let chain_reaction = ChainReaction::new(settled);
+    settled
+        .blocks
+        .keys()
+        .filter_map(|block_id| chain_reaction.falling(*block_id))
+        .sum()
 }
 
 #[cfg(test)]
@@ -582,7 +854,7 @@ mod tests_count_moved_after_disintegration {
     #[test]
     fn count_moved_after_disintegration_01() {
         let content = parse_content(
-            &"\
+            "\
 1,0,1~1,2,1
 0,0,2~2,0,2
 0,2,3~2,2,3
@@ -591,7 +863,8 @@ mod tests_count_moved_after_disintegration {
 0,1,6~2,1,6
 1,1,8~1,1,9
 ",
-        );
+        )
+        .unwrap();
         let (_, new_content) = settle(&content);
         assert_eq!(count_moved_after_disintegration(&new_content), 7);
     }
@@ -600,23 +873,55 @@ mod tests_count_moved_after_disintegration {
 #[allow(dead_code)]
 fn display_content_grid_slice(content: &Content, z_level: usize) {
     for y in 0..=content.max_y {
-        for x in 0..=content.max_x {
-            print!(
-                "{:0>2} ",
-                match content.grid.get(&Coords { x, y, z: z_level }) {
-                    Some(id) => id.to_string(),
-                    None => "..".to_string(),
-                }
-            );
-        }
-        println!();
+        let row: String = (0..=content.max_x)
+            .map(|x| match content.grid.get(&Coords { x, y, z: z_level }) {
+                Some(id) => format!("{:0>2} ", id),
+                None => String::from(".. "),
+            })
+            .collect();
+        trace!("{}", row);
     }
 }
 
-fn main() {
-    let content = &parse_content(&get_file_content("assets/input"));
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Settled;
 
-    let (_, settled) = settle(&content);
+    /// Both parts are about the settled stack, so it is settled only once
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (_, settled) = settle(&parse_content(input)?);
+        Ok(settled)
+    }
 
-    println!("Part 1: {}", count_moved_after_disintegration(&settled));
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(count_disintegratable(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(count_moved_after_disintegration(input))
+    }
+}
+
+#[cfg(test)]
+mod tests_day22 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day22_example() {
+        let input = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+        assert_eq!(
+            solve::<Day22>(input, &[1, 2]),
+            Ok(vec![Some(String::from("5")), Some(String::from("7"))])
+        );
+    }
 }
@@ -0,0 +1,790 @@
+use std::collections::{btree_map::Entry, BTreeMap, HashMap};
+use std::fmt::Display;
+
+use aoc_core::{coords::BoundingRectangle, debug, display, grid::Grid, trace};
+use aoc_core::{
+    dot::{Dot, ToDot},
+    log::{self, Level},
+    parse::ParseError,
+    solution::Solution,
+};
+
+type Coords = aoc_core::coords::Coords<usize>;
+
+type Map = HashMap<Coords, char>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Content {
+    map: Map,
+    width: usize,
+    height: usize,
+}
+
+/// A tile of the hiking map: a path, a forest or a slope
+struct MapTile(char);
+
+impl TryFrom<char> for MapTile {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        if ".#<>^v".contains(c) {
+            Ok(MapTile(c))
+        } else {
+            Err(())
+        }
+    }
+}
+
+fn parse_content(lines: &str) -> Result<Content, ParseError> {
+    let grid: Grid<MapTile> = lines.parse()?;
+    let content = Content {
+        map: grid.iter().map(|(coords, tile)| (coords, tile.0)).collect(),
+        width: grid.width(),
+        height: grid.height(),
+    };
+    if content.width < 3 || content.height < 2 {
+        return Err(ParseError::missing(0, "", "hiking map"));
+    }
+    if content.map[&content.start()] != '.' || content.map[&content.finish()] != '.' {
+        return Err(ParseError::missing(
+            0,
+            "",
+            "path from the top left to the bottom right",
+        ));
+    }
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        let content = parse_content(
+            "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+",
+        )
+        .unwrap();
+        assert_eq!(content.map.len(), 529);
+        assert_eq!(content.width, 23);
+        assert_eq!(content.height, 23);
+    }
+
+    #[test]
+    fn parse_content_malformed() {
+        let error = parse_content("#.#\n#X#\n#.#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.reason, "unknown tile");
+        let error = parse_content("#.#\n#.\n#.#").unwrap_err();
+        assert_eq!(error.reason, "expected 3 tiles, got 2");
+        assert_eq!(
+            parse_content("\n").unwrap_err().reason,
+            "missing hiking map"
+        );
+        assert_eq!(
+            parse_content("#.#\n#.#\n###").unwrap_err().reason,
+            "missing path from the top left to the bottom right"
+        );
+    }
+}
+
+fn display_map(content: &Content) {
+    let rect = BoundingRectangle {
+        origin: Coords { x: 0, y: 0 },
+        width: content.width,
+        height: content.height,
+    };
+    display::display_map(&rect, |c| format!("{} ", content.map.get(c).unwrap()));
+}
+
+impl Content {
+    fn start(&self) -> Tile {
+        Coords { x: 1, y: 0 }
+    }
+
+    fn finish(&self) -> Tile {
+        Coords {
+            x: self.width - 2,
+            y: self.height - 1,
+        }
+    }
+}
+
+type Tile = Coords;
+type Node = Coords;
+type Graph = BTreeMap<Node, HashMap<Node, usize>>;
+
+#[allow(dead_code)]
+fn display_graph(graph: &Graph) {
+    graph.iter().for_each(|successors| {
+        trace!("Node: {:?}", successors.0);
+        successors.1.iter().for_each(|successor| {
+            trace!(
+                "    Successor: {:?}, Path length: {}",
+                successor.0,
+                successor.1
+            )
+        });
+    });
+}
+
+/// The traversable tiles next to a tile. On slippery slopes (`slippery`), one
+/// can only go down a slope, so neither leave it nor step on it the other way.
+fn get_neighbours(content: &Content, tile: &Tile, slippery: bool) -> Vec<Tile> {
+    let slope_allows = |slope_tile: &Tile, dx: isize, dy: isize| match content.map.get(slope_tile) {
+        Some('<') => (dx, dy) == (-1, 0),
+        Some('>') => (dx, dy) == (1, 0),
+        Some('^') => (dx, dy) == (0, -1),
+        Some('v') => (dx, dy) == (0, 1),
+        _ => true,
+    };
+    [(-1, 0), (1, 0), (0, -1), (0, 1)]
+        .into_iter()
+        .filter_map(|(dx, dy)| {
+            let x = tile.x.checked_add_signed(dx)?;
+            let y = tile.y.checked_add_signed(dy)?;
+            let next_tile = Coords { x, y };
+            match content.map.get(&next_tile) {
+                Some('#') | None => None,
+                _ if slippery
+                    && !(slope_allows(tile, dx, dy) && slope_allows(&next_tile, dx, dy)) =>
+                {
+                    None
+                }
+                _ => Some(next_tile),
+            }
+        })
+        .collect()
+}
+
+/// Junctions are the nodes of the graph: the start, the finish and every tile
+/// where trails meet
+fn is_junction(content: &Content, tile: &Tile) -> bool {
+    *tile == content.start()
+        || *tile == content.finish()
+        || get_neighbours(content, tile, false).len() > 2
+}
+
+/// Follow the trail leaving `node` through `first`, up to the next junction.
+/// Returns the tiles walked through, the junction being the last one, or
+/// `None` on a dead end.
+fn follow_trail(content: &Content, node: &Node, first: &Tile, slippery: bool) -> Option<Vec<Tile>> {
+    let mut previous = *node;
+    let mut tiles = vec![*first];
+    loop {
+        let current = *tiles.last().unwrap();
+        if is_junction(content, &current) {
+            return Some(tiles);
+        }
+        let next = get_neighbours(content, &current, slippery)
+            .into_iter()
+            .find(|tile| *tile != previous)?;
+        previous = current;
+        tiles.push(next);
+    }
+}
+
+/// Convert the map to a weighted graph between junctions, directed along the
+/// slopes when they are `slippery`
+fn get_graph(content: &Content, slippery: bool) -> Graph {
+    let mut graph = Graph::from([(content.start(), HashMap::new())]);
+    let mut queue: Vec<Node> = vec![content.start()];
+
+    while let Some(node) = queue.pop() {
+        for first in get_neighbours(content, &node, slippery) {
+            let Some(tiles) = follow_trail(content, &node, &first, slippery) else {
+                continue;
+            };
+            let next = *tiles.last().unwrap();
+            if next == node {
+                continue;
+            }
+            let length = graph.get_mut(&node).unwrap().entry(next).or_default();
+            *length = (*length).max(tiles.len());
+            if let Entry::Vacant(entry) = graph.entry(next) {
+                entry.insert(HashMap::new());
+                queue.push(next);
+            }
+        }
+    }
+
+    debug!("Found {} junctions", graph.len());
+
+    graph
+}
+
+#[cfg(test)]
+mod tests_get_graph {
+    use super::*;
+
+    #[test]
+    fn get_graph_01() {
+        let content = parse_content(
+            "\
+#.##
+#..#
+##.#
+",
+        )
+        .unwrap();
+
+        let result = get_graph(&content, false);
+
+        assert_eq!(
+            result,
+            BTreeMap::from_iter(vec![
+                (
+                    Coords { x: 1, y: 0 },
+                    HashMap::from_iter(vec![(Coords { x: 2, y: 2 }, 3)])
+                ),
+                (
+                    Coords { x: 2, y: 2 },
+                    HashMap::from_iter(vec![(Coords { x: 1, y: 0 }, 3)])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn get_graph_slippery() {
+        let content = parse_content(
+            "\
+#.###
+#.>.#
+#v#.#
+#...#
+###.#
+",
+        )
+        .unwrap();
+
+        let result = get_graph(&content, true);
+
+        assert_eq!(
+            result,
+            BTreeMap::from_iter(vec![
+                (
+                    Coords { x: 1, y: 0 },
+                    HashMap::from_iter(vec![(Coords { x: 1, y: 1 }, 1)])
+                ),
+                (
+                    Coords { x: 1, y: 1 },
+                    HashMap::from_iter(vec![
+                        (Coords { x: 1, y: 0 }, 1),
+                        (Coords { x: 3, y: 3 }, 4)
+                    ])
+                ),
+                // Going back up would mean climbing a slope
+                (
+                    Coords { x: 3, y: 3 },
+                    HashMap::from_iter(vec![(Coords { x: 3, y: 4 }, 1)])
+                ),
+                (
+                    Coords { x: 3, y: 4 },
+                    HashMap::from_iter(vec![(Coords { x: 3, y: 3 }, 1)])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn get_graph_test_input() {
+        let content = parse_content(
+            "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+",
+        )
+        .unwrap();
+
+        let graph = get_graph(&content, false);
+
+        display_graph(&graph);
+
+        assert_eq!(
+            graph,
+            BTreeMap::from_iter(vec![
+                (
+                    Coords { x: 1, y: 0 },
+                    HashMap::from_iter(vec![(Coords { x: 3, y: 5 }, 15)]),
+                ),
+                (
+                    Coords { x: 3, y: 5 },
+                    HashMap::from_iter(vec![
+                        (Coords { x: 1, y: 0 }, 15),
+                        (Coords { x: 5, y: 13 }, 22),
+                        (Coords { x: 11, y: 3 }, 22),
+                    ]),
+                ),
+                (
+                    Coords { x: 5, y: 13 },
+                    HashMap::from_iter(vec![
+                        (Coords { x: 3, y: 5 }, 22),
+                        (Coords { x: 13, y: 13 }, 12),
+                        (Coords { x: 13, y: 19 }, 38),
+                    ]),
+                ),
+                (
+                    Coords { x: 11, y: 3 },
+                    HashMap::from_iter(vec![
+                        (Coords { x: 21, y: 11 }, 30),
+                        (Coords { x: 13, y: 13 }, 24),
+                        (Coords { x: 3, y: 5 }, 22),
+                    ]),
+                ),
+                (
+                    Coords { x: 13, y: 13 },
+                    HashMap::from_iter(vec![
+                        (Coords { x: 13, y: 19 }, 10),
+                        (Coords { x: 11, y: 3 }, 24),
+                        (Coords { x: 5, y: 13 }, 12),
+                        (Coords { x: 21, y: 11 }, 18),
+                    ]),
+                ),
+                (
+                    Coords { x: 13, y: 19 },
+                    HashMap::from_iter(vec![
+                        (Coords { x: 13, y: 13 }, 10),
+                        (Coords { x: 5, y: 13 }, 38),
+                        (Coords { x: 19, y: 19 }, 10),
+                    ]),
+                ),
+                (
+                    Coords { x: 19, y: 19 },
+                    HashMap::from_iter(vec![
+                        (Coords { x: 21, y: 22 }, 5),
+                        (Coords { x: 21, y: 11 }, 10),
+                        (Coords { x: 13, y: 19 }, 10),
+                    ]),
+                ),
+                (
+                    Coords { x: 21, y: 11 },
+                    HashMap::from_iter(vec![
+                        (Coords { x: 19, y: 19 }, 10),
+                        (Coords { x: 11, y: 3 }, 30),
+                        (Coords { x: 13, y: 13 }, 18),
+                    ]),
+                ),
+                (
+                    Coords { x: 21, y: 22 },
+                    HashMap::from_iter(vec![(Coords { x: 19, y: 19 }, 5)]),
+                ),
+            ])
+        );
+    }
+}
+
+/// The longest path through the junctions of the graph, and its length
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Hike {
+    length: usize,
+    nodes: Vec<Node>,
+}
+
+/// Depth-first search state, with the nodes as indexes into a bitmask
+struct Search {
+    successors: Vec<Vec<(usize, usize)>>,
+    finish: usize,
+    // The only node leading to the finish, if so: once there, going anywhere
+    // else would cut the way to the finish
+    last_junction: Option<usize>,
+    path: Vec<usize>,
+    longest: Option<(usize, Vec<usize>)>,
+    steps: usize,
+}
+
+impl Search {
+    fn explore(&mut self, visited: u64, length: usize) {
+        self.steps += 1;
+        let node = *self.path.last().unwrap();
+        if node == self.finish {
+            if self.longest.as_ref().is_none_or(|(best, _)| length > *best) {
+                self.longest = Some((length, self.path.clone()));
+            }
+            return;
+        }
+        for index in 0..self.successors[node].len() {
+            let (next, weight) = self.successors[node][index];
+            if visited & (1 << next) != 0
+                || (self.last_junction == Some(node) && next != self.finish)
+            {
+                continue;
+            }
+            self.path.push(next);
+            self.explore(visited | (1 << next), length + weight);
+            self.path.pop();
+        }
+    }
+}
+
+/// Find the longest path without going through a node twice, or `None` if
+/// there is none or if the graph is too big for the 64 bits visited mask
+fn find_longest_path(graph: &Graph, starting_node: &Node, finish_node: &Node) -> Option<Hike> {
+    let nodes: Vec<&Node> = graph.keys().collect();
+    if nodes.len() > u64::BITS as usize {
+        return None;
+    }
+    let index = |node: &Node| nodes.binary_search(&node).ok();
+    let successors: Vec<Vec<(usize, usize)>> = graph
+        .values()
+        .map(|successors| {
+            let mut successors: Vec<(usize, usize)> = successors
+                .iter()
+                .filter_map(|(node, length)| Some((index(node)?, *length)))
+                .collect();
+            successors.sort();
+            successors
+        })
+        .collect();
+    let start = index(starting_node)?;
+    let finish = index(finish_node)?;
+    let predecessors: Vec<usize> = (0..nodes.len())
+        .filter(|node| successors[*node].iter().any(|(next, _)| *next == finish))
+        .collect();
+
+    let mut search = Search {
+        successors,
+        finish,
+        last_junction: match predecessors[..] {
+            [node] => Some(node),
+            _ => None,
+        },
+        path: vec![start],
+        longest: None,
+        steps: 0,
+    };
+    search.explore(1 << start, 0);
+
+    debug!("Took {} steps", search.steps);
+
+    search.longest.map(|(length, path)| Hike {
+        length,
+        nodes: path.into_iter().map(|node| *nodes[node]).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::input::get_file_content;
+
+    #[test]
+    fn find_longest_path_01() {
+        let graph = BTreeMap::from_iter(vec![
+            (
+                Coords { x: 1, y: 0 },
+                HashMap::from_iter(vec![(Coords { x: 3, y: 5 }, 15)]),
+            ),
+            (
+                Coords { x: 3, y: 5 },
+                HashMap::from_iter(vec![
+                    (Coords { x: 11, y: 3 }, 22),
+                    (Coords { x: 5, y: 13 }, 22),
+                    (Coords { x: 1, y: 0 }, 15),
+                ]),
+            ),
+            (
+                Coords { x: 5, y: 13 },
+                HashMap::from_iter(vec![
+                    (Coords { x: 13, y: 13 }, 12),
+                    (Coords { x: 3, y: 5 }, 22),
+                    (Coords { x: 13, y: 19 }, 38),
+                ]),
+            ),
+            (
+                Coords { x: 11, y: 3 },
+                HashMap::from_iter(vec![
+                    (Coords { x: 21, y: 11 }, 30),
+                    (Coords { x: 13, y: 13 }, 24),
+                    (Coords { x: 3, y: 5 }, 22),
+                ]),
+            ),
+            (
+                Coords { x: 13, y: 13 },
+                HashMap::from_iter(vec![
+                    (Coords { x: 5, y: 13 }, 12),
+                    (Coords { x: 11, y: 3 }, 24),
+                    (Coords { x: 13, y: 19 }, 10),
+                    (Coords { x: 21, y: 11 }, 18),
+                ]),
+            ),
+            (
+                Coords { x: 13, y: 19 },
+                HashMap::from_iter(vec![
+                    (Coords { x: 5, y: 13 }, 38),
+                    (Coords { x: 13, y: 13 }, 10),
+                    (Coords { x: 19, y: 19 }, 10),
+                ]),
+            ),
+            (
+                Coords { x: 21, y: 11 },
+                HashMap::from_iter(vec![
+                    (Coords { x: 11, y: 3 }, 30),
+                    (Coords { x: 13, y: 13 }, 18),
+                    (Coords { x: 19, y: 19 }, 10),
+                ]),
+            ),
+            (
+                Coords { x: 19, y: 19 },
+                HashMap::from_iter(vec![
+                    (Coords { x: 21, y: 22 }, 5),
+                    (Coords { x: 21, y: 11 }, 10),
+                    (Coords { x: 13, y: 19 }, 10),
+                ]),
+            ),
+            (
+                Coords { x: 21, y: 22 },
+                HashMap::from_iter(vec![(Coords { x: 19, y: 19 }, 5)]),
+            ),
+        ]);
+
+        let result = find_longest_path(&graph, &Coords { x: 1, y: 0 }, &Coords { x: 21, y: 22 });
+
+        assert_eq!(
+            result,
+            Some(Hike {
+                length: 154,
+                nodes: vec![
+                    Coords { x: 1, y: 0 },
+                    Coords { x: 3, y: 5 },
+                    Coords { x: 5, y: 13 },
+                    Coords { x: 13, y: 19 },
+                    Coords { x: 13, y: 13 },
+                    Coords { x: 11, y: 3 },
+                    Coords { x: 21, y: 11 },
+                    Coords { x: 19, y: 19 },
+                    Coords { x: 21, y: 22 },
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn find_longest_path_unreachable() {
+        let graph = BTreeMap::from_iter(vec![
+            (Coords { x: 1, y: 0 }, HashMap::new()),
+            (Coords { x: 2, y: 2 }, HashMap::new()),
+        ]);
+
+        let result = find_longest_path(&graph, &Coords { x: 1, y: 0 }, &Coords { x: 2, y: 2 });
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    #[ignore = "needs assets/input, which is not committed"]
+    fn find_longest_path_main() {
+        let content = &parse_content(&get_file_content("assets/input").unwrap()).unwrap();
+        let graph = get_graph(content, false);
+
+        assert_eq!(
+            find_longest_path(&graph, &content.start(), &content.finish()).map(|hike| hike.length),
+            Some(9999)
+        );
+    }
+}
+
+fn find_longest_hike(content: &Content, slippery: bool) -> Option<Hike> {
+    let graph = get_graph(content, slippery);
+    find_longest_path(&graph, &content.start(), &content.finish())
+}
+
+/// Every tile walked through by the hike, after the start
+fn get_hike_tiles(content: &Content, hike: &Hike, slippery: bool) -> Vec<Tile> {
+    hike.nodes
+        .windows(2)
+        .flat_map(|pair| {
+            // The longest of the trails between both junctions, as in the graph
+            get_neighbours(content, &pair[0], slippery)
+                .iter()
+                .filter_map(|first| follow_trail(content, &pair[0], first, slippery))
+                .filter(|tiles| tiles.last() == Some(&pair[1]))
+                .max_by_key(|tiles| tiles.len())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// The map with the hike drawn on it
+fn draw_hike(content: &Content, hike: &Hike, slippery: bool) -> Content {
+    let mut new_content = content.clone();
+    new_content.map.insert(content.start(), 'S');
+    for tile in get_hike_tiles(content, hike, slippery) {
+        // We don't want to «hide» the icy slopes in the resulting path display
+        if let Some('.') = new_content.map.get(&tile) {
+            new_content.map.insert(tile, 'O');
+        }
+    }
+    new_content
+}
+
+#[cfg(test)]
+mod tests_find_longest_hike {
+    use super::*;
+    use std::collections::HashSet;
+
+    const INPUT: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    #[test]
+    fn find_longest_hike_slippery() {
+        let content = parse_content(INPUT).unwrap();
+
+        let hike = find_longest_hike(&content, true).unwrap();
+
+        display_map(&draw_hike(&content, &hike, true));
+
+        assert_eq!(hike.length, 94);
+        let tiles = get_hike_tiles(&content, &hike, true);
+        assert_eq!(tiles.len(), 94);
+        assert_eq!(tiles.iter().collect::<HashSet<_>>().len(), 94);
+    }
+
+    #[test]
+    fn find_longest_hike_dry() {
+        let content = parse_content(INPUT).unwrap();
+
+        let hike = find_longest_hike(&content, false).unwrap();
+
+        assert_eq!(hike.length, 154);
+        assert_eq!(get_hike_tiles(&content, &hike, false).len(), 154);
+    }
+}
+
+/// The graph of part 2, each edge showing the length of the path it stands
+/// for
+impl ToDot for Content {
+    fn to_dot(&self) -> Dot {
+        let mut dot = Dot::graph("trails");
+        for (node, successors) in get_graph(self, false) {
+            for (successor, length) in successors {
+                dot.labeled_edge(&node.to_string(), &successor.to_string(), length);
+            }
+        }
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests_to_dot {
+    use super::*;
+
+    #[test]
+    fn to_dot_01() {
+        let content = parse_content(
+            "\
+#.##
+#..#
+##.#
+",
+        )
+        .unwrap();
+        assert_eq!(
+            content.to_dot().to_string(),
+            r#"graph "trails" {
+    "(1, 0)" [shape=ellipse];
+    "(2, 2)" [shape=ellipse];
+    "(1, 0)" -- "(2, 2)" [label="3"];
+}
+"#
+        );
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        let hike = find_longest_hike(input, true)?;
+        if log::enabled(Level::Trace) {
+            display_map(&draw_hike(input, &hike, true));
+        }
+        Some(hike.length)
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let hike = find_longest_hike(input, false)?;
+        if log::enabled(Level::Trace) {
+            display_map(&draw_hike(input, &hike, false));
+        }
+        Some(hike.length)
+    }
+
+    fn dot(input: &Self::Input) -> Option<Dot> {
+        Some(input.to_dot())
+    }
+}
@@ -0,0 +1,756 @@
+use std::fmt::Display;
+
+use aoc_core::{
+    debug, info,
+    parse::{parse_field, ParseError},
+    solution::Solution,
+};
+use itertools::Itertools;
+use num::{rational::Ratio, BigInt, Integer, ToPrimitive, Zero};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Hail {
+    px: i64,
+    py: i64,
+    pz: i64,
+    dx: i64,
+    dy: i64,
+    dz: i64,
+}
+
+type List = Vec<Hail>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Content {
+    list: List,
+}
+
+/// Parse the three comma separated coordinates of a position or a velocity
+fn parse_vector(index: usize, line: &str, text: &str, what: &str) -> Result<[i64; 3], ParseError> {
+    let fields = text.split(',').map(str::trim).collect::<Vec<_>>();
+    let [x, y, z] = fields[..] else {
+        return Err(ParseError::at(
+            index,
+            line,
+            text,
+            format!("expected the 3 coordinates of a {what}"),
+        ));
+    };
+    Ok([
+        parse_field(index, line, x, what)?,
+        parse_field(index, line, y, what)?,
+        parse_field(index, line, z, what)?,
+    ])
+}
+
+fn parse_hail(index: usize, line: &str) -> Result<Hail, ParseError> {
+    let Some((position, velocity)) = line.split_once('@') else {
+        return Err(ParseError::missing(
+            index,
+            line,
+            "`@` between position and velocity",
+        ));
+    };
+    let [px, py, pz] = parse_vector(index, line, position, "position")?;
+    let [dx, dy, dz] = parse_vector(index, line, velocity, "velocity")?;
+    Ok(Hail {
+        px,
+        py,
+        pz,
+        dx,
+        dy,
+        dz,
+    })
+}
+
+fn parse_content(lines: &str) -> Result<Content, ParseError> {
+    Ok(Content {
+        list: lines
+            .split("\n")
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(index, line)| parse_hail(index, line))
+            .collect::<Result<List, ParseError>>()?,
+    })
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        let content = parse_content(
+            "\
+19, 13, 30 @ -2, 1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @ 1, -5, -3
+",
+        )
+        .unwrap();
+        assert_eq!(
+            content.list.get(3),
+            Some(&Hail {
+                px: 12,
+                py: 31,
+                pz: 28,
+                dx: -1,
+                dy: -2,
+                dz: -1
+            })
+        );
+    }
+
+    #[test]
+    fn parse_content_malformed() {
+        let error = parse_content("19, 13, 30 @ -2, 1, -2\n18, 19, 22 -1, -1, -2").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.reason, "missing `@` between position and velocity");
+        let error = parse_content("19, 13 @ -2, 1, -2").unwrap_err();
+        assert_eq!(error.column, 1);
+        assert_eq!(error.reason, "expected the 3 coordinates of a position");
+        let error = parse_content("19, 13, 30 @ -2, x, -2").unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (18, "x"));
+        assert!(error.reason.starts_with("invalid velocity: "));
+    }
+}
+
+// Positions go up to about 10^14 and velocities to about 10^3, so products of
+// both are still far from overflowing an i128: every computation is exact
+type CoordType = Ratio<i128>;
+
+fn as_rational<T: Clone + Integer + From<i64>>(hail: &Hail) -> [[Ratio<T>; 3]; 2] {
+    let r = |v: i64| Ratio::from_integer(T::from(v));
+    [
+        [r(hail.px), r(hail.py), r(hail.pz)],
+        [r(hail.dx), r(hail.dy), r(hail.dz)],
+    ]
+}
+
+/// The Z component of the cross product of two vectors of the X/Y plane
+fn cross_xy((ax, ay): (CoordType, CoordType), (bx, by): (CoordType, CoordType)) -> CoordType {
+    ax * by - ay * bx
+}
+
+/// Where the paths of two hailstones meet, ignoring the Z axis
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Crossing {
+    /// Both go along parallel lines, which never meet
+    Parallel,
+    /// Both go along the same line
+    Coincident,
+    /// Both lines cross at (`x`, `y`), the first hailstone being there at `t1`
+    /// and the second one at `t2` (negative when in the past)
+    At {
+        x: CoordType,
+        y: CoordType,
+        t1: CoordType,
+        t2: CoordType,
+    },
+}
+
+/// Hailstones are expected to move: one standing still has no path to speak of
+fn get_crossing(h1: &Hail, h2: &Hail) -> Crossing {
+    let [[x1, y1, _], [vx1, vy1, _]] = as_rational::<i128>(h1);
+    let [[x2, y2, _], [vx2, vy2, _]] = as_rational::<i128>(h2);
+
+    // p1 + t1 * v1 = p2 + t2 * v2, so (p2 - p1) = t1 * v1 - t2 * v2: crossing
+    // both sides with v2, then v1, leaves a single unknown
+    let offset = (x2 - x1, y2 - y1);
+    let determinant = cross_xy((vx1, vy1), (vx2, vy2));
+    if determinant == CoordType::default() {
+        return if cross_xy(offset, (vx1, vy1)) == CoordType::default() {
+            Crossing::Coincident
+        } else {
+            Crossing::Parallel
+        };
+    }
+    let t1 = cross_xy(offset, (vx2, vy2)) / determinant;
+    let t2 = cross_xy(offset, (vx1, vy1)) / determinant;
+    Crossing::At {
+        x: x1 + t1 * vx1,
+        y: y1 + t1 * vy1,
+        t1,
+        t2,
+    }
+}
+
+#[cfg(test)]
+mod tests_get_crossing {
+    use super::*;
+
+    fn hail(line: &str) -> Hail {
+        parse_content(line).unwrap().list.remove(0)
+    }
+
+    #[test]
+    fn get_crossing_at() {
+        let r = |n: i128, d: i128| CoordType::new(n, d);
+        assert_eq!(
+            get_crossing(
+                &hail("19, 13, 30 @ -2, 1, -2"),
+                &hail("18, 19, 22 @ -1, -1, -2")
+            ),
+            Crossing::At {
+                x: r(43, 3),
+                y: r(46, 3),
+                t1: r(7, 3),
+                t2: r(11, 3)
+            }
+        );
+        // Crossed in the past
+        assert!(matches!(
+            get_crossing(&hail("19, 13, 30 @ -2, 1, -2"), &hail("20, 19, 15 @ 1, -5, -3")),
+            Crossing::At { t1, .. } if t1 < CoordType::default()
+        ));
+    }
+
+    #[test]
+    fn get_crossing_parallel() {
+        assert_eq!(
+            get_crossing(
+                &hail("18, 19, 22 @ -1, -1, -2"),
+                &hail("20, 25, 34 @ -2, -2, -4")
+            ),
+            Crossing::Parallel
+        );
+    }
+
+    #[test]
+    fn get_crossing_coincident() {
+        assert_eq!(
+            get_crossing(
+                &hail("18, 19, 22 @ -1, -1, -2"),
+                &hail("20, 21, 34 @ 2, 2, -4")
+            ),
+            Crossing::Coincident
+        );
+    }
+}
+
+/// Whether the future paths of two hailstones going along the same line
+/// overlap within the test area
+fn do_coincident_paths_cross(h1: &Hail, h2: &Hail, min: CoordType, max: CoordType) -> bool {
+    let [[x1, y1, _], [vx1, vy1, _]] = as_rational::<i128>(h1);
+    let [[x2, y2, _], [vx2, vy2, _]] = as_rational::<i128>(h2);
+    let zero = CoordType::default();
+
+    // Along the line, places are told apart by when the first hailstone is
+    // (or was) there: that is `s` in p1 + s * v1. Its future is s >= 0.
+    let (mut lo, mut hi): (CoordType, Option<CoordType>) = (zero, None);
+    let mut restrict = |from: Option<CoordType>, to: Option<CoordType>| {
+        if let Some(from) = from {
+            lo = lo.max(from);
+        }
+        if let Some(to) = to {
+            hi = Some(hi.map_or(to, |hi| hi.min(to)));
+        }
+    };
+
+    // Within the test area, on each axis
+    for (p1, v1) in [(x1, vx1), (y1, vy1)] {
+        if v1 == zero {
+            if p1 < min || p1 > max {
+                return false;
+            }
+        } else {
+            let (a, b) = ((min - p1) / v1, (max - p1) / v1);
+            restrict(Some(a.min(b)), Some(a.max(b)));
+        }
+    }
+
+    // In the future of the second hailstone, which is at p1 + s0 * v1 now and
+    // moves k times as fast as the first one
+    let (s0, k) = if vx1 != zero {
+        ((x2 - x1) / vx1, vx2 / vx1)
+    } else {
+        ((y2 - y1) / vy1, vy2 / vy1)
+    };
+    if k >= zero {
+        restrict(Some(s0), None);
+    }
+    if k <= zero {
+        restrict(None, Some(s0));
+    }
+
+    hi.is_none_or(|hi| lo <= hi)
+}
+
+#[cfg(test)]
+mod tests_do_coincident_paths_cross {
+    use super::*;
+
+    fn hail(line: &str) -> Hail {
+        parse_content(line).unwrap().list.remove(0)
+    }
+
+    #[test]
+    fn do_coincident_paths_cross_01() {
+        let (min, max) = (CoordType::from_integer(0), CoordType::from_integer(10));
+        // Towards each other
+        assert!(do_coincident_paths_cross(
+            &hail("1, 1, 0 @ 1, 1, 0"),
+            &hail("5, 5, 0 @ -1, -1, 0"),
+            min,
+            max
+        ));
+        // Away from each other
+        assert!(!do_coincident_paths_cross(
+            &hail("1, 1, 0 @ -1, -1, 0"),
+            &hail("5, 5, 0 @ 1, 1, 0"),
+            min,
+            max
+        ));
+        // One ahead of the other, which is already out of the area
+        assert!(!do_coincident_paths_cross(
+            &hail("1, 1, 0 @ 1, 1, 0"),
+            &hail("12, 12, 0 @ 1, 1, 0"),
+            min,
+            max
+        ));
+        // One ahead of the other, both still in the area
+        assert!(do_coincident_paths_cross(
+            &hail("1, 1, 0 @ 1, 1, 0"),
+            &hail("3, 3, 0 @ 2, 2, 0"),
+            min,
+            max
+        ));
+    }
+}
+
+fn count_colliding_hailstones(content: &Content, min: i64, max: i64) -> usize {
+    let (min, max) = (
+        CoordType::from_integer(min.into()),
+        CoordType::from_integer(max.into()),
+    );
+    let zero = CoordType::default();
+    content
+        .list
+        .iter()
+        .tuple_combinations::<(_, _)>()
+        .filter(|(h1, h2)| match get_crossing(h1, h2) {
+            Crossing::Parallel => false,
+            Crossing::Coincident => {
+                debug!("{:?} and {:?} go along the same line", h1, h2);
+                do_coincident_paths_cross(h1, h2, min, max)
+            }
+            Crossing::At { x, y, t1, t2 } => {
+                t1 >= zero && t2 >= zero && x >= min && x <= max && y >= min && y <= max
+            }
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests_count_colliding_hailstones {
+    use super::*;
+
+    #[test]
+    fn count_colliding_hailstones_01() {
+        let hailstones = parse_content(
+            "\
+19, 13, 30 @ -2, 1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @ 1, -5, -3
+",
+        )
+        .unwrap();
+        assert_eq!(count_colliding_hailstones(&hailstones, 7, 27), 2);
+    }
+
+    #[test]
+    fn count_colliding_hailstones_on_the_edge() {
+        // Both are at (200000000000000, 200000000000000) after one nanosecond
+        let hailstones = parse_content(
+            "\
+199999999999999, 199999999999998, 0 @ 1, 2, 0
+199999999999997, 200000000000001, 0 @ 3, -1, 0
+",
+        )
+        .unwrap();
+        assert_eq!(
+            count_colliding_hailstones(&hailstones, 200000000000000, 400000000000000),
+            1
+        );
+        assert_eq!(
+            count_colliding_hailstones(&hailstones, 200000000000001, 400000000000000),
+            0
+        );
+    }
+}
+
+// Solving for the rock needs a whole 6x6 system, whose coefficients grow well
+// past what an i128 holds while it is being eliminated
+type BigCoordType = Ratio<BigInt>;
+type Vector = [BigCoordType; 3];
+
+fn sub(a: &Vector, b: &Vector) -> Vector {
+    [&a[0] - &b[0], &a[1] - &b[1], &a[2] - &b[2]]
+}
+
+fn cross(a: &Vector, b: &Vector) -> Vector {
+    [
+        &a[1] * &b[2] - &a[2] * &b[1],
+        &a[2] * &b[0] - &a[0] * &b[2],
+        &a[0] * &b[1] - &a[1] * &b[0],
+    ]
+}
+
+/// Linear equations on the rock position and velocity (the first six columns,
+/// the last one being the constant), kept in reduced row echelon form
+#[derive(Debug, Default)]
+struct System {
+    /// Each equation, with the column of its leading 1, found in no other one
+    rows: Vec<([BigCoordType; 7], usize)>,
+}
+
+impl System {
+    /// Add an equation, telling whether it brings anything the others didn't
+    fn add(&mut self, mut row: [BigCoordType; 7]) -> bool {
+        for (other, pivot) in &self.rows {
+            let factor = row[*pivot].clone();
+            if !factor.is_zero() {
+                for (item, other) in row.iter_mut().zip(other) {
+                    *item -= other * &factor;
+                }
+            }
+        }
+        let Some(pivot) = (0..6).find(|&column| !row[column].is_zero()) else {
+            return false;
+        };
+        let value = row[pivot].clone();
+        for item in &mut row {
+            *item /= &value;
+        }
+        for (other, _) in &mut self.rows {
+            let factor = other[pivot].clone();
+            if !factor.is_zero() {
+                for (item, new) in other.iter_mut().zip(&row) {
+                    *item -= new * &factor;
+                }
+            }
+        }
+        self.rows.push((row, pivot));
+        true
+    }
+
+    fn is_complete(&self) -> bool {
+        self.rows.len() == 6
+    }
+
+    /// The only solution, once every unknown has its own equation
+    fn solution(&self) -> Option<[BigCoordType; 6]> {
+        if !self.is_complete() {
+            return None;
+        }
+        let mut solution: [BigCoordType; 6] = Default::default();
+        for (row, pivot) in &self.rows {
+            solution[*pivot] = row[6].clone();
+        }
+        Some(solution)
+    }
+}
+
+// The rock (P, V) hits hailstone (Pi, Vi) when P + t * V = Pi + t * Vi for
+// some t, which means (P - Pi) and (V - Vi) are parallel:
+//
+// (P - Pi) × (V - Vi) = P × V - P × Vi - Pi × V + Pi × Vi = 0
+//
+// The only part which isn't linear, P × V, is the same for every hailstone, so
+// taking the equation of hailstone j away from the one of hailstone i leaves:
+//
+// P × (Vj - Vi) + (Pj - Pi) × V = Pj × Vj - Pi × Vi
+//
+// Which gives three linear equations on the six unknowns for each pair.
+fn get_equations(hi: &Hail, hj: &Hail) -> [[BigCoordType; 7]; 3] {
+    let [pi, vi] = as_rational::<BigInt>(hi);
+    let [pj, vj] = as_rational::<BigInt>(hj);
+    let [dx, dy, dz] = sub(&vj, &vi);
+    let [ex, ey, ez] = sub(&pj, &pi);
+    let [cx, cy, cz] = sub(&cross(&pj, &vj), &cross(&pi, &vi));
+    let zero = BigCoordType::zero;
+    [
+        [zero(), dz.clone(), -&dy, zero(), -&ez, ey.clone(), cx],
+        [-&dz, zero(), dx.clone(), ez, zero(), -&ex, cy],
+        [dy, -dx, zero(), -ey, ex, zero(), cz],
+    ]
+}
+
+/// When the rock thrown from `position` at `velocity` is at the same place as
+/// the hailstone, if ever. If both always are, the hailstone is hit right away.
+fn get_impact_time(position: &Vector, velocity: &Vector, hail: &Hail) -> Option<BigCoordType> {
+    let [hail_position, hail_velocity] = as_rational::<BigInt>(hail);
+    let mut time = None;
+    for axis in 0..3 {
+        let distance = &hail_position[axis] - &position[axis];
+        let closing_speed = &velocity[axis] - &hail_velocity[axis];
+        if closing_speed.is_zero() {
+            if !distance.is_zero() {
+                return None;
+            }
+        } else {
+            let t = distance / closing_speed;
+            match &time {
+                Some(time) if *time != t => return None,
+                _ => time = Some(t),
+            }
+        }
+    }
+    Some(time.unwrap_or_default())
+}
+
+/// The rock to throw, and when it hits each hailstone, in the order of the
+/// input
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Throw {
+    position: [i64; 3],
+    velocity: [i64; 3],
+    impacts: Vec<i64>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum ThrowError {
+    /// Even with every hailstone, more than one trajectory fits. The ones
+    /// listed gave fewer than three new equations (the first hailstone, which
+    /// all the others are paired with, gives none by itself).
+    Degenerate(Vec<usize>),
+    /// The only possible trajectory doesn't start from, or go at, whole numbers
+    NotIntegral,
+    /// The rock never hits this hailstone, or not at a nonnegative whole time
+    Miss {
+        index: usize,
+        time: Option<BigCoordType>,
+    },
+}
+
+impl Display for ThrowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThrowError::Degenerate(hailstones) => write!(
+                f,
+                "No single rock trajectory, hailstones {:?} are degenerate",
+                hailstones
+            ),
+            ThrowError::NotIntegral => write!(f, "The rock trajectory is not made of integers"),
+            ThrowError::Miss { index, time: None } => {
+                write!(f, "The rock never hits hailstone {index}")
+            }
+            ThrowError::Miss {
+                index,
+                time: Some(time),
+            } => write!(
+                f,
+                "The rock only meets hailstone {index} at time {time}, which is negative or not a whole number"
+            ),
+        }
+    }
+}
+
+/// Unlike `get_crossing`, which meets two known paths, the rock path is the
+/// unknown here, hence the linear system rather than pairwise crossings
+fn throw_rock(content: &Content) -> Result<Throw, ThrowError> {
+    let mut system = System::default();
+    let mut degenerate = vec![];
+    if let Some((first, others)) = content.list.split_first() {
+        for (index, hail) in others.iter().enumerate() {
+            let added = get_equations(first, hail)
+                .into_iter()
+                .filter(|row| system.add(row.clone()))
+                .count();
+            if added < 3 {
+                debug!("Hailstone {} only adds {} equations", index + 1, added);
+                degenerate.push(index + 1);
+            }
+            if system.is_complete() {
+                break;
+            }
+        }
+    }
+    let [px, py, pz, vx, vy, vz] = system
+        .solution()
+        .ok_or(ThrowError::Degenerate(degenerate))?;
+    let (position, velocity) = ([px, py, pz], [vx, vy, vz]);
+
+    let as_integer = |value: &BigCoordType| {
+        value
+            .is_integer()
+            .then(|| value.to_integer().to_i64())
+            .flatten()
+            .ok_or(ThrowError::NotIntegral)
+    };
+    let throw = Throw {
+        position: [
+            as_integer(&position[0])?,
+            as_integer(&position[1])?,
+            as_integer(&position[2])?,
+        ],
+        velocity: [
+            as_integer(&velocity[0])?,
+            as_integer(&velocity[1])?,
+            as_integer(&velocity[2])?,
+        ],
+        impacts: content
+            .list
+            .iter()
+            .enumerate()
+            .map(|(index, hail)| {
+                let time = get_impact_time(&position, &velocity, hail)
+                    .ok_or(ThrowError::Miss { index, time: None })?;
+                match time.to_integer().to_i64() {
+                    Some(t) if time.is_integer() && t >= 0 => Ok(t),
+                    _ => Err(ThrowError::Miss {
+                        index,
+                        time: Some(time),
+                    }),
+                }
+            })
+            .collect::<Result<_, _>>()?,
+    };
+    debug!(
+        "Throwing the rock from {:?} at {:?} hits the hailstones at {:?}",
+        throw.position, throw.velocity, throw.impacts
+    );
+    Ok(throw)
+}
+
+#[cfg(test)]
+mod tests_throw_rock {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+19, 13, 30 @ -2, 1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @ 1, -5, -3
+";
+
+    #[test]
+    fn throw_rock_01() {
+        assert_eq!(
+            throw_rock(&parse_content(EXAMPLE).unwrap()),
+            Ok(Throw {
+                position: [24, 13, 10],
+                velocity: [-3, 1, 2],
+                impacts: vec![5, 3, 4, 6, 1]
+            })
+        );
+    }
+
+    #[test]
+    fn throw_rock_never_hits() {
+        let content = parse_content(&format!("{EXAMPLE}0, 0, 0 @ 0, 0, 0\n")).unwrap();
+        assert_eq!(
+            throw_rock(&content),
+            Err(ThrowError::Miss {
+                index: 5,
+                time: None
+            })
+        );
+    }
+
+    #[test]
+    fn throw_rock_hits_in_the_past() {
+        // Where the rock was at -2
+        let content = parse_content(&format!("{EXAMPLE}30, 11, 6 @ 0, 0, 0\n")).unwrap();
+        assert_eq!(
+            throw_rock(&content),
+            Err(ThrowError::Miss {
+                index: 5,
+                time: Some(BigCoordType::from_integer((-2).into()))
+            })
+        );
+    }
+
+    #[test]
+    fn throw_rock_hits_between_nanoseconds() {
+        let content = parse_content(&format!("{EXAMPLE}25, 13, 10 @ -5, 1, 2\n")).unwrap();
+        assert_eq!(
+            throw_rock(&content),
+            Err(ThrowError::Miss {
+                index: 5,
+                time: Some(BigCoordType::new(1.into(), 2.into()))
+            })
+        );
+        assert_eq!(
+            throw_rock(&content).unwrap_err().to_string(),
+            "The rock only meets hailstone 5 at time 1/2, which is negative or not a whole number"
+        );
+    }
+
+    #[test]
+    fn throw_rock_degenerate() {
+        // All going the same way: only the rock velocity can be told
+        let content = parse_content(
+            "\
+1, 0, 0 @ 1, 1, 1
+0, 1, 0 @ 1, 1, 1
+0, 0, 1 @ 1, 1, 1
+",
+        )
+        .unwrap();
+        assert_eq!(
+            throw_rock(&content),
+            Err(ThrowError::Degenerate(vec![1, 2]))
+        );
+        assert_eq!(
+            throw_rock(&parse_content("19, 13, 30 @ -2, 1, -2\n").unwrap()),
+            Err(ThrowError::Degenerate(vec![]))
+        );
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(count_colliding_hailstones(
+            input,
+            200000000000000,
+            400000000000000,
+        ))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        match throw_rock(input) {
+            Ok(throw) => Some(throw.position.iter().sum::<i64>()),
+            Err(error) => {
+                info!("{}", error);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_day24 {
+    use super::*;
+    use aoc_core::solution::solve;
+
+    #[test]
+    fn day24_example() {
+        assert_eq!(
+            solve::<Day24>(
+                "\
+19, 13, 30 @ -2, 1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @ 1, -5, -3
+",
+                &[2]
+            ),
+            Ok(vec![Some(String::from("47"))])
+        );
+    }
+}
@@ -0,0 +1,573 @@
+use std::{
+    cmp::{max, min, Reverse},
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap},
+};
+use std::{fmt::Display, str::FromStr};
+
+use aoc_core::{
+    debug,
+    dot::{Dot, ToDot},
+    info,
+    parse::ParseError,
+    solution::Solution,
+};
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+type Nodes = Vec<String>;
+type Graph = HashMap<String, Nodes>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Content {
+    graph: Graph,
+}
+
+fn parse_content(lines: &str) -> Result<Content, ParseError> {
+    let mut graph = Graph::new();
+    for (index, line) in lines.split("\n").enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let Some((name, connections)) = line.split_once(':') else {
+            return Err(ParseError::missing(
+                index,
+                line,
+                "`:` after the component name",
+            ));
+        };
+        let name = name.trim();
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(ParseError::at(
+                index,
+                line,
+                name,
+                "expected a single component name",
+            ));
+        }
+        if graph.contains_key(name) {
+            return Err(ParseError::at(index, line, name, "component listed twice"));
+        }
+        graph.insert(
+            name.to_owned(),
+            connections
+                .split_ascii_whitespace()
+                .map(str::to_owned)
+                .collect(),
+        );
+    }
+    Ok(Content { graph })
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        let content = parse_content(
+            "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+",
+        )
+        .unwrap();
+        assert_eq!(content.graph.get("bvb").unwrap(), &vec!["xhk", "hfx"]);
+    }
+
+    #[test]
+    fn parse_content_malformed() {
+        let error = parse_content("jqt: rhn\nrhn xhk").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 8));
+        assert_eq!(error.reason, "missing `:` after the component name");
+        let error = parse_content(": rhn").unwrap_err();
+        assert_eq!(error.reason, "expected a single component name");
+        let error = parse_content("jqt: rhn\njqt: xhk").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.reason, "component listed twice");
+    }
+}
+
+/// A cut splitting the graph in two
+#[derive(Debug, PartialEq, Clone)]
+pub struct MinCut {
+    /// Edges going from one side to the other, each as (smallest, largest)
+    edges: Vec<(String, String)>,
+    /// Both sides of the cut, the one holding the first node (in name order)
+    /// coming first
+    partitions: (Vec<String>, Vec<String>),
+}
+
+impl MinCut {
+    /// Build the cut separating `side` from the rest of the graph
+    fn from_side(graph: &Graph, side: &BTreeSet<&str>) -> MinCut {
+        let mut edges = graph
+            .iter()
+            .flat_map(|(k, v_list)| v_list.iter().map(move |v| (k, v)))
+            .filter(|(k, v)| side.contains(k.as_str()) != side.contains(v.as_str()))
+            .map(|(k, v)| (min(k, v).to_owned(), max(k, v).to_owned()))
+            .collect::<Vec<_>>();
+        edges.sort();
+        edges.dedup();
+
+        let (mut first, mut second): (Vec<String>, Vec<String>) = get_names(graph)
+            .into_iter()
+            .map(str::to_owned)
+            .partition(|name| side.contains(name.as_str()));
+        if second.first() < first.first() {
+            (first, second) = (second, first);
+        }
+        MinCut {
+            edges,
+            partitions: (first, second),
+        }
+    }
+
+    /// The wanted answer: the product of the sizes of both sides
+    fn product(&self) -> usize {
+        self.partitions.0.len() * self.partitions.1.len()
+    }
+}
+
+/// How to look for the minimum cut
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Method {
+    /// Deterministic, the default
+    StoerWagner,
+    /// Randomized contractions, repeated until a cut of the expected size is
+    /// found, or given up on after `attempts`; seeded so that runs can be
+    /// reproduced
+    Karger { seed: u64, attempts: usize },
+}
+
+/// Enough for the attempts of Karger's algorithm to find a minimum cut of
+/// puzzle inputs most of the time
+const KARGER_ATTEMPTS: usize = 1000;
+
+impl Method {
+    /// Karger's algorithm with the default seed and attempts
+    pub const KARGER: Method = Method::Karger {
+        seed: 0,
+        attempts: KARGER_ATTEMPTS,
+    };
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Method::StoerWagner => write!(f, "stoer-wagner"),
+            Method::Karger { seed, attempts } => write!(f, "karger:{}:{}", seed, attempts),
+        }
+    }
+}
+
+/// Either `stoer-wagner` or `karger`, optionally followed by `:<seed>` and
+/// then `:<attempts>`
+impl FromStr for Method {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "invalid method `{}`, expected `stoer-wagner` or e.g. `karger:25:1000`",
+                s
+            )
+        };
+        let mut fields = s.split(':');
+        match fields.next() {
+            Some("stoer-wagner") if fields.next().is_none() => Ok(Method::StoerWagner),
+            Some("karger") => {
+                let seed = fields
+                    .next()
+                    .map_or(Ok(0), str::parse)
+                    .map_err(|_| invalid())?;
+                let attempts = fields
+                    .next()
+                    .map_or(Ok(KARGER_ATTEMPTS), str::parse)
+                    .map_err(|_| invalid())?;
+                if attempts == 0 || fields.next().is_some() {
+                    return Err(invalid());
+                }
+                Ok(Method::Karger { seed, attempts })
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_method {
+    use super::*;
+
+    #[test]
+    fn method_from_str() {
+        assert_eq!("stoer-wagner".parse(), Ok(Method::StoerWagner));
+        assert_eq!("karger".parse(), Ok(Method::KARGER));
+        assert_eq!(
+            "karger:25".parse(),
+            Ok(Method::Karger {
+                seed: 25,
+                attempts: 1000
+            })
+        );
+        assert_eq!(
+            "karger:25:10".parse(),
+            Ok(Method::Karger {
+                seed: 25,
+                attempts: 10
+            })
+        );
+        assert!("karger:25:0".parse::<Method>().is_err());
+        assert!("karger:x".parse::<Method>().is_err());
+        assert!("stoer-wagner:1".parse::<Method>().is_err());
+        assert!("dinic".parse::<Method>().is_err());
+    }
+
+    #[test]
+    fn method_display() {
+        let method: Method = "karger:25:10".parse().unwrap();
+        assert_eq!(method.to_string(), "karger:25:10");
+        assert_eq!(Method::StoerWagner.to_string(), "stoer-wagner");
+    }
+}
+
+/// Every node of the graph, sorted by name
+fn get_names(graph: &Graph) -> Vec<&str> {
+    graph
+        .iter()
+        .flat_map(|(k, v_list)| std::iter::once(k).chain(v_list))
+        .map(String::as_str)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Find a minimum cut of the graph, or `None` if it has less than two nodes or
+/// if its minimum cut is not of the `expected` size. Karger's algorithm needs
+/// that `expected` size and, unlike Stoer-Wagner's, may return a larger cut
+/// than the minimum one when it happens upon it before any smaller cut.
+fn get_min_cut(graph: &Graph, method: Method, expected: Option<usize>) -> Option<MinCut> {
+    if get_names(graph).len() < 2 {
+        return None;
+    }
+    let min_cut = match method {
+        Method::StoerWagner => stoer_wagner(graph),
+        Method::Karger { seed, attempts } => karger(graph, seed, attempts, expected?)?,
+    };
+    match expected {
+        Some(size) if min_cut.edges.len() != size => None,
+        _ => Some(min_cut),
+    }
+}
+
+// Using Stoer-Wagner's algorithm: each phase grows a set from the first node by
+// always adding the most tightly connected node, the cut isolating the last
+// added one being a candidate; those last two nodes are then merged. As the
+// phases are deterministic, so is the result.
+//
+// See https://en.wikipedia.org/wiki/Stoer%E2%80%93Wagner_algorithm for more
+// information
+fn stoer_wagner(graph: &Graph) -> MinCut {
+    let names = get_names(graph);
+    let indexes: HashMap<&str, usize> = names.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    let mut weights: Vec<BTreeMap<usize, usize>> = vec![BTreeMap::new(); names.len()];
+    graph.iter().for_each(|(k, v_list)| {
+        v_list.iter().for_each(|v| {
+            let (a, b) = (indexes[k.as_str()], indexes[v.as_str()]);
+            if a != b {
+                *weights[a].entry(b).or_default() += 1;
+                *weights[b].entry(a).or_default() += 1;
+            }
+        })
+    });
+    // The original nodes merged into each node, empty once merged away
+    let mut members: Vec<Vec<usize>> = (0..names.len()).map(|i| vec![i]).collect();
+
+    let mut best: Option<(usize, Vec<usize>)> = None;
+    for remaining in (2..=names.len()).rev() {
+        let mut added = vec![false; names.len()];
+        let mut keys = vec![0; names.len()];
+        let mut queue = BinaryHeap::from([(0, Reverse(0))]);
+        let (mut s, mut t) = (0, 0);
+        let mut count = 0;
+        while count < remaining {
+            let Some((key, Reverse(node))) = queue.pop() else {
+                // Not connected to the set: start again from another node
+                let node = (0..names.len())
+                    .find(|&i| !added[i] && !members[i].is_empty())
+                    .unwrap();
+                queue.push((0, Reverse(node)));
+                continue;
+            };
+            if added[node] || key != keys[node] {
+                continue;
+            }
+            added[node] = true;
+            count += 1;
+            (s, t) = (t, node);
+            for (&next, &weight) in &weights[node] {
+                if !added[next] {
+                    keys[next] += weight;
+                    queue.push((keys[next], Reverse(next)));
+                }
+            }
+        }
+
+        let cut = keys[t];
+        if best.as_ref().is_none_or(|(size, _)| cut < *size) {
+            best = Some((cut, members[t].clone()));
+        }
+
+        let t_members = std::mem::take(&mut members[t]);
+        members[s].extend(t_members);
+        for (next, weight) in std::mem::take(&mut weights[t]) {
+            weights[next].remove(&t);
+            if next != s {
+                *weights[s].entry(next).or_default() += weight;
+                *weights[next].entry(s).or_default() += weight;
+            }
+        }
+    }
+
+    let (_, side) = best.unwrap();
+    MinCut::from_side(graph, &side.into_iter().map(|i| names[i]).collect())
+}
+
+// Using Karger's algorithm: contract random edges until two nodes remain, and
+// start again until the remaining edges are as many as expected. Each attempt
+// only finds a minimum cut with some probability, so after `attempts` of them
+// (or as soon as a smaller cut shows the expected size is not the minimum one)
+// there is no cut to return.
+//
+// See https://en.wikipedia.org/wiki/Karger%27s_algorithm for more information
+fn karger(graph: &Graph, seed: u64, attempts: usize, expected: usize) -> Option<MinCut> {
+    let names = get_names(graph);
+    let mut edges: Vec<(&str, &str)> = vec![];
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    graph.iter().for_each(|(k, v_list)| {
+        v_list.iter().for_each(|v| {
+            edges.push((min(k, v), max(k, v)));
+        })
+    });
+
+    // Many thanks to https://github.com/LinAGKar/advent-of-code-2023-rust/blob/master/day25/src/main.rs
+    // for the «last mile» regarding "edges" switching !
+    for _ in 0..attempts {
+        let mut tmp_edges = edges.clone();
+        let mut members: HashMap<&str, Vec<&str>> = names.iter().map(|&n| (n, vec![n])).collect();
+        let mut vertices_count = names.len();
+        while vertices_count > 2 && !tmp_edges.is_empty() {
+            // Choose an edge at random and contract its two vertices
+            let (a, b) = tmp_edges.swap_remove(rng.gen_range(0..tmp_edges.len()));
+            let b_members = members.remove(b).unwrap();
+            members.get_mut(a).unwrap().extend(b_members);
+
+            let mut i = 0;
+            while i < tmp_edges.len() {
+                let (c, d) = tmp_edges[i];
+                if (c, d) == (a, b) {
+                    tmp_edges.swap_remove(i);
+                } else if c == b {
+                    tmp_edges[i] = (min(a, d), max(a, d));
+                    i += 1;
+                } else if d == b {
+                    tmp_edges[i] = (min(a, c), max(a, c));
+                    i += 1;
+                } else {
+                    i += 1;
+                }
+            }
+            vertices_count -= 1;
+        }
+
+        if vertices_count == 2 && tmp_edges.len() < expected {
+            info!(
+                "Found a cut of {} edges, so no minimum cut has {}",
+                tmp_edges.len(),
+                expected
+            );
+            return None;
+        }
+        if vertices_count == 2 && tmp_edges.len() == expected {
+            let side = members.into_values().next().unwrap();
+            return Some(MinCut::from_side(graph, &side.into_iter().collect()));
+        }
+    }
+
+    info!(
+        "No cut of {} edges found after {} attempts",
+        expected, attempts
+    );
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+
+    fn expected_cut() -> MinCut {
+        let names = |list: &str| list.split(' ').map(str::to_owned).collect::<Vec<_>>();
+        MinCut {
+            edges: vec![
+                ("bvb".to_owned(), "cmg".to_owned()),
+                ("hfx".to_owned(), "pzl".to_owned()),
+                ("jqt".to_owned(), "nvd".to_owned()),
+            ],
+            partitions: (
+                names("bvb hfx jqt ntq rhn xhk"),
+                names("cmg frs lhk lsr nvd pzl qnr rsh rzs"),
+            ),
+        }
+    }
+
+    #[test]
+    fn get_min_cut_test_input() {
+        let content = parse_content(EXAMPLE).unwrap();
+        let min_cut = get_min_cut(&content.graph, Method::StoerWagner, None).unwrap();
+        assert_eq!(min_cut, expected_cut());
+        assert_eq!(min_cut.product(), 54);
+    }
+
+    #[test]
+    fn get_min_cut_expected_size() {
+        let content = parse_content(EXAMPLE).unwrap();
+        assert_eq!(
+            get_min_cut(&content.graph, Method::StoerWagner, Some(3)),
+            Some(expected_cut())
+        );
+        assert_eq!(
+            get_min_cut(&content.graph, Method::StoerWagner, Some(2)),
+            None
+        );
+    }
+
+    #[test]
+    fn get_min_cut_karger() {
+        let content = parse_content(EXAMPLE).unwrap();
+        let method = Method::Karger {
+            seed: 25,
+            attempts: 1000,
+        };
+        assert_eq!(
+            get_min_cut(&content.graph, method, Some(3)),
+            Some(expected_cut())
+        );
+        assert_eq!(get_min_cut(&content.graph, method, Some(2)), None);
+    }
+
+    #[test]
+    fn solve_with_karger() {
+        let content = parse_content(EXAMPLE).unwrap();
+        assert_eq!(solve_with(&content, &Method::KARGER), Some(54));
+        assert_eq!(solve_with(&content, &Method::StoerWagner), Some(54));
+    }
+
+    #[test]
+    fn get_min_cut_disconnected() {
+        let content = parse_content("a: b\nc: d").unwrap();
+        let min_cut = get_min_cut(&content.graph, Method::StoerWagner, None).unwrap();
+        assert!(min_cut.edges.is_empty());
+        assert_eq!(min_cut.product(), 4);
+        assert_eq!(
+            get_min_cut(&parse_content("").unwrap().graph, Method::StoerWagner, None),
+            None
+        );
+    }
+}
+
+impl ToDot for Content {
+    fn to_dot(&self) -> Dot {
+        let mut dot = Dot::graph("components");
+        for (name, nodes) in &self.graph {
+            for node in nodes {
+                dot.edge(name, node);
+            }
+        }
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests_to_dot {
+    use super::*;
+
+    #[test]
+    fn to_dot_01() {
+        let content = parse_content("jqt: rhn xhk\nrhn: xhk").unwrap();
+        assert_eq!(
+            content.to_dot().to_string(),
+            r#"graph "components" {
+    "jqt" [shape=ellipse];
+    "rhn" [shape=ellipse];
+    "xhk" [shape=ellipse];
+    "jqt" -- "rhn";
+    "jqt" -- "xhk";
+    "rhn" -- "xhk";
+}
+"#
+        );
+    }
+}
+
+/// The product of the sizes of both groups once the three wires are cut with
+/// `method`, showing those wires and groups at debug level. Lets the runner try
+/// Karger's algorithm instead of Stoer-Wagner's.
+pub fn solve_with(content: &Content, method: &Method) -> Option<usize> {
+    let min_cut = get_min_cut(&content.graph, *method, Some(3))?;
+    debug!(
+        "Cutting {}",
+        min_cut
+            .edges
+            .iter()
+            .map(|(a, b)| format!("{}/{}", a, b))
+            .join(", ")
+    );
+    debug!("First group: {}", min_cut.partitions.0.join(" "));
+    debug!("Second group: {}", min_cut.partitions.1.join(" "));
+    Some(min_cut.product())
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        solve_with(input, &Method::StoerWagner)
+    }
+
+    fn dot(input: &Self::Input) -> Option<Dot> {
+        Some(input.to_dot())
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Content {
+    left: Vec<usize>,
+    right: Vec<usize>,
+}
+
+fn parse_content(lines: &str) -> Result<Content, ParseError> {
+    let mut left: Vec<usize> = Vec::new();
+    let mut right: Vec<usize> = Vec::new();
+    for (index, line) in lines.split('\n').enumerate() {
+        if !line.is_empty() {
+            let mut left_right = line.split_whitespace();
+            let left_value = parse_field(
+                index,
+                line,
+                left_right
+                    .next()
+                    .ok_or_else(|| ParseError::missing(index, line, "left value"))?,
+                "left value",
+            )?;
+            let right_value = parse_field(
+                index,
+                line,
+                left_right
+                    .next()
+                    .ok_or_else(|| ParseError::missing(index, line, "right value"))?,
+                "right value",
+            )?;
+            let left_pos = left.binary_search(&left_value).unwrap_or_else(|e| e);
+            left.insert(left_pos, left_value);
+            let right_pos = right.binary_search(&right_value).unwrap_or_else(|e| e);
+            right.insert(right_pos, right_value);
+        }
+    }
+    Ok(Content { left, right })
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        let content = parse_content(
+            "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+        );
+        assert_eq!(
+            content,
+            Ok(Content {
+                left: Vec::from_iter(vec![1, 2, 3, 3, 3, 4]),
+                right: Vec::from_iter(vec![3, 3, 3, 4, 5, 9]),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_content_02() {
+        assert_eq!(
+            parse_content("3   4\n4   x3\n"),
+            Err(ParseError {
+                line: 2,
+                column: 5,
+                text: String::from("x3"),
+                reason: String::from("invalid right value: invalid digit found in string"),
+            })
+        );
+        assert_eq!(
+            parse_content("3   4\n4"),
+            Err(ParseError {
+                line: 2,
+                column: 2,
+                text: String::new(),
+                reason: String::from("missing right value"),
+            })
+        );
+    }
+}
+
+fn fold_delta(content: &Content) -> usize {
+    content
+        .right
+        .iter()
+        .enumerate()
+        .map(|(index, right_value)| {
+            let left_value = content.left[index];
+            let delta: isize = (*right_value as isize) - (left_value as isize);
+            delta.unsigned_abs()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests_fold_delta {
+    use super::*;
+
+    #[test]
+    fn fold_delta_01() {
+        let content = parse_content(
+            "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+        )
+        .unwrap();
+        assert_eq!(fold_delta(&content), 11);
+    }
+}
+
+fn fold_similarity(content: &Content) -> usize {
+    let mut right_counts: HashMap<usize, usize> = HashMap::new();
+    content
+        .right
+        .iter()
+        .for_each(|right_value| *right_counts.entry(*right_value).or_insert(0) += 1);
+
+    content
+        .left
+        .iter()
+        .map(|left_value| left_value * right_counts.get(left_value).unwrap_or(&0))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests_fold_similarity {
+    use super::*;
+
+    #[test]
+    fn fold_similarity_01() {
+        let content = parse_content(
+            "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+        )
+        .unwrap();
+        assert_eq!(fold_similarity(&content), 31);
+    }
+}
+
+#[allow(clippy::items_after_test_module)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(fold_delta(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(fold_similarity(input))
+    }
+}
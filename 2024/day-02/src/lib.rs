@@ -1,27 +1,27 @@
-use std::fs;
+use std::fmt::Display;
 
-fn get_file_content(file_path: &str) -> String {
-    println!("Loading input file: {}", file_path);
-    fs::read_to_string(file_path).expect("Cannot load file")
-}
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+};
 
 #[derive(Debug, PartialEq, Clone)]
-struct Content {
+pub struct Content {
     list: Vec<Vec<usize>>,
 }
 
-fn parse_content(lines: &str) -> Content {
-    Content {
-        list: lines
-            .split('\n')
-            .map(|line| {
-                line.split_whitespace()
-                    .map(|e| e.parse().unwrap())
-                    .collect::<Vec<usize>>()
-            })
-            .filter(|l| !l.is_empty())
-            .collect::<Vec<Vec<usize>>>(),
+fn parse_content(lines: &str) -> Result<Content, ParseError> {
+    let mut list: Vec<Vec<usize>> = Vec::new();
+    for (index, line) in lines.split('\n').enumerate() {
+        let report = line
+            .split_whitespace()
+            .map(|level| parse_field(index, line, level, "level"))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+        if !report.is_empty() {
+            list.push(report);
+        }
     }
+    Ok(Content { list })
 }
 
 #[cfg(test)]
@@ -42,7 +42,7 @@ mod tests_parse_content {
         );
         assert_eq!(
             content,
-            Content {
+            Ok(Content {
                 list: vec![
                     vec![7, 6, 4, 2, 1],
                     vec![1, 2, 7, 8, 9],
@@ -51,7 +51,20 @@ mod tests_parse_content {
                     vec![8, 6, 4, 4, 1],
                     vec![1, 3, 6, 7, 9],
                 ]
-            }
+            })
+        );
+    }
+
+    #[test]
+    fn parse_content_02() {
+        assert_eq!(
+            parse_content("7 6 4 2 1\n1 2 -7 8 9\n"),
+            Err(ParseError {
+                line: 2,
+                column: 5,
+                text: String::from("-7"),
+                reason: String::from("invalid level: invalid digit found in string"),
+            })
         );
     }
 }
@@ -122,10 +135,6 @@ fn is_monotonic_and_bounded(list: &[usize], can_try_again: bool) -> bool {
                 }
             }
 
-            if result_without_suspect_3 {
-                dbg!(list);
-            }
-
             result_without_suspect_1 || result_without_suspect_2 || result_without_suspect_3
         } else {
             false
@@ -141,6 +150,10 @@ mod tests_is_monotonic_and_bounded {
 
     #[test]
     fn is_monotonic_and_bounded_01() {
+        assert!(is_monotonic_and_bounded(&[1, 2, 3], false));
+        assert!(!is_monotonic_and_bounded(&[1, 2, 7], false));
+        assert!(is_monotonic_and_bounded(&[3, 2, 1], false));
+        assert!(!is_monotonic_and_bounded(&[7, 2, 1], false));
         assert!(is_monotonic_and_bounded(&[7, 6, 4, 2, 1], false));
         assert!(!is_monotonic_and_bounded(&[1, 3, 2, 4, 5], false));
         assert!(is_monotonic_and_bounded(&[1, 3, 2, 4, 5], true));
@@ -160,22 +173,11 @@ mod tests_is_monotonic_and_bounded {
     }
 }
 
-fn fold_delta(content: &Content) -> usize {
+fn fold_delta(content: &Content, can_try_again: bool) -> usize {
     content
         .list
         .iter()
-        .map(|levels| {
-            let result = is_monotonic_and_bounded(levels, true);
-            println!("{:?} is {}", levels, {
-                if result {
-                    "OK"
-                } else {
-                    "KO"
-                }
-            });
-            result
-        })
-        .filter(|b| *b)
+        .filter(|levels| is_monotonic_and_bounded(levels, can_try_again))
         .count()
 }
 
@@ -194,14 +196,28 @@ mod tests_fold_delta {
 8 6 4 4 1
 1 3 6 7 9
 ",
-        );
-        assert_eq!(fold_delta(&content), 4);
+        )
+        .unwrap();
+        assert_eq!(fold_delta(&content, false), 2);
+        assert_eq!(fold_delta(&content, true), 4);
     }
 }
 
 #[allow(clippy::items_after_test_module)]
-fn main() {
-    let content = &parse_content(&get_file_content("assets/input"));
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Content;
 
-    println!("Result: {}", fold_delta(content));
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(fold_delta(input, false))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(fold_delta(input, true))
+    }
 }
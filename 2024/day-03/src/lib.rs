@@ -0,0 +1,160 @@
+use std::fmt::Display;
+
+use aoc_core::{parse::ParseError, solution::Solution};
+use regex::Regex;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Content {
+    list: String,
+}
+
+fn parse_content(lines: &str) -> Content {
+    Content {
+        list: lines.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        let content = parse_content("toto");
+        assert_eq!(
+            content,
+            Content {
+                list: "toto".to_owned()
+            }
+        );
+    }
+}
+
+/// Keep the `mul` instructions, honouring `do()` and `don't()` only when
+/// `with_conditionals` is set. Their operands have at most 3 digits: anything
+/// longer is just more corruption.
+fn clean_up(list: &str, with_conditionals: bool) -> Vec<&str> {
+    let re = Regex::new(r"mul\(\d{1,3},\d{1,3}\)|do\(\)|don't\(\)").unwrap();
+    let matches: Vec<_> = re.find_iter(list).map(|m| m.as_str()).collect();
+
+    let mut results: Vec<&str> = Vec::new();
+    let mut record = true;
+    for m in matches {
+        if m == "don't()" {
+            record = !with_conditionals;
+            continue;
+        }
+        if m == "do()" {
+            record = true;
+            continue;
+        }
+        if record {
+            results.push(m);
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests_clean_up {
+    use super::*;
+
+    #[test]
+    fn clean_up_01() {
+        assert_eq!(
+            clean_up(
+                "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))",
+                false
+            ),
+            vec!["mul(2,4)", "mul(5,5)", "mul(11,8)", "mul(8,5)",]
+        )
+    }
+
+    #[test]
+    fn clean_up_02() {
+        assert_eq!(
+            clean_up(
+                "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+                true
+            ),
+            vec!["mul(2,4)", "mul(8,5)",]
+        )
+    }
+
+    #[test]
+    fn clean_up_long_operands() {
+        assert_eq!(
+            clean_up("mul(1234,5)mul(99999999999999999999,2)mul(123,45)", false),
+            vec!["mul(123,45)"]
+        )
+    }
+}
+
+fn execute_operation(operation: &str) -> usize {
+    let re = Regex::new(r"(\d+)").unwrap();
+    let results: Vec<_> = re.find_iter(operation).map(|m| m.as_str()).collect();
+    results
+        .iter()
+        .map(|c| c.parse().unwrap())
+        .reduce(|acc, e| acc * e)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests_execute_operation {
+    use super::*;
+
+    #[test]
+    fn execute_operation_01() {
+        assert_eq!(execute_operation("mul(2,4)"), 8);
+        assert_eq!(execute_operation("mul(5,5)"), 25);
+    }
+}
+
+fn fold_executions(content: &Content, with_conditionals: bool) -> usize {
+    clean_up(&content.list, with_conditionals)
+        .iter()
+        .map(|operation| execute_operation(operation))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests_fold_executions {
+    use super::*;
+
+    #[test]
+    fn fold_executions_01() {
+        let content = parse_content(
+            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))",
+        );
+        assert_eq!(fold_executions(&content, false), 161);
+    }
+
+    #[test]
+    fn fold_executions_02() {
+        let content = parse_content(
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+        );
+        assert_eq!(fold_executions(&content, true), 48);
+    }
+}
+
+#[allow(clippy::items_after_test_module)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_content(input))
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(fold_executions(input, false))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(fold_executions(input, true))
+    }
+}
@@ -0,0 +1,305 @@
+use std::fmt::Display;
+
+use aoc_core::{
+    coords::Coords,
+    direction::{Compass, Direction8},
+    grid::Grid,
+};
+use aoc_core::{parse::ParseError, solution::Solution};
+
+type Input = Grid<char>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Content {
+    input: Input,
+}
+
+fn parse_content(lines: &str) -> Result<Content, ParseError> {
+    Ok(Content {
+        input: lines.parse()?,
+    })
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        let content = parse_content(
+            "\
+1234
+5678
+9ABC
+",
+        )
+        .unwrap();
+        assert_eq!(
+            content,
+            Content {
+                input: Grid::new(
+                    4,
+                    3,
+                    vec!['1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C']
+                )
+            }
+        );
+    }
+}
+
+/// Is "MAS" written from `position` (excluded) in the given direction ?
+fn find_xmas(input: &Input, position: Coords<usize>, direction: Direction8) -> usize {
+    let (dx, dy) = direction.delta();
+    let found = "MAS".chars().zip(1..).all(|(letter, step)| {
+        input.get(Coords {
+            x: position.x as isize + dx * step,
+            y: position.y as isize + dy * step,
+        }) == Some(&letter)
+    });
+    if found {
+        1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests_find_xmas {
+    use super::*;
+
+    #[test]
+    fn find_xmas_01() {
+        let content = parse_content(
+            "\
+S00S00S
+0A0A0A0
+00MMM00
+SAMXMAS
+00MMM00
+0A0A0A0
+S00S00S
+",
+        )
+        .unwrap();
+        assert_eq!(
+            find_xmas(&content.input, Coords { x: 3, y: 3 }, Direction8::NorthWest),
+            1
+        );
+        assert_eq!(
+            find_xmas(&content.input, Coords { x: 3, y: 3 }, Direction8::North),
+            1
+        );
+        assert_eq!(
+            find_xmas(&content.input, Coords { x: 3, y: 3 }, Direction8::NorthEast),
+            1
+        );
+        assert_eq!(
+            find_xmas(&content.input, Coords { x: 3, y: 3 }, Direction8::West),
+            1
+        );
+        assert_eq!(
+            find_xmas(&content.input, Coords { x: 3, y: 3 }, Direction8::East),
+            1
+        );
+        assert_eq!(
+            find_xmas(&content.input, Coords { x: 3, y: 3 }, Direction8::SouthWest),
+            1
+        );
+        assert_eq!(
+            find_xmas(&content.input, Coords { x: 3, y: 3 }, Direction8::South),
+            1
+        );
+        assert_eq!(
+            find_xmas(&content.input, Coords { x: 3, y: 3 }, Direction8::SouthEast),
+            1
+        );
+        // Out of bounds test
+        assert_eq!(
+            find_xmas(&content.input, Coords { x: 0, y: 0 }, Direction8::NorthWest),
+            0
+        );
+    }
+}
+
+fn fold_xmas(content: &Content) -> usize {
+    content
+        .input
+        .iter()
+        .filter(|(_, &c)| c == 'X')
+        .map(|(position, _)| {
+            Direction8::ALL
+                .iter()
+                .map(|&direction| find_xmas(&content.input, position, direction))
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests_fold_xmas {
+    use super::*;
+
+    #[test]
+    fn fold_xmas_01() {
+        let content = parse_content(
+            "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+",
+        )
+        .unwrap();
+        assert_eq!(fold_xmas(&content), 18);
+    }
+}
+
+/// X-MAS orientation.
+///
+/// 4 possible values (A,B,C,D):
+///      M.S      S.S      S.M      M.M
+///   A: .A.   B: .A.   C: .A.   D: .A.
+///      M.S      M.M      S.M      S.S
+enum Orientation {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Orientation {
+    /// Expected letters at the north-west, north-east, south-west and
+    /// south-east corners
+    fn corners(&self) -> [char; 4] {
+        match self {
+            Orientation::A => ['M', 'S', 'M', 'S'],
+            Orientation::B => ['S', 'S', 'M', 'M'],
+            Orientation::C => ['S', 'M', 'S', 'M'],
+            Orientation::D => ['M', 'M', 'S', 'S'],
+        }
+    }
+}
+
+fn find_x_mas(input: &Input, position: Coords<usize>, orientation: &Orientation) -> usize {
+    let found = [
+        Direction8::NorthWest,
+        Direction8::NorthEast,
+        Direction8::SouthWest,
+        Direction8::SouthEast,
+    ]
+    .into_iter()
+    .zip(orientation.corners())
+    .all(|(direction, letter)| {
+        input
+            .offset(position, direction.delta())
+            .and_then(|corner| input.get(corner))
+            == Some(&letter)
+    });
+    if found {
+        1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests_find_x_mas {
+    use super::*;
+
+    #[test]
+    fn find_x_mas_01() {
+        let content = parse_content(
+            "\
+.M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........
+",
+        )
+        .unwrap();
+        assert_eq!(
+            find_x_mas(&content.input, Coords { x: 2, y: 1 }, &Orientation::A),
+            1
+        );
+        // Out of bounds test
+        assert_eq!(
+            find_x_mas(&content.input, Coords { x: 0, y: 0 }, &Orientation::A),
+            0
+        );
+    }
+}
+
+fn fold_x_mas(content: &Content) -> usize {
+    content
+        .input
+        .iter()
+        .filter(|(_, &c)| c == 'A')
+        .map(|(position, _)| {
+            [
+                Orientation::A,
+                Orientation::B,
+                Orientation::C,
+                Orientation::D,
+            ]
+            .iter()
+            .map(|orientation| find_x_mas(&content.input, position, orientation))
+            .sum::<usize>()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests_fold_x_mas {
+    use super::*;
+
+    #[test]
+    fn fold_x_mas_01() {
+        let content = parse_content(
+            "\
+.M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........
+",
+        )
+        .unwrap();
+        assert_eq!(fold_x_mas(&content), 9);
+    }
+}
+
+#[allow(clippy::items_after_test_module)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(fold_xmas(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(fold_x_mas(input))
+    }
+}
@@ -0,0 +1,361 @@
+use std::fmt::Display;
+
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+};
+
+type OrderingRule = (usize, usize);
+type OrderingRuleList = Vec<OrderingRule>;
+type Update = Vec<usize>;
+type UpdateList = Vec<Update>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Content {
+    ordering_rule_list: OrderingRuleList,
+    update_list: UpdateList,
+}
+
+fn parse_content(input: &str) -> Result<Content, ParseError> {
+    // Input is comprised of two big blocks, split by a blank line:
+    // - The list of ordering rules
+    // - The list of pages to produce
+    let mut ordering_rule_list: OrderingRuleList = Vec::new();
+    let mut update_list: UpdateList = Vec::new();
+    let mut in_updates = false;
+
+    for (index, line) in input.split('\n').enumerate() {
+        if line.is_empty() {
+            // Only the first blank line separates both blocks, the following
+            // ones are trailing
+            in_updates |= !ordering_rule_list.is_empty();
+            continue;
+        }
+        if in_updates {
+            update_list.push(
+                line.split(',')
+                    .map(|page| parse_field(index, line, page, "page to produce"))
+                    .collect::<Result<Update, ParseError>>()?,
+            );
+        } else {
+            let Some((first, second)) = line.split_once('|') else {
+                return Err(ParseError::missing(
+                    index,
+                    line,
+                    "`|` between the pages of an ordering rule",
+                ));
+            };
+            ordering_rule_list.push((
+                parse_field(index, line, first, "ordering rule page")?,
+                parse_field(index, line, second, "ordering rule page")?,
+            ));
+        }
+    }
+
+    Ok(Content {
+        ordering_rule_list,
+        update_list,
+    })
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        let content = parse_content(
+            "\
+47|53
+97|13
+97|61
+
+75,47,61
+97,61
+",
+        );
+        assert_eq!(
+            content,
+            Ok(Content {
+                ordering_rule_list: vec![(47, 53), (97, 13), (97, 61)],
+                update_list: vec![vec![75, 47, 61], vec![97, 61]]
+            })
+        );
+    }
+
+    #[test]
+    fn parse_content_02() {
+        assert_eq!(
+            parse_content("47|53\n97,13\n\n75,47,61\n"),
+            Err(ParseError {
+                line: 2,
+                column: 6,
+                text: String::new(),
+                reason: String::from("missing `|` between the pages of an ordering rule"),
+            })
+        );
+        assert_eq!(
+            parse_content("47|53\n\n75,,61\n"),
+            Err(ParseError {
+                line: 3,
+                column: 4,
+                text: String::new(),
+                reason: String::from(
+                    "invalid page to produce: cannot parse integer from empty string"
+                ),
+            })
+        );
+    }
+}
+
+fn is_correctly_ordered(ordering_rule_list: &OrderingRuleList, update: &Update) -> Option<Update> {
+    if ordering_rule_list.iter().all(|rule| {
+        // Does the update violates the current rule ?
+        // Let's find out !
+        let first = update.iter().position(|&e| e == rule.0);
+        let second = update.iter().position(|&e| e == rule.1);
+        match (first, second) {
+            (Some(f), Some(s)) => f < s,
+            (_, _) => true,
+        }
+    }) {
+        Some(update.to_owned())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests_is_correctly_ordered {
+    use super::*;
+
+    #[test]
+    fn is_correctly_ordered_01() {
+        let content = parse_content(
+            "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+",
+        )
+        .unwrap();
+        assert_eq!(
+            is_correctly_ordered(&content.ordering_rule_list, &vec![75, 47, 61, 53, 29]),
+            Some(vec![75, 47, 61, 53, 29])
+        );
+        assert_eq!(
+            is_correctly_ordered(&content.ordering_rule_list, &vec![97, 61, 53, 29, 13]),
+            Some(vec![97, 61, 53, 29, 13])
+        );
+        assert_eq!(
+            is_correctly_ordered(&content.ordering_rule_list, &vec![75, 29, 13]),
+            Some(vec![75, 29, 13])
+        );
+        assert_eq!(
+            is_correctly_ordered(&content.ordering_rule_list, &vec![75, 97, 47, 61, 53]),
+            None
+        );
+        assert_eq!(
+            is_correctly_ordered(&content.ordering_rule_list, &vec![61, 13, 29]),
+            None
+        );
+        assert_eq!(
+            is_correctly_ordered(&content.ordering_rule_list, &vec![97, 13, 75, 29, 47]),
+            None
+        );
+    }
+}
+
+/// If (and only IF) an update is incorrect, return an ordered version
+fn correctly_order_update(
+    ordering_rule_list: &OrderingRuleList,
+    update: &Update,
+) -> Option<Update> {
+    let mut mutable_update = update.clone();
+    let mut mutated = false;
+    ordering_rule_list.iter().for_each(|(first, second)| {
+        // If the update violates the current rules, then we swap both problematic pages
+        let first_pos = mutable_update.iter().position(|e| e == first);
+        let second_pos = mutable_update.iter().position(|e| e == second);
+        if let (Some(f), Some(s)) = (first_pos, second_pos) {
+            if f > s {
+                mutable_update.swap(f, s);
+                mutated = true;
+                if let Some(another_update) =
+                    correctly_order_update(ordering_rule_list, &mutable_update)
+                {
+                    mutable_update = another_update;
+                }
+            }
+        }
+    });
+    if mutated {
+        Some(mutable_update)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests_correctly_order_update {
+    use super::*;
+
+    #[test]
+    fn correctly_order_update_01() {
+        let content = parse_content(
+            "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+",
+        )
+        .unwrap();
+        assert_eq!(
+            correctly_order_update(&content.ordering_rule_list, &vec![75, 47, 61, 53, 29]),
+            None
+        );
+        assert_eq!(
+            correctly_order_update(&content.ordering_rule_list, &vec![97, 61, 53, 29, 13]),
+            None
+        );
+        assert_eq!(
+            correctly_order_update(&content.ordering_rule_list, &vec![75, 29, 13]),
+            None
+        );
+        assert_eq!(
+            correctly_order_update(&content.ordering_rule_list, &vec![75, 97, 47, 61, 53]),
+            Some(vec![97, 75, 47, 61, 53])
+        );
+        assert_eq!(
+            correctly_order_update(&content.ordering_rule_list, &vec![61, 13, 29]),
+            Some(vec![61, 29, 13])
+        );
+        assert_eq!(
+            correctly_order_update(&content.ordering_rule_list, &vec![97, 13, 75, 29, 47]),
+            Some(vec![97, 75, 47, 29, 13])
+        );
+    }
+}
+
+/// Sum the middle page of every update that `select` keeps
+fn fold(content: &Content, select: fn(&OrderingRuleList, &Update) -> Option<Update>) -> usize {
+    content
+        .update_list
+        .iter()
+        .filter_map(|update| select(&content.ordering_rule_list, update))
+        .map(|update| update[update.len() / 2])
+        .sum()
+}
+
+#[cfg(test)]
+mod tests_fold {
+    use super::*;
+
+    #[test]
+    fn fold_01() {
+        let content = parse_content(
+            "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+",
+        )
+        .unwrap();
+        assert_eq!(fold(&content, is_correctly_ordered), 143);
+        assert_eq!(fold(&content, correctly_order_update), 123);
+    }
+}
+
+#[allow(clippy::items_after_test_module)]
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(fold(input, is_correctly_ordered))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(fold(input, correctly_order_update))
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_core::{
+    coords::Coords,
+    direction::{Compass, Direction},
+    grid::Grid,
+    trace,
+};
+use aoc_core::{parse::ParseError, solution::Solution};
+
+type Map = Grid<char>;
+#[derive(Debug, PartialEq, Clone)]
+pub struct Content {
+    map: Map,
+}
+
+#[allow(dead_code)]
+fn display_map(map: &Map) {
+    trace!("{}", map);
+}
+
+fn parse_content(input: &str) -> Result<Content, ParseError> {
+    Ok(Content {
+        map: input.parse()?,
+    })
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        let content = parse_content(
+            "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+",
+        )
+        .unwrap();
+        assert_eq!(content.map.width(), 10);
+        assert_eq!(content.map.height(), 10);
+        assert_eq!(content.map[(4, 0)], '#');
+        assert_eq!(content.map[(4, 6)], '^');
+    }
+}
+
+/// Where is the guard (facing up) standing ?
+fn find_guard(map: &Map) -> Option<(usize, usize)> {
+    map.position(|&c| c == '^')
+        .map(|coords| (coords.x, coords.y))
+}
+
+#[cfg(test)]
+mod tests_find_guard {
+    use super::*;
+
+    #[test]
+    fn find_guard_01() {
+        let content = parse_content(
+            "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+",
+        )
+        .unwrap();
+        assert_eq!(find_guard(&content.map), Some((4, 6)));
+    }
+}
+
+/// Walk the gard through the Map
+fn walk_the_guard(map: &Map, (initial_x, initial_y): (usize, usize)) -> Map {
+    let rect = map.bounding_rect();
+    let mut updated_map = map.clone();
+    let mut orientation = Direction::North;
+    let mut position = Some(Coords {
+        x: initial_x,
+        y: initial_y,
+    });
+
+    while let Some(coords) = position {
+        match updated_map.get(coords) {
+            Some('#') => {
+                position = orientation.reverse().step(coords, &rect);
+                orientation = orientation.turn_right();
+            }
+            _ => {
+                updated_map[coords] = 'X';
+            }
+        }
+        if let Some(coords) = position {
+            position = orientation.step(coords, &rect);
+        }
+    }
+
+    updated_map
+}
+
+#[cfg(test)]
+mod tests_walk_the_guard {
+    use super::*;
+
+    #[test]
+    fn walk_the_guard_01() {
+        let content = parse_content(
+            "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+",
+        )
+        .unwrap();
+        let expected = parse_content(
+            "\
+....#.....
+....XXXXX#
+....X...X.
+..#.X...X.
+..XXXXX#X.
+..X.X.X.X.
+.#XXXXXXX.
+.XXXXXXX#.
+#XXXXXXX..
+......#X..
+        ",
+        )
+        .unwrap();
+        let updated = walk_the_guard(&content.map, (4, 6));
+        display_map(&updated);
+        assert_eq!(updated, expected.map);
+    }
+}
+
+fn ends_up_in_a_loop(map: &Map, (initial_x, initial_y): (usize, usize)) -> bool {
+    let rect = map.bounding_rect();
+    let mut updated_map = map.clone();
+    let mut orientation = Direction::North;
+    let mut position = Some(Coords {
+        x: initial_x,
+        y: initial_y,
+    });
+    let mut trace: HashSet<(Coords<usize>, Direction)> = HashSet::new();
+
+    while let Some(coords) = position {
+        if !trace.insert((coords, orientation)) {
+            return true;
+        }
+        match updated_map.get(coords) {
+            Some('#') | Some('O') => {
+                position = orientation.reverse().step(coords, &rect);
+                orientation = orientation.turn_right();
+            }
+            _ => {
+                updated_map[coords] = 'X';
+            }
+        }
+        if let Some(coords) = position {
+            position = orientation.step(coords, &rect);
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests_ends_up_in_a_loop {
+    use super::*;
+
+    #[test]
+    fn ends_up_in_a_loop_01() {
+        let content = parse_content(
+            "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#.O^.....
+........#.
+#.........
+......#...
+",
+        )
+        .unwrap();
+        assert!(ends_up_in_a_loop(&content.map, (4, 6)));
+    }
+}
+
+/// How many X are there in the given Map ?
+fn fold(map: &Map) -> usize {
+    map.iter().filter(|(_, &c)| c == 'X').count()
+}
+
+#[cfg(test)]
+mod tests_fold {
+    use super::*;
+
+    #[test]
+    fn fold_01() {
+        let content = parse_content(
+            "\
+....#.....
+....XXXXX#
+....X...X.
+..#.X...X.
+..XXXXX#X.
+..X.X.X.X.
+.#XXXXXXX.
+.XXXXXXX#.
+#XXXXXXX..
+......#X..
+",
+        )
+        .unwrap();
+        assert_eq!(fold(&content.map), 41);
+    }
+}
+
+/// How many positions can be used to stuck the guard in a loop ?
+fn fold_obstructions(map: &Map, starting_position: (usize, usize)) -> usize {
+    map.coords()
+        .filter(|&coords| {
+            let mut map_with_obstacle = map.clone();
+            map_with_obstacle[coords] = 'O';
+            ends_up_in_a_loop(&map_with_obstacle, starting_position)
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests_fold_obstructions {
+    use super::*;
+
+    #[test]
+    fn fold_obstructions_01() {
+        let content = parse_content(
+            "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+",
+        )
+        .unwrap();
+        assert_eq!(fold_obstructions(&content.map, (4, 6)), 6);
+    }
+}
+
+#[allow(clippy::items_after_test_module)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        let starting_position = find_guard(&input.map)?;
+
+        Some(fold(&walk_the_guard(&input.map, starting_position)))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let starting_position = find_guard(&input.map)?;
+
+        Some(fold_obstructions(&input.map, starting_position))
+    }
+}
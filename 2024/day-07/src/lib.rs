@@ -0,0 +1,259 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+};
+
+type Test = (usize, VecDeque<usize>);
+type Tests = Vec<Test>;
+#[derive(Debug, PartialEq, Clone)]
+pub struct Content {
+    tests: Tests,
+}
+
+fn parse_test(index: usize, line: &str) -> Result<Test, ParseError> {
+    let Some((result, operands)) = line.split_once(':') else {
+        return Err(ParseError::missing(
+            index,
+            line,
+            "`:` after the test result",
+        ));
+    };
+    let operands = operands
+        .split_whitespace()
+        .map(|operand| parse_field(index, line, operand, "operand"))
+        .collect::<Result<VecDeque<usize>, ParseError>>()?;
+    if operands.is_empty() {
+        return Err(ParseError::missing(index, line, "operands"));
+    }
+    Ok((parse_field(index, line, result, "test result")?, operands))
+}
+
+fn parse_content(input: &str) -> Result<Content, ParseError> {
+    Ok(Content {
+        tests: input
+            .split("\n")
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(index, line)| parse_test(index, line))
+            .collect::<Result<Tests, ParseError>>()?,
+    })
+}
+
+#[cfg(test)]
+mod tests_parse_content {
+    use super::*;
+
+    #[test]
+    fn parse_content_01() {
+        let content = parse_content(
+            "\
+190:10 19
+3267:81 40 27
+83:17 5
+156:15 6
+7290:6 8 6 15
+161011:16 10 13
+192:17 8 14
+21037:9 7 18 13
+292:11 6 16 20
+",
+        );
+        assert_eq!(
+            content,
+            Ok(Content {
+                tests: vec![
+                    (190, VecDeque::from([10, 19])),
+                    (3267, VecDeque::from([81, 40, 27])),
+                    (83, VecDeque::from([17, 5])),
+                    (156, VecDeque::from([15, 6])),
+                    (7290, VecDeque::from([6, 8, 6, 15])),
+                    (161011, VecDeque::from([16, 10, 13])),
+                    (192, VecDeque::from([17, 8, 14])),
+                    (21037, VecDeque::from([9, 7, 18, 13])),
+                    (292, VecDeque::from([11, 6, 16, 20]))
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn parse_content_02() {
+        assert_eq!(
+            parse_content("190: 10 19\n3267 81 40 27\n"),
+            Err(ParseError {
+                line: 2,
+                column: 14,
+                text: String::new(),
+                reason: String::from("missing `:` after the test result"),
+            })
+        );
+        assert_eq!(
+            parse_content("190: 10 x19\n"),
+            Err(ParseError {
+                line: 1,
+                column: 9,
+                text: String::from("x19"),
+                reason: String::from("invalid operand: invalid digit found in string"),
+            })
+        );
+        assert_eq!(
+            parse_content("190:\n").map(|_| ()).unwrap_err().reason,
+            "missing operands"
+        );
+    }
+}
+
+/// [a,b] => a * b OR a + b
+/// [a,b,c] => a * b + c OR a + b * c OR a + b + c OR a * b * c
+///
+/// With `with_concatenation`, a || b (digits of a followed by digits of b)
+/// is also tried. Results too large for a `usize` are dead ends.
+fn is_computable((expected, operands): Test, acc: usize, with_concatenation: bool) -> bool {
+    // dbg!(&expected, &operands);
+    let mut remains = operands.clone();
+    let Some(operand) = remains.pop_front() else {
+        return acc == expected;
+    };
+    let try_with = |acc: Option<usize>| {
+        acc.is_some_and(|acc| is_computable((expected, remains.clone()), acc, with_concatenation))
+    };
+    try_with(acc.checked_mul(operand))
+        || try_with(acc.checked_add(operand))
+        || with_concatenation && try_with(format!("{acc}{operand}").parse().ok())
+}
+
+#[cfg(test)]
+mod tests_is_computable {
+    use super::*;
+
+    #[test]
+    fn is_computable_01() {
+        assert!(is_computable((190, VecDeque::from([10, 19])), 0, false));
+        assert!(is_computable(
+            (3267, VecDeque::from([81, 40, 27])),
+            0,
+            false
+        ));
+        assert!(!is_computable((83, VecDeque::from([17, 5])), 0, false));
+        assert!(!is_computable((156, VecDeque::from([15, 6])), 0, false));
+        assert!(!is_computable(
+            (7290, VecDeque::from([6, 8, 6, 15])),
+            0,
+            false
+        ));
+        assert!(!is_computable(
+            (161011, VecDeque::from([16, 10, 13])),
+            0,
+            false
+        ));
+        assert!(!is_computable((192, VecDeque::from([17, 8, 14])), 0, false));
+        assert!(!is_computable(
+            (21037, VecDeque::from([9, 7, 18, 13])),
+            0,
+            false
+        ));
+        assert!(is_computable(
+            (292, VecDeque::from([11, 6, 16, 20])),
+            0,
+            false
+        ));
+    }
+
+    #[test]
+    fn is_computable_02() {
+        assert!(is_computable((190, VecDeque::from([10, 19])), 0, true));
+        assert!(is_computable((3267, VecDeque::from([81, 40, 27])), 0, true));
+        assert!(!is_computable((83, VecDeque::from([17, 5])), 0, true));
+        assert!(is_computable((156, VecDeque::from([15, 6])), 0, true));
+        assert!(is_computable(
+            (7290, VecDeque::from([6, 8, 6, 15])),
+            0,
+            true
+        ));
+        assert!(!is_computable(
+            (161011, VecDeque::from([16, 10, 13])),
+            0,
+            true
+        ));
+        assert!(is_computable((192, VecDeque::from([17, 8, 14])), 0, true));
+        assert!(!is_computable(
+            (21037, VecDeque::from([9, 7, 18, 13])),
+            0,
+            true
+        ));
+        assert!(is_computable(
+            (292, VecDeque::from([11, 6, 16, 20])),
+            0,
+            true
+        ));
+    }
+
+    #[test]
+    fn is_computable_overflow() {
+        assert!(!is_computable(
+            (1, VecDeque::from([usize::MAX, usize::MAX])),
+            0,
+            true
+        ));
+    }
+}
+
+fn fold(tests: &Tests, with_concatenation: bool) -> usize {
+    tests
+        .iter()
+        .map(|test| {
+            if is_computable(test.clone(), 0, with_concatenation) {
+                test.0
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests_fold {
+    use super::*;
+
+    #[test]
+    fn fold_01() {
+        let content = parse_content(
+            "\
+190:10 19
+3267:81 40 27
+83:17 5
+156:15 6
+7290:6 8 6 15
+161011:16 10 13
+192:17 8 14
+21037:9 7 18 13
+292:11 6 16 20
+",
+        )
+        .unwrap();
+        assert_eq!(fold(&content.tests, false), 3749);
+        assert_eq!(fold(&content.tests, true), 11387);
+    }
+}
+
+#[allow(clippy::items_after_test_module)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_content(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(fold(&input.tests, false))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(fold(&input.tests, true))
+    }
+}
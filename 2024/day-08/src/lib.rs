@@ -0,0 +1,402 @@
+use std::fmt::Display;
+use std::{cmp::Ordering, collections::HashMap, str::FromStr};
+
+use aoc_core::grid::Grid;
+use aoc_core::{parse::ParseError, solution::Solution, trace};
+use itertools::Itertools;
+
+type Coords = (usize, usize);
+type Map = Grid<char>;
+#[derive(Debug, PartialEq, Clone)]
+pub struct Content {
+    map: Map,
+}
+
+impl Content {
+    #[allow(dead_code)]
+    fn display_map(&self) {
+        trace!("{}", self.map);
+    }
+
+    fn get_bounding_rect(&self) -> (usize, usize) {
+        (self.map.width(), self.map.height())
+    }
+}
+
+impl FromStr for Content {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { map: s.parse()? })
+    }
+}
+
+#[cfg(test)]
+mod tests_content {
+    use super::*;
+
+    #[test]
+    fn content_from() {
+        let content = Content::from_str(
+            "\
+..........
+...#......
+..........
+....a.....
+..........
+.....a....
+..........
+......#...
+..........
+..........
+",
+        )
+        .unwrap();
+        assert_eq!(content.get_bounding_rect(), (10, 10));
+        assert_eq!(content.map[(3, 1)], '#');
+        assert_eq!(content.map[(4, 3)], 'a');
+        assert_eq!(content.map[(5, 5)], 'a');
+    }
+}
+
+#[derive(Debug)]
+enum Antinodes {
+    None,
+    One(Coords),
+    Two(Coords, Coords),
+}
+
+impl PartialEq for Antinodes {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Antinodes::None, Antinodes::None) => true,
+            (Antinodes::One(s), Antinodes::One(o)) => s == o,
+            (Antinodes::Two(sa, sb), Antinodes::Two(oa, ob)) => {
+                (sa == oa && sb == ob) || (sa == ob && sb == oa)
+            }
+            (_, _) => true,
+        }
+    }
+}
+
+impl From<Antinodes> for Vec<Coords> {
+    fn from(value: Antinodes) -> Self {
+        match value {
+            Antinodes::None => vec![],
+            Antinodes::One(a) => vec![a],
+            Antinodes::Two(a, b) => vec![a, b],
+        }
+    }
+}
+
+/// There are only two antinodes per antenna pair.
+///
+/// ("a" are antennas, "#" are their antinodes):
+/// # . . .
+/// . . . .
+/// . a . .
+/// . . . .
+/// . . a .
+/// . . . .
+/// . . . #
+///
+/// bounding_rect is assumed to start at (0,0) and to end at (max_x, max_y)
+fn compute_antinode((xa, ya): &Coords, (xb, yb): &Coords, (max_x, max_y): Coords) -> Antinodes {
+    let delta_x: isize = *xb as isize - *xa as isize;
+    let delta_y: isize = *yb as isize - *ya as isize;
+
+    let first_x: isize = *xa as isize - delta_x;
+    let first_y: isize = *ya as isize - delta_y;
+
+    let second_x: isize = *xb as isize + delta_x;
+    let second_y: isize = *yb as isize + delta_y;
+
+    let first = {
+        if first_x >= 0 && first_x < max_x as isize && first_y >= 0 && first_y < max_y as isize {
+            Some((first_x as usize, first_y as usize))
+        } else {
+            None
+        }
+    };
+
+    let second = {
+        if second_x >= 0 && second_x < max_x as isize && second_y >= 0 && second_y < max_y as isize
+        {
+            Some((second_x as usize, second_y as usize))
+        } else {
+            None
+        }
+    };
+
+    match (first, second) {
+        (None, None) => Antinodes::None,
+        (None, Some(s)) => Antinodes::One(s),
+        (Some(f), None) => Antinodes::One(f),
+        (Some(f), Some(s)) => Antinodes::Two(f, s),
+    }
+}
+
+#[cfg(test)]
+mod tests_compute_antinode {
+    use super::*;
+
+    #[test]
+    fn compute_antinode_01() {
+        assert_eq!(
+            compute_antinode(&(10, 10), &(11, 11), (100, 100)),
+            Antinodes::Two((9, 9), (12, 12))
+        );
+    }
+}
+
+/// There are now multiple antinodes per antenna pair
+fn compute_antinodes((xa, ya): &Coords, (xb, yb): &Coords, (max_x, max_y): Coords) -> Vec<Coords> {
+    let delta_x: isize = *xb as isize - *xa as isize;
+    let delta_y: isize = *yb as isize - *ya as isize;
+
+    let mut antinodes: Vec<Coords> = vec![];
+
+    // First side (arbitrarily chosen)
+    let mut x: isize = *xb as isize - delta_x;
+    let mut y: isize = *yb as isize - delta_y;
+    while x >= 0 && x < max_x as isize && y >= 0 && y < max_y as isize {
+        antinodes.push((x as usize, y as usize));
+        x -= delta_x;
+        y -= delta_y;
+    }
+
+    // Other side (since we're going on both sides of the axis defined by both antennas)
+    let mut x: isize = *xa as isize + delta_x;
+    let mut y: isize = *ya as isize + delta_y;
+    while x >= 0 && x < max_x as isize && y >= 0 && y < max_y as isize {
+        antinodes.push((x as usize, y as usize));
+        x += delta_x;
+        y += delta_y;
+    }
+
+    antinodes
+}
+
+#[cfg(test)]
+mod tests_compute_antinodes {
+    use super::*;
+
+    #[test]
+    fn compute_antinodes_01() {
+        assert_eq!(
+            compute_antinodes(&(0, 0), &(1, 2), (9, 9)),
+            vec![(0, 0), (1, 2), (2, 4), (3, 6), (4, 8)]
+        );
+    }
+}
+
+/// Count the distinct antinodes, `compute` giving the ones of an antenna pair
+fn fold(content: &Content, compute: fn(&Coords, &Coords, Coords) -> Vec<Coords>) -> usize {
+    let mut antennas: HashMap<char, Vec<Coords>> = HashMap::new();
+    let bounding_rect = content.get_bounding_rect();
+
+    // Parse map to retrieve all antennas coordinates
+    content.map.iter().for_each(|(coords, &freq)| {
+        if freq != '.' && freq != '#' {
+            antennas.entry(freq).or_default().push((coords.x, coords.y));
+        }
+    });
+
+    // Count all antinodes
+    let mut antinodes: Vec<Coords> = antennas
+        .values()
+        .flat_map(|coords| {
+            coords
+                .iter()
+                .combinations(2)
+                .unique()
+                .flat_map(|c| compute(c[0], c[1], bounding_rect))
+        })
+        .unique()
+        .collect();
+
+    antinodes.sort_by(|(xa, ya), (xb, yb)| match ya.cmp(yb) {
+        Ordering::Less => Ordering::Less,
+        Ordering::Equal => xa.cmp(xb),
+        Ordering::Greater => Ordering::Greater,
+    });
+    antinodes.len()
+}
+
+#[cfg(test)]
+mod tests_fold {
+    use super::*;
+
+    #[test]
+    fn fold_01() {
+        let content = Content::from_str(
+            "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+",
+        )
+        .unwrap();
+        assert_eq!(
+            fold(&content, |a, b, rect| compute_antinode(a, b, rect).into()),
+            14
+        );
+        assert_eq!(fold(&content, compute_antinodes), 34);
+    }
+
+    #[test]
+    fn fold_a() {
+        let content = Content::from_str(
+            "\
+    T....#....
+    ...T......
+    .T....#...
+    .........#
+    ..#.......
+    ..........
+    ...#......
+    ..........
+    ....#.....
+    ..........
+    ",
+        )
+        .unwrap();
+        assert_eq!(fold(&content, compute_antinodes), 9);
+    }
+
+    #[test]
+    fn fold_b() {
+        let content = Content::from_str(
+            "\
+    #.........
+    ..........
+    .#........
+    ..........
+    ..a.......
+    ..........
+    ...a......
+    ..........
+    ....#.....
+    ..........
+    ",
+        )
+        .unwrap();
+        assert_eq!(fold(&content, compute_antinodes), 5);
+    }
+
+    #[test]
+    fn fold_c() {
+        let content = Content::from_str(
+            "\
+    T....#....
+    ...T......
+    .T....#...
+    .........#
+    ..a.......
+    ..........
+    ...a......
+    ..........
+    ....#.....
+    ..........
+    ",
+        )
+        .unwrap();
+        assert_eq!(fold(&content, compute_antinodes), 9);
+    }
+
+    #[test]
+    fn fold_d() {
+        let content = Content::from_str(
+            "\
+    .#....#....#
+    ...#....0...
+    .....0....#.
+    ..#....0....
+    ....0....#..
+    .#....#....#
+    ...#........
+    #....#......
+    ..#.........
+    ....#.......
+    .#..........
+    ...#........
+    ",
+        )
+        .unwrap();
+        assert_eq!(fold(&content, compute_antinodes), 21);
+    }
+
+    #[test]
+    fn fold_e() {
+        let content = Content::from_str(
+            "\
+    #...........
+    .#.#........
+    ..#.#.......
+    ...#........
+    ....#.......
+    .....#A.....
+    ......#.....
+    .......#....
+    ........A...
+    .........A..
+    ..........#.
+    ..........##
+    ",
+        )
+        .unwrap();
+        assert_eq!(fold(&content, compute_antinodes), 16);
+    }
+
+    #[test]
+    fn fold_final() {
+        let content = Content::from_str(
+            "\
+    ##....#....#
+    .#.#....0...
+    ..#.#0....#.
+    ..##...0....
+    ....0....#..
+    .#...#A....#
+    ...#..#.....
+    #....#.#....
+    ..#.....A...
+    ....#....A..
+    .#........#.
+    ...#......##
+    ",
+        )
+        .unwrap();
+        assert_eq!(fold(&content, compute_antinodes), 34);
+    }
+}
+
+#[allow(clippy::items_after_test_module)]
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Content;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Content::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(fold(input, |a, b, rect| {
+            compute_antinode(a, b, rect).into()
+        }))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(fold(input, compute_antinodes))
+    }
+}
@@ -1,18 +1,15 @@
-use std::{fmt::Display, fs, slice::Iter};
+use std::{fmt::Display, slice::Iter, str::FromStr};
 
-fn get_file_content(file_path: &str) -> String {
-    println!("Loading input file: {}", file_path);
-    fs::read_to_string(file_path).expect("Cannot load file")
-}
+use aoc_core::{parse::ParseError, solution::Solution};
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 enum BlockType {
     FreeSpace,
     File(usize),
 }
 
 #[derive(Debug, PartialEq, Clone)]
-struct DiskMap(Vec<BlockType>);
+pub struct DiskMap(Vec<BlockType>);
 
 impl From<Vec<BlockType>> for DiskMap {
     fn from(value: Vec<BlockType>) -> Self {
@@ -23,7 +20,7 @@ impl From<Vec<BlockType>> for DiskMap {
 impl DiskMap {
     fn get(&self, &position: &usize) -> BlockType {
         match self.0.get(position) {
-            Some(block) => block.clone(),
+            Some(block) => *block,
             None => BlockType::FreeSpace,
         }
     }
@@ -45,32 +42,45 @@ impl DiskMap {
     }
 }
 
-impl From<&str> for DiskMap {
-    fn from(value: &str) -> Self {
+/// The dense format: digits alternating between the length of a file and the
+/// length of the free space after it
+impl FromStr for DiskMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut disk_map: DiskMap = DiskMap::from(vec![]);
         let mut file_id: usize = 0;
+        let mut position = 0;
 
-        for (position, block_count_char) in value.chars().enumerate() {
-            if block_count_char.is_ascii_digit() {
-                let _block_count = block_count_char
-                    .to_digit(10)
-                    .unwrap_or_else(|| panic!("Char '{}' should be a number", block_count_char))
-                    as usize;
+        for (index, line) in s.lines().enumerate() {
+            for (offset, block_count_char) in line.char_indices() {
+                if block_count_char.is_whitespace() {
+                    continue;
+                }
+                let Some(block_count) = block_count_char.to_digit(10) else {
+                    return Err(ParseError::at(
+                        index,
+                        line,
+                        &line[offset..offset + block_count_char.len_utf8()],
+                        "expected a block count digit",
+                    ));
+                };
 
                 if position % 2 == 0 {
-                    for _ in 0.._block_count {
+                    for _ in 0..block_count {
                         disk_map.push(BlockType::File(file_id));
                     }
                     file_id += 1;
                 } else {
-                    for _ in 0.._block_count {
+                    for _ in 0..block_count {
                         disk_map.push(BlockType::FreeSpace);
                     }
                 }
+                position += 1;
             }
         }
 
-        disk_map
+        Ok(disk_map)
     }
 }
 
@@ -103,7 +113,7 @@ mod tests_disk_map {
 
     #[test]
     fn disk_map_from_simple() {
-        let disk_map = DiskMap::from("12345");
+        let disk_map = DiskMap::from_str("12345").unwrap();
         pretty_assertions::assert_eq!(
             disk_map,
             DiskMap::from(vec![
@@ -127,9 +137,26 @@ mod tests_disk_map {
         pretty_assertions::assert_eq!(format!("{}", disk_map), "0..111....22222");
     }
 
+    #[test]
+    fn disk_map_from_malformed() {
+        assert_eq!(
+            DiskMap::from_str("12345\n"),
+            Ok(DiskMap::from_str("12345").unwrap())
+        );
+        assert_eq!(
+            DiskMap::from_str("123-45"),
+            Err(ParseError {
+                line: 1,
+                column: 4,
+                text: String::from("-"),
+                reason: String::from("expected a block count digit"),
+            })
+        );
+    }
+
     #[test]
     fn disk_map_from_less_simple() {
-        let disk_map = DiskMap::from("2333133121414131402");
+        let disk_map = DiskMap::from_str("2333133121414131402").unwrap();
         pretty_assertions::assert_eq!(
             disk_map,
             DiskMap::from(vec![
@@ -184,13 +211,125 @@ mod tests_disk_map {
     }
 }
 
+/// Move file blocks one at a time, from the end of the disk to the leftmost
+/// free space
 fn move_file_blocks(disk_map: &mut DiskMap) {
+    let disk_map = &mut disk_map.0;
+    let mut pos = 0;
+    while let Some(&value) = disk_map.get(pos) {
+        match value {
+            BlockType::FreeSpace => {
+                let mut rev_pos: isize = (disk_map.len() - 1) as isize;
+                while let Some(&rev_value) = disk_map.get(rev_pos as usize) {
+                    if rev_value != BlockType::FreeSpace && rev_pos > pos as isize {
+                        // println!("Swapping {} and {}", pos, rev_pos);
+                        disk_map.swap(pos, rev_pos as usize);
+                        break;
+                    }
+                    rev_pos -= 1;
+                }
+            }
+            BlockType::File(_) => {}
+        }
+        pos += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests_move_file_blocks {
+    use super::*;
+
+    #[test]
+    fn move_file_blocks_01() {
+        let mut disk_map = DiskMap::from_str("12345").unwrap();
+        move_file_blocks(&mut disk_map);
+        assert_eq!(
+            disk_map,
+            DiskMap::from(vec![
+                BlockType::File(0),
+                BlockType::File(2),
+                BlockType::File(2),
+                BlockType::File(1),
+                BlockType::File(1),
+                BlockType::File(1),
+                BlockType::File(2),
+                BlockType::File(2),
+                BlockType::File(2),
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace
+            ])
+        );
+    }
+
+    #[test]
+    fn move_file_blocks_02() {
+        let mut disk_map = DiskMap::from_str("2333133121414131402").unwrap();
+        move_file_blocks(&mut disk_map);
+        assert_eq!(
+            disk_map,
+            DiskMap::from(vec![
+                BlockType::File(0),
+                BlockType::File(0),
+                BlockType::File(9),
+                BlockType::File(9),
+                BlockType::File(8),
+                BlockType::File(1),
+                BlockType::File(1),
+                BlockType::File(1),
+                BlockType::File(8),
+                BlockType::File(8),
+                BlockType::File(8),
+                BlockType::File(2),
+                BlockType::File(7),
+                BlockType::File(7),
+                BlockType::File(7),
+                BlockType::File(3),
+                BlockType::File(3),
+                BlockType::File(3),
+                BlockType::File(6),
+                BlockType::File(4),
+                BlockType::File(4),
+                BlockType::File(6),
+                BlockType::File(5),
+                BlockType::File(5),
+                BlockType::File(5),
+                BlockType::File(5),
+                BlockType::File(6),
+                BlockType::File(6),
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace,
+                BlockType::FreeSpace
+            ])
+        );
+    }
+}
+
+/// Move whole files, by descending ID, to the leftmost span of free space
+/// large enough to hold them
+fn move_files(disk_map: &mut DiskMap) {
     // println!("Layout: {}", disk_map);
     let mut last_moved_id = usize::MAX;
     let disk_length = disk_map.len();
 
     // Cursor will move backwards to get file by ID descending order
-    let mut cursor = disk_length - 1;
+    let Some(mut cursor) = disk_length.checked_sub(1) else {
+        return;
+    };
     let mut temp_id = usize::MAX;
     while cursor > 0 {
         let block_type = disk_map.get(&cursor);
@@ -260,13 +399,13 @@ fn move_file_blocks(disk_map: &mut DiskMap) {
 }
 
 #[cfg(test)]
-mod tests_move_file_blocks {
+mod tests_move_files {
     use super::*;
 
     #[test]
-    fn move_file_blocks_final_result() {
-        let mut disk_map = DiskMap::from("2333133121414131402");
-        move_file_blocks(&mut disk_map);
+    fn move_files_final_result() {
+        let mut disk_map = DiskMap::from_str("2333133121414131402").unwrap();
+        move_files(&mut disk_map);
         pretty_assertions::assert_eq!(
             disk_map,
             DiskMap::from(vec![
@@ -386,13 +525,44 @@ mod tests_compute_checksum {
             2858
         );
     }
+
+    #[test]
+    fn compute_checksum_empty() {
+        let mut disk_map = DiskMap::from_str("").unwrap();
+        move_files(&mut disk_map);
+        move_file_blocks(&mut disk_map);
+        assert_eq!(compute_checksum(&disk_map), 0);
+    }
+
+    #[test]
+    fn compute_checksum_02() {
+        let mut disk_map = DiskMap::from_str("2333133121414131402").unwrap();
+        move_file_blocks(&mut disk_map);
+        assert_eq!(compute_checksum(&disk_map), 1928);
+    }
 }
 
 #[allow(clippy::items_after_test_module)]
-fn main() {
-    let mut disk_map = DiskMap::from(get_file_content("assets/input").as_str());
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = DiskMap;
 
-    move_file_blocks(&mut disk_map);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        DiskMap::from_str(input)
+    }
 
-    println!("Result: {}", compute_checksum(&disk_map));
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        let mut disk_map = input.clone();
+        move_file_blocks(&mut disk_map);
+
+        Some(compute_checksum(&disk_map))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let mut disk_map = input.clone();
+        move_files(&mut disk_map);
+
+        Some(compute_checksum(&disk_map))
+    }
 }
@@ -1,20 +1,17 @@
 use std::{
     collections::{HashSet, VecDeque},
     fmt::Display,
-    fs,
     slice::Iter,
+    str::FromStr,
 };
 
-fn get_file_content(file_path: &str) -> String {
-    println!("Loading input file: {}", file_path);
-    fs::read_to_string(file_path).expect("Cannot load file")
-}
+use aoc_core::{parse::ParseError, solution::Solution};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 struct Coords(isize, isize);
 
 #[derive(Debug, PartialEq, Clone)]
-struct Map(Vec<Vec<usize>>);
+pub struct Map(Vec<Vec<usize>>);
 
 impl From<Vec<Vec<usize>>> for Map {
     fn from(value: Vec<Vec<usize>>) -> Self {
@@ -51,26 +48,45 @@ impl Map {
     }
 }
 
-impl From<&str> for Map {
-    fn from(value: &str) -> Self {
-        Self(
-            value
-                .split_whitespace()
-                .map(|row| {
-                    row.chars()
-                        .map(|c| {
-                            if c.is_ascii_digit() {
-                                c.to_digit(10)
-                                    .unwrap_or_else(|| panic!("'{}' should be a digit", &c))
-                                    as usize
-                            } else {
-                                usize::MAX
-                            }
-                        })
-                        .collect()
+/// One row per line, each tile being a height digit or `.` for impassable
+/// terrain
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rows: Vec<Vec<usize>> = vec![];
+        for (index, line) in s.lines().enumerate() {
+            let row = line.trim();
+            if row.is_empty() {
+                continue;
+            }
+            let heights = row
+                .char_indices()
+                .map(|(offset, c)| match c {
+                    '.' => Ok(usize::MAX),
+                    _ => c.to_digit(10).map(|height| height as usize).ok_or_else(|| {
+                        ParseError::at(
+                            index,
+                            line,
+                            &row[offset..offset + c.len_utf8()],
+                            "expected a height digit or `.`",
+                        )
+                    }),
                 })
-                .collect(),
-        )
+                .collect::<Result<Vec<usize>, ParseError>>()?;
+            if let Some(first) = rows.first() {
+                if first.len() != heights.len() {
+                    return Err(ParseError::at(
+                        index,
+                        line,
+                        row,
+                        format!("expected {} tiles, got {}", first.len(), heights.len()),
+                    ));
+                }
+            }
+            rows.push(heights);
+        }
+        Ok(Self(rows))
     }
 }
 
@@ -103,14 +119,15 @@ mod tests_map {
 
     #[test]
     fn map_from_simple() {
-        let map = Map::from(
+        let map = Map::from_str(
             "\
 0123
 1234
 8765
 9876
 ",
-        );
+        )
+        .unwrap();
         pretty_assertions::assert_eq!(
             map,
             Map::from(vec![
@@ -133,7 +150,7 @@ mod tests_map {
 
     #[test]
     fn map_from_less_simple() {
-        let map = Map::from(
+        let map = Map::from_str(
             "\
 89010123
 78121874
@@ -144,7 +161,8 @@ mod tests_map {
 01329801
 10456732
 ",
-        );
+        )
+        .unwrap();
         pretty_assertions::assert_eq!(
             map,
             Map::from(vec![
@@ -173,9 +191,31 @@ mod tests_map {
         );
     }
 
+    #[test]
+    fn map_from_malformed() {
+        assert_eq!(
+            Map::from_str("0123\n1x34\n"),
+            Err(ParseError {
+                line: 2,
+                column: 2,
+                text: String::from("x"),
+                reason: String::from("expected a height digit or `.`"),
+            })
+        );
+        assert_eq!(
+            Map::from_str("0123\n123\n"),
+            Err(ParseError {
+                line: 2,
+                column: 1,
+                text: String::from("123"),
+                reason: String::from("expected 4 tiles, got 3"),
+            })
+        );
+    }
+
     #[test]
     fn map_from_impassable_terrain() {
-        let map = Map::from(
+        let map = Map::from_str(
             "\
 ...0...
 ...1...
@@ -185,7 +225,8 @@ mod tests_map {
 8.....8
 9.....9
 ",
-        );
+        )
+        .unwrap();
         pretty_assertions::assert_eq!(
             map,
             Map::from(vec![
@@ -261,6 +302,102 @@ mod tests_map {
     }
 }
 
+/// BFS used to retrived the found goal of the hiking trail
+///
+/// Many thanks to https://en.wikipedia.org/wiki/Breadth-first_search
+fn discover_trail(map: &Map, start: &Coords, already_discovered: &[Coords]) -> Option<Coords> {
+    // let Q be a queue
+    let mut queue: Vec<Coords> = vec![];
+    let mut explored: HashSet<Coords> = HashSet::new();
+    // label start as explored
+    explored.insert(start.to_owned());
+
+    queue.push(start.to_owned());
+    // while Q is not empty do
+    // v := Q.dequeue()
+    while let Some(v) = queue.pop() {
+        // if v is an already undiscovered goal then we have discovered a hike trail
+        if map.get(&v) == Some(&9) && !already_discovered.contains(&v) {
+            return Some(v);
+        }
+
+        //  for all edges in G.adjacentEdges(v) do
+        for edge in map.get_possible_adjacent_edges(&v) {
+            // if w is not labeled as explored then
+            if !explored.contains(&edge) {
+                // label edge as explored
+                explored.insert(edge.clone());
+                // w.parent := v // Not needed here, we don't care about the actual path
+                // Q.enqueue(w)
+                queue.push(edge.clone());
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests_discover_trail {
+    use super::*;
+
+    #[test]
+    fn discover_trail_simple() {
+        let map = Map::from_str(
+            "\
+...0...
+...1...
+...2...
+6543...
+7......
+8......
+9......
+",
+        )
+        .unwrap();
+        assert_eq!(discover_trail(&map, &Coords(3, 0), &[]), Some(Coords(0, 6)));
+    }
+
+    #[test]
+    fn discover_trail_one_trail_already_discovered() {
+        let map = Map::from_str(
+            "\
+...0...
+...1...
+...2...
+6543456
+7.....7
+8.....8
+9.....9
+",
+        )
+        .unwrap();
+        assert_eq!(
+            discover_trail(&map, &Coords(3, 0), &[Coords(0, 6)]),
+            Some(Coords(6, 6))
+        );
+    }
+
+    #[test]
+    fn discover_trail_all_already_discovered() {
+        let map = Map::from_str(
+            "\
+...0...
+...1...
+...2...
+6543456
+7.....7
+8.....8
+9.....9
+",
+        )
+        .unwrap();
+        assert_eq!(
+            discover_trail(&map, &Coords(3, 0), &[Coords(0, 6), Coords(6, 6)]),
+            None
+        );
+    }
+}
+
 type Path = VecDeque<Coords>;
 
 /// BFS used to retrived the found goal of the hiking trail
@@ -296,12 +433,12 @@ fn discover_path(map: &Map, start: &Coords, already_discovered_paths: &[Path]) -
 }
 
 #[cfg(test)]
-mod tests_discover_trail {
+mod tests_discover_path {
     use super::*;
 
     #[test]
-    fn discover_trail_simple() {
-        let map = Map::from(
+    fn discover_path_simple() {
+        let map = Map::from_str(
             "\
 ...0...
 ...1...
@@ -311,7 +448,8 @@ mod tests_discover_trail {
 8......
 9......
 ",
-        );
+        )
+        .unwrap();
         assert_eq!(
             discover_path(&map, &Coords(3, 0), &[]),
             Some(VecDeque::from(vec![
@@ -330,8 +468,8 @@ mod tests_discover_trail {
     }
 
     #[test]
-    fn discover_trail_one_trail_already_discovered() {
-        let map = Map::from(
+    fn discover_path_one_trail_already_discovered() {
+        let map = Map::from_str(
             "\
 ...0...
 ...1...
@@ -341,7 +479,8 @@ mod tests_discover_trail {
 8.....8
 9.....9
 ",
-        );
+        )
+        .unwrap();
         assert_eq!(
             discover_path(&map, &Coords(3, 0), &[VecDeque::from(vec![Coords(0, 6)])]),
             Some(VecDeque::from(vec![
@@ -360,8 +499,8 @@ mod tests_discover_trail {
     }
 
     #[test]
-    fn discover_trail_all_already_discovered() {
-        let map = Map::from(
+    fn discover_path_all_already_discovered() {
+        let map = Map::from_str(
             "\
 ...0...
 ...1...
@@ -371,7 +510,8 @@ mod tests_discover_trail {
 8.....8
 9.....9
 ",
-        );
+        )
+        .unwrap();
         assert_eq!(
             discover_path(
                 &map,
@@ -408,8 +548,8 @@ mod tests_discover_trail {
     }
 
     #[test]
-    fn discover_trail_multiple_at_once() {
-        let map = Map::from(
+    fn discover_path_multiple_at_once() {
+        let map = Map::from_str(
             "\
 .....0.
 ..4321.
@@ -419,7 +559,8 @@ mod tests_discover_trail {
 ..8765.
 ..9....
 ",
-        );
+        )
+        .unwrap();
         assert_eq!(
             discover_path(
                 &map,
@@ -468,9 +609,9 @@ mod tests_discover_trail {
     }
 }
 
-fn count_trails(map: &Map) -> usize {
-    let origin_points: Vec<Coords> = map
-        .iter()
+/// Every position at height 0
+fn get_trailheads(map: &Map) -> Vec<Coords> {
+    map.iter()
         .enumerate()
         .flat_map(|(y, row)| {
             row.iter()
@@ -484,10 +625,83 @@ fn count_trails(map: &Map) -> usize {
                 })
                 .collect::<Vec<Coords>>()
         })
-        .collect();
+        .collect()
+}
 
-    let mut paths_found = 0;
+fn count_trails(map: &Map) -> usize {
+    let origin_points = get_trailheads(map);
+
+    let mut trails_found = 0;
     for origin in origin_points {
+        let mut already_discovered: Vec<Coords> = vec![];
+        while let Some(goal) = discover_trail(map, &origin, &already_discovered) {
+            trails_found += 1;
+            already_discovered.push(goal);
+        }
+    }
+    trails_found
+}
+
+#[cfg(test)]
+mod tests_count_trails {
+    use super::*;
+
+    #[test]
+    fn count_trails_one() {
+        let map = Map::from_str(
+            "\
+...0...
+...1...
+...2...
+6543...
+7......
+8......
+9......
+",
+        )
+        .unwrap();
+        pretty_assertions::assert_eq!(count_trails(&map), 1);
+    }
+
+    #[test]
+    fn count_trails_two() {
+        let map = Map::from_str(
+            "\
+...0...
+...1...
+...2...
+6543456
+7.....7
+8.....8
+9.....9
+",
+        )
+        .unwrap();
+        pretty_assertions::assert_eq!(count_trails(&map), 2);
+    }
+
+    #[test]
+    fn count_trails_less_simple() {
+        let map = Map::from_str(
+            "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+",
+        )
+        .unwrap();
+        pretty_assertions::assert_eq!(count_trails(&map), 36);
+    }
+}
+
+fn count_paths(map: &Map) -> usize {
+    let mut paths_found = 0;
+    for origin in get_trailheads(map) {
         let mut already_discovered_paths: Vec<Path> = vec![];
         while let Some(path) = discover_path(map, &origin, &already_discovered_paths) {
             paths_found += 1;
@@ -498,12 +712,12 @@ fn count_trails(map: &Map) -> usize {
 }
 
 #[cfg(test)]
-mod tests_count_trails {
+mod tests_count_paths {
     use super::*;
 
     #[test]
-    fn count_trails_a() {
-        let map = Map::from(
+    fn count_paths_a() {
+        let map = Map::from_str(
             "\
 .....0.
 ..4321.
@@ -513,13 +727,14 @@ mod tests_count_trails {
 ..8765.
 ..9....
 ",
-        );
-        pretty_assertions::assert_eq!(count_trails(&map), 3);
+        )
+        .unwrap();
+        pretty_assertions::assert_eq!(count_paths(&map), 3);
     }
 
     #[test]
-    fn count_trails_b() {
-        let map = Map::from(
+    fn count_paths_b() {
+        let map = Map::from_str(
             "\
 ..90..9
 ...1.98
@@ -529,13 +744,14 @@ mod tests_count_trails {
 876....
 987....
 ",
-        );
-        pretty_assertions::assert_eq!(count_trails(&map), 13);
+        )
+        .unwrap();
+        pretty_assertions::assert_eq!(count_paths(&map), 13);
     }
 
     #[test]
-    fn count_trails_c() {
-        let map = Map::from(
+    fn count_paths_c() {
+        let map = Map::from_str(
             "\
 89010123
 78121874
@@ -546,14 +762,27 @@ mod tests_count_trails {
 01329801
 10456732
 ",
-        );
-        pretty_assertions::assert_eq!(count_trails(&map), 81);
+        )
+        .unwrap();
+        pretty_assertions::assert_eq!(count_paths(&map), 81);
     }
 }
 
 #[allow(clippy::items_after_test_module)]
-fn main() {
-    let map = Map::from(get_file_content("assets/input").as_str());
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
 
-    println!("Result: {}", count_trails(&map));
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        Some(count_trails(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        Some(count_paths(input))
+    }
 }
@@ -1,17 +1,17 @@
 use std::{
     collections::HashMap,
     fmt::Display,
-    fs,
     ops::{Deref, DerefMut},
+    str::FromStr,
 };
 
-fn get_file_content(file_path: &str) -> String {
-    println!("Loading input file: {}", file_path);
-    fs::read_to_string(file_path).expect("Cannot load file")
-}
+use aoc_core::{
+    parse::{parse_field, ParseError},
+    solution::Solution,
+};
 
 #[derive(Debug, PartialEq, Clone)]
-struct Stones(
+pub struct Stones(
     HashMap<
         usize, // Stone ID
         usize, // Stone count
@@ -38,15 +38,21 @@ impl From<Vec<(usize, usize)>> for Stones {
     }
 }
 
-impl From<&str> for Stones {
-    fn from(value: &str) -> Self {
-        Self(HashMap::from_iter(value.split_whitespace().map(|s| {
-            (
-                s.parse()
-                    .unwrap_or_else(|_| panic!("{} should be a number", s)),
-                1,
-            )
-        })))
+impl FromStr for Stones {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut stones = Stones(HashMap::new());
+        for (index, line) in s.lines().enumerate() {
+            for stone in line.split_whitespace() {
+                upsert(
+                    &mut stones,
+                    parse_field(index, line, stone, "stone")?,
+                    |v| v + 1,
+                );
+            }
+        }
+        Ok(stones)
     }
 }
 
@@ -70,9 +76,28 @@ mod tests_stones {
 
     #[test]
     fn stones_from_simple() {
-        let stones = Stones::from("10 20 30");
+        let stones = Stones::from_str("10 20 30").unwrap();
         pretty_assertions::assert_eq!(stones, Stones::from(vec![(10, 1), (20, 1), (30, 1)]));
     }
+
+    #[test]
+    fn stones_from_same_number() {
+        let stones = Stones::from_str("10 20 10\n").unwrap();
+        pretty_assertions::assert_eq!(stones, Stones::from(vec![(10, 2), (20, 1)]));
+    }
+
+    #[test]
+    fn stones_from_malformed() {
+        assert_eq!(
+            Stones::from_str("10 2O 30"),
+            Err(ParseError {
+                line: 1,
+                column: 4,
+                text: String::from("2O"),
+                reason: String::from("invalid stone: invalid digit found in string"),
+            })
+        );
+    }
 }
 
 fn upsert(stones: &mut Stones, key: usize, closure: impl Fn(usize) -> usize) {
@@ -113,7 +138,7 @@ mod tests_blink_once {
 
     #[test]
     fn blink_once_01() {
-        let mut stones = Stones::from("0 1 23 1000");
+        let mut stones = Stones::from_str("0 1 23 1000").unwrap();
         blink_once(&mut stones);
         println!("Final Stones: {}", stones);
         dbg!(&stones, stones.get(&0), stones.get(&1));
@@ -140,27 +165,45 @@ mod tests_blink_n_times {
 
     #[test]
     fn blink_n_times_01() {
-        let mut stones = Stones::from("125 17");
+        let mut stones = Stones::from_str("125 17").unwrap();
         blink_n_times(&mut stones, 1);
-        assert_eq!(stones.iter().map(|(_, v)| *v).sum::<usize>(), 3);
+        assert_eq!(stones.values().copied().sum::<usize>(), 3);
         blink_n_times(&mut stones, 1);
-        assert_eq!(stones.iter().map(|(_, v)| *v).sum::<usize>(), 4);
+        assert_eq!(stones.values().copied().sum::<usize>(), 4);
         blink_n_times(&mut stones, 1);
-        assert_eq!(stones.iter().map(|(_, v)| *v).sum::<usize>(), 5);
+        assert_eq!(stones.values().copied().sum::<usize>(), 5);
         blink_n_times(&mut stones, 1);
-        assert_eq!(stones.iter().map(|(_, v)| *v).sum::<usize>(), 9);
+        assert_eq!(stones.values().copied().sum::<usize>(), 9);
         blink_n_times(&mut stones, 1);
-        assert_eq!(stones.iter().map(|(_, v)| *v).sum::<usize>(), 13);
+        assert_eq!(stones.values().copied().sum::<usize>(), 13);
         blink_n_times(&mut stones, 1);
-        assert_eq!(stones.iter().map(|(_, v)| *v).sum::<usize>(), 22);
+        assert_eq!(stones.values().copied().sum::<usize>(), 22);
         blink_n_times(&mut stones, 19);
-        assert_eq!(stones.iter().map(|(_, v)| *v).sum::<usize>(), 55312);
+        assert_eq!(stones.values().copied().sum::<usize>(), 55312);
     }
 }
 
 #[allow(clippy::items_after_test_module)]
-fn main() {
-    let mut stones = Stones::from(get_file_content("assets/input").as_str());
-    blink_n_times(&mut stones, 75);
-    println!("Result: {}", stones.iter().map(|(_, v)| *v).sum::<usize>());
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Stones;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Stones::from_str(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<impl Display> {
+        let mut stones = input.clone();
+        blink_n_times(&mut stones, 25);
+
+        Some(stones.values().copied().sum::<usize>())
+    }
+
+    fn part2(input: &Self::Input) -> Option<impl Display> {
+        let mut stones = input.clone();
+        blink_n_times(&mut stones, 75);
+
+        Some(stones.values().copied().sum::<usize>())
+    }
 }
@@ -0,0 +1,137 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{parse::ParseError, solution::Solution};
+
+/// What is being timed
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Step {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// Every duration measured for a step, sorted from fastest to slowest
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Timing {
+    pub step: Step,
+    pub durations: Vec<Duration>,
+}
+
+impl Timing {
+    pub fn new(step: Step, mut durations: Vec<Duration>) -> Self {
+        assert!(!durations.is_empty(), "Cannot time {} without runs", step);
+        durations.sort();
+        Timing { step, durations }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.durations[0]
+    }
+
+    /// Upper median when there is an even number of runs
+    pub fn median(&self) -> Duration {
+        self.durations[self.durations.len() / 2]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.durations[self.durations.len() - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests_timing {
+    use super::*;
+
+    #[test]
+    fn timing_01() {
+        let timing = Timing::new(
+            Step::Part(2),
+            [3, 1, 4, 1, 5].map(Duration::from_millis).to_vec(),
+        );
+        assert_eq!(timing.min(), Duration::from_millis(1));
+        assert_eq!(timing.median(), Duration::from_millis(3));
+        assert_eq!(timing.max(), Duration::from_millis(5));
+        assert_eq!(timing.step.to_string(), "part 2");
+    }
+}
+
+/// Timings of parsing and of each part, or why the input could not be parsed
+pub type Timings = Result<Vec<Timing>, ParseError>;
+
+fn time<T>(runs: usize, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+/// Parse `input` and solve each of the given parts of `S`, `runs` times each.
+///
+/// Parts that `S` does not implement are left out.
+pub fn bench<S: Solution>(input: &str, parts: &[u8], runs: usize) -> Timings {
+    let parsed = S::parse(input)?;
+    let mut timings = vec![Timing::new(Step::Parse, time(runs, || S::parse(input)))];
+
+    for &part in parts {
+        // The first, untimed, solve doubles as a warm-up
+        let durations = match part {
+            1 if S::part1(&parsed).is_some() => time(runs, || S::part1(&parsed)),
+            2 if S::part2(&parsed).is_some() => time(runs, || S::part2(&parsed)),
+            _ => continue,
+        };
+        timings.push(Timing::new(Step::Part(part), durations));
+    }
+
+    Ok(timings)
+}
+
+#[cfg(test)]
+mod tests_bench {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(index, line)| crate::parse::parse_field(index, line, line, "number"))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Option<impl Display> {
+            Some(input.iter().sum::<u32>())
+        }
+    }
+
+    #[test]
+    fn bench_01() {
+        let timings = bench::<Sum>("1\n2\n3", &[1, 2], 3).unwrap();
+        assert_eq!(
+            timings
+                .iter()
+                .map(|timing| (timing.step, timing.durations.len()))
+                .collect::<Vec<_>>(),
+            vec![(Step::Parse, 3), (Step::Part(1), 3)]
+        );
+        assert!(bench::<Sum>("1\nx", &[1], 3).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt::Display;
+
+use num_traits::PrimInt;
+
+/// A point on a 2D map.
+///
+/// The component type is left to each day: some maps only ever need `usize`,
+/// others have to account for negative (or very large) positions.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Coords<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Coords<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Coords { x, y }
+    }
+}
+
+impl<T> From<(T, T)> for Coords<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Coords { x, y }
+    }
+}
+
+impl<T: Display> Display for Coords<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// The smallest rectangle holding a set of coordinates.
+///
+/// `width` and `height` are cell counts, so the rectangle spans from `origin`
+/// up to (but excluding) `origin + (width, height)`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct BoundingRectangle<T = isize> {
+    pub origin: Coords<T>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<T: PrimInt> BoundingRectangle<T> {
+    /// Bottom-right corner, which is still inside the rectangle
+    pub fn end(&self) -> Coords<T> {
+        Coords {
+            x: self.origin.x + T::from(self.width).unwrap() - T::one(),
+            y: self.origin.y + T::from(self.height).unwrap() - T::one(),
+        }
+    }
+
+    pub fn contains(&self, coords: &Coords<T>) -> bool {
+        let end = self.end();
+        coords.x >= self.origin.x
+            && coords.x <= end.x
+            && coords.y >= self.origin.y
+            && coords.y <= end.y
+    }
+
+    /// Every coordinates of the rectangle, row by row
+    pub fn iter(&self) -> impl Iterator<Item = Coords<T>> + '_ {
+        (0..self.height).flat_map(move |dy| {
+            (0..self.width).map(move |dx| Coords {
+                x: self.origin.x + T::from(dx).unwrap(),
+                y: self.origin.y + T::from(dy).unwrap(),
+            })
+        })
+    }
+}
+
+/// Compute the bounding rectangle of all given coordinates.
+///
+/// Panics if there are no coordinates at all, since there is no meaningful
+/// rectangle to return in that case.
+pub fn get_bounding_rect<T, I>(coords: I) -> BoundingRectangle<T>
+where
+    T: PrimInt,
+    I: IntoIterator,
+    I::Item: Into<Coords<T>>,
+{
+    let mut coords = coords.into_iter().map(Into::into);
+    let first = coords.next().expect("Cannot bound an empty map");
+    let (min, max) = coords.fold((first, first), |(min, max), c| {
+        (
+            Coords {
+                x: min.x.min(c.x),
+                y: min.y.min(c.y),
+            },
+            Coords {
+                x: max.x.max(c.x),
+                y: max.y.max(c.y),
+            },
+        )
+    });
+    BoundingRectangle {
+        origin: min,
+        width: (max.x - min.x).to_usize().unwrap() + 1,
+        height: (max.y - min.y).to_usize().unwrap() + 1,
+    }
+}
+
+#[cfg(test)]
+mod tests_get_bounding_rect {
+    use super::*;
+
+    #[test]
+    fn get_bounding_rect_01() {
+        assert_eq!(
+            get_bounding_rect(vec![(0usize, 0usize), (3, 1), (2, 4)]),
+            BoundingRectangle {
+                origin: Coords { x: 0, y: 0 },
+                width: 4,
+                height: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn get_bounding_rect_negative() {
+        let rect = get_bounding_rect(vec![Coords { x: -2, y: 3 }, Coords { x: 1, y: -1 }]);
+        assert_eq!(
+            rect,
+            BoundingRectangle {
+                origin: Coords { x: -2, y: -1 },
+                width: 4,
+                height: 5,
+            }
+        );
+        assert_eq!(rect.end(), Coords { x: 1, y: 3 });
+        assert!(rect.contains(&Coords { x: 0, y: 0 }));
+        assert!(!rect.contains(&Coords { x: 2, y: 0 }));
+        assert_eq!(rect.iter().count(), 20);
+    }
+}
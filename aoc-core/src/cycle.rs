@@ -0,0 +1,85 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// The states of a simulation, each computed from the previous one, up to the
+/// first one seen twice: from `start` on, they repeat every `length` steps.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Cycle<T> {
+    /// Every state before the first repeated one, the initial one first
+    states: Vec<T>,
+    /// How many steps it takes to enter the cycle
+    pub start: usize,
+    /// How many steps it takes to go around the cycle
+    pub length: usize,
+}
+
+impl<T> Cycle<T> {
+    /// The state after `n` steps, however large `n` is
+    pub fn nth(&self, n: usize) -> &T {
+        if n < self.start {
+            &self.states[n]
+        } else {
+            &self.states[self.start + (n - self.start) % self.length]
+        }
+    }
+}
+
+/// Run `step` from `initial` until a state comes back. Every state is kept
+/// (and hashed) along the way, so that the ones before the cycle can be given
+/// back too.
+///
+/// The states must eventually repeat, which they do as long as there is a
+/// finite number of them.
+pub fn find_cycle<T, F>(initial: T, mut step: F) -> Cycle<T>
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut states: Vec<T> = vec![];
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            return Cycle {
+                start,
+                length: states.len() - start,
+                states,
+            };
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests_find_cycle {
+    use super::*;
+
+    #[test]
+    fn find_cycle_01() {
+        // 1, 2, 4, 8, 16, 32, 64, 28, 56, 12, 24, 48, 96, 92, 84, 68, 36, 72, 44, 88, 76, 52, 4
+        let cycle = find_cycle(1, |n| n * 2 % 100);
+        assert_eq!(cycle.start, 2);
+        assert_eq!(cycle.length, 20);
+        assert_eq!(cycle.nth(0), &1);
+        assert_eq!(cycle.nth(7), &28);
+        assert_eq!(cycle.nth(22), &4);
+        assert_eq!(cycle.nth(1_000_000_000), &76);
+    }
+
+    #[test]
+    fn find_cycle_from_the_start() {
+        let cycle = find_cycle('a', |c| if *c == 'a' { 'b' } else { 'a' });
+        assert_eq!(cycle.start, 0);
+        assert_eq!(cycle.length, 2);
+        assert_eq!(cycle.nth(3), &'b');
+    }
+
+    #[test]
+    fn find_cycle_fixed_point() {
+        let cycle = find_cycle(5, |n| (n + 1).min(7));
+        assert_eq!((cycle.start, cycle.length), (2, 1));
+        assert_eq!(cycle.nth(100), &7);
+    }
+}
@@ -0,0 +1,272 @@
+use std::{fmt::Display, str::FromStr};
+
+use num_traits::{PrimInt, Signed};
+
+use crate::coords::{BoundingRectangle, Coords};
+
+/// What 4-way and 8-way directions have in common.
+///
+/// Like on a map, `y` grows towards the south.
+pub trait Compass: Copy + Eq + 'static {
+    /// Every direction, clockwise from north
+    const ALL: &'static [Self];
+
+    /// Move of a single cell in this direction
+    fn delta(self) -> (isize, isize);
+
+    /// Rotate clockwise by `steps` directions (counterclockwise if negative)
+    fn rotate(self, steps: isize) -> Self {
+        let index = Self::ALL.iter().position(|&d| d == self).unwrap() as isize;
+        Self::ALL[(index + steps).rem_euclid(Self::ALL.len() as isize) as usize]
+    }
+
+    /// Quarter turn clockwise
+    fn turn_right(self) -> Self {
+        self.rotate(Self::ALL.len() as isize / 4)
+    }
+
+    /// Quarter turn counterclockwise
+    fn turn_left(self) -> Self {
+        self.rotate(-(Self::ALL.len() as isize) / 4)
+    }
+
+    fn reverse(self) -> Self {
+        self.rotate(Self::ALL.len() as isize / 2)
+    }
+
+    /// `coords` moved `distance` cells in this direction, on an unbounded map
+    fn moved<T: PrimInt + Signed>(self, coords: Coords<T>, distance: T) -> Coords<T> {
+        let (dx, dy) = self.delta();
+        Coords {
+            x: coords.x + T::from(dx).unwrap() * distance,
+            y: coords.y + T::from(dy).unwrap() * distance,
+        }
+    }
+
+    /// The cell next to `coords` in this direction, unless it is outside of
+    /// `rect`
+    fn step(self, coords: Coords<usize>, rect: &BoundingRectangle<usize>) -> Option<Coords<usize>> {
+        let (dx, dy) = self.delta();
+        let next = Coords {
+            x: coords.x.checked_add_signed(dx)?,
+            y: coords.y.checked_add_signed(dy)?,
+        };
+        rect.contains(&next).then_some(next)
+    }
+}
+
+/// The 4 cardinal directions
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Compass for Direction {
+    const ALL: &'static [Self] = &[
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+}
+
+impl Direction {
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+}
+
+/// Any of `U/R/D/L`, `^>v<` or `N/E/S/W`
+impl TryFrom<char> for Direction {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' | 'N' => Ok(Direction::North),
+            'R' | '>' | 'E' => Ok(Direction::East),
+            'D' | 'v' | 'S' => Ok(Direction::South),
+            'L' | '<' | 'W' => Ok(Direction::West),
+            _ => Err(()),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Drawn as an arrow
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::North => write!(f, "^"),
+            Direction::East => write!(f, ">"),
+            Direction::South => write!(f, "v"),
+            Direction::West => write!(f, "<"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_direction {
+    use super::*;
+
+    #[test]
+    fn turn_01() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+        assert_eq!(Direction::South.rotate(-3), Direction::West);
+    }
+
+    #[test]
+    fn parse_01() {
+        assert_eq!("U".parse(), Ok(Direction::North));
+        assert_eq!(">".parse(), Ok(Direction::East));
+        assert_eq!("S".parse(), Ok(Direction::South));
+        assert_eq!(Direction::try_from('<'), Ok(Direction::West));
+        assert_eq!("X".parse::<Direction>(), Err(()));
+        assert_eq!("UD".parse::<Direction>(), Err(()));
+    }
+
+    #[test]
+    fn moved_01() {
+        assert_eq!(
+            Direction::North.moved(Coords { x: 2, y: 1 }, 3),
+            Coords { x: 2, y: -2 }
+        );
+    }
+
+    #[test]
+    fn step_01() {
+        let rect = BoundingRectangle {
+            origin: Coords { x: 0, y: 0 },
+            width: 3,
+            height: 2,
+        };
+        let corner = Coords { x: 2, y: 1 };
+        assert_eq!(Direction::East.step(corner, &rect), None);
+        assert_eq!(Direction::South.step(corner, &rect), None);
+        assert_eq!(
+            Direction::West.step(corner, &rect),
+            Some(Coords { x: 1, y: 1 })
+        );
+        assert_eq!(Direction::North.step(Coords { x: 0, y: 0 }, &rect), None);
+    }
+}
+
+/// The 4 cardinal and 4 intercardinal directions
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass for Direction8 {
+    const ALL: &'static [Self] = &[
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Direction8::North,
+            Direction::East => Direction8::East,
+            Direction::South => Direction8::South,
+            Direction::West => Direction8::West,
+        }
+    }
+}
+
+/// `N`, `NE`, `E`, … or anything a `Direction` can be parsed from
+impl FromStr for Direction8 {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction8::NorthEast),
+            "SE" => Ok(Direction8::SouthEast),
+            "SW" => Ok(Direction8::SouthWest),
+            "NW" => Ok(Direction8::NorthWest),
+            _ => s.parse::<Direction>().map(Direction8::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_direction8 {
+    use super::*;
+
+    #[test]
+    fn turn_01() {
+        assert_eq!(Direction8::NorthEast.turn_right(), Direction8::SouthEast);
+        assert_eq!(Direction8::NorthEast.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthWest.reverse(), Direction8::NorthEast);
+        assert_eq!(Direction8::North.rotate(-1), Direction8::NorthWest);
+    }
+
+    #[test]
+    fn parse_01() {
+        assert_eq!("NE".parse(), Ok(Direction8::NorthEast));
+        assert_eq!("v".parse(), Ok(Direction8::South));
+        assert_eq!("NNE".parse::<Direction8>(), Err(()));
+    }
+
+    #[test]
+    fn delta_01() {
+        // Opposite directions cancel each other out
+        for &direction in Direction8::ALL {
+            let (dx, dy) = direction.delta();
+            let (rx, ry) = direction.reverse().delta();
+            assert_eq!((dx + rx, dy + ry), (0, 0));
+        }
+    }
+}
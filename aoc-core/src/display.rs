@@ -0,0 +1,56 @@
+use std::fmt::Display;
+
+use num_traits::PrimInt;
+
+use crate::{
+    coords::{BoundingRectangle, Coords},
+    log::{self, Level},
+};
+
+/// Dump a map to stderr at trace level, row by row, asking `render` how to
+/// draw each cell
+pub fn display_map<T, D, F>(rect: &BoundingRectangle<T>, mut render: F)
+where
+    T: PrimInt,
+    D: Display,
+    F: FnMut(&Coords<T>) -> D,
+{
+    if log::enabled(Level::Trace) {
+        eprint!("{}", render_map(rect, &mut render));
+    }
+}
+
+/// Same as `display_map`, but return the drawing instead of writing it
+pub fn render_map<T, D, F>(rect: &BoundingRectangle<T>, mut render: F) -> String
+where
+    T: PrimInt,
+    D: Display,
+    F: FnMut(&Coords<T>) -> D,
+{
+    let mut out = String::new();
+    for (index, coords) in rect.iter().enumerate() {
+        out.push_str(&render(&coords).to_string());
+        if (index + 1) % rect.width == 0 {
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests_render_map {
+    use super::*;
+
+    #[test]
+    fn render_map_01() {
+        let rect = BoundingRectangle {
+            origin: Coords { x: 1, y: 1 },
+            width: 3,
+            height: 2,
+        };
+        assert_eq!(
+            render_map(&rect, |c: &Coords| if c.x == c.y { '#' } else { '.' }),
+            "#..\n.#.\n"
+        );
+    }
+}
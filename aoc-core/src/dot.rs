@@ -0,0 +1,158 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+/// How a node is drawn
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+pub enum Shape {
+    #[default]
+    Ellipse,
+    Box,
+    Diamond,
+    DoubleCircle,
+}
+
+impl Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Shape::Ellipse => write!(f, "ellipse"),
+            Shape::Box => write!(f, "box"),
+            Shape::Diamond => write!(f, "diamond"),
+            Shape::DoubleCircle => write!(f, "doublecircle"),
+        }
+    }
+}
+
+/// A graph in the GraphViz DOT language, to be drawn with e.g.
+/// `dot -Tsvg graph.dot -o graph.svg`.
+///
+/// Nodes and edges are written sorted, so that the same graph always gives the
+/// same file whatever order it was built in. Edges of an undirected graph are
+/// only written once, whichever way round they were added.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Dot {
+    name: String,
+    directed: bool,
+    nodes: BTreeMap<String, Shape>,
+    edges: BTreeSet<(String, String, Option<String>)>,
+}
+
+impl Dot {
+    pub fn digraph(name: &str) -> Self {
+        Dot {
+            name: name.to_string(),
+            directed: true,
+            nodes: BTreeMap::new(),
+            edges: BTreeSet::new(),
+        }
+    }
+
+    pub fn graph(name: &str) -> Self {
+        Dot {
+            directed: false,
+            ..Dot::digraph(name)
+        }
+    }
+
+    /// Add a node, or change the shape of an existing one
+    pub fn node(&mut self, id: &str, shape: Shape) -> &mut Self {
+        self.nodes.insert(id.to_string(), shape);
+        self
+    }
+
+    fn add_edge(&mut self, from: &str, to: &str, label: Option<String>) -> &mut Self {
+        for id in [from, to] {
+            if !self.nodes.contains_key(id) {
+                self.nodes.insert(id.to_string(), Shape::default());
+            }
+        }
+        let (from, to) = if !self.directed && to < from {
+            (to, from)
+        } else {
+            (from, to)
+        };
+        self.edges.insert((from.to_string(), to.to_string(), label));
+        self
+    }
+
+    /// Add an edge, along with any node it needs
+    pub fn edge(&mut self, from: &str, to: &str) -> &mut Self {
+        self.add_edge(from, to, None)
+    }
+
+    /// Add an edge showing its weight (or any other label)
+    pub fn labeled_edge(&mut self, from: &str, to: &str, label: impl Display) -> &mut Self {
+        self.add_edge(from, to, Some(label.to_string()))
+    }
+}
+
+/// Quote an identifier, so that any name is valid
+fn quoted(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl Display for Dot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (keyword, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        writeln!(f, "{} {} {{", keyword, quoted(&self.name))?;
+        for (id, shape) in &self.nodes {
+            writeln!(f, "    {} [shape={}];", quoted(id), shape)?;
+        }
+        for (from, to, label) in &self.edges {
+            write!(f, "    {} {} {}", quoted(from), arrow, quoted(to))?;
+            match label {
+                Some(label) => writeln!(f, " [label={}];", quoted(label))?,
+                None => writeln!(f, ";")?,
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// Types that can be drawn as a graph
+pub trait ToDot {
+    fn to_dot(&self) -> Dot;
+}
+
+#[cfg(test)]
+mod tests_dot {
+    use super::*;
+
+    #[test]
+    fn digraph_01() {
+        let mut dot = Dot::digraph("circuit");
+        dot.node("b", Shape::Box)
+            .labeled_edge("b", "a", 12)
+            .edge("a", "b");
+        assert_eq!(
+            dot.to_string(),
+            r#"digraph "circuit" {
+    "a" [shape=ellipse];
+    "b" [shape=box];
+    "a" -> "b";
+    "b" -> "a" [label="12"];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn graph_01() {
+        let mut dot = Dot::graph("say \"hi\"");
+        dot.edge("b", "a").edge("a", "b");
+        assert_eq!(
+            dot.to_string(),
+            r#"graph "say \"hi\"" {
+    "a" [shape=ellipse];
+    "b" [shape=ellipse];
+    "a" -- "b";
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,390 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use num_traits::PrimInt;
+
+use crate::{
+    coords::{BoundingRectangle, Coords},
+    parse::ParseError,
+};
+
+/// Offsets of the 4 orthogonal neighbours: north, east, south and west
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the 8 surrounding neighbours, row by row
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A dense 2D map, stored row by row.
+///
+/// `(0, 0)` is the top-left cell, `x` grows to the right and `y` downwards.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Panics if there are not exactly `width * height` cells
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "A {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounding_rect(&self) -> BoundingRectangle<usize> {
+        BoundingRectangle {
+            origin: Coords { x: 0, y: 0 },
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Where `coords` is stored in `cells`, if it is inside the grid at all.
+    ///
+    /// Any integer type is accepted, so that callers walking out of the grid
+    /// (negative positions included) can simply get `None` back.
+    fn index_of<C: PrimInt>(&self, coords: Coords<C>) -> Option<usize> {
+        let x = coords.x.to_usize()?;
+        let y = coords.y.to_usize()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn contains<C: PrimInt>(&self, coords: impl Into<Coords<C>>) -> bool {
+        self.index_of(coords.into()).is_some()
+    }
+
+    pub fn get<C: PrimInt>(&self, coords: impl Into<Coords<C>>) -> Option<&T> {
+        self.index_of(coords.into()).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut<C: PrimInt>(&mut self, coords: impl Into<Coords<C>>) -> Option<&mut T> {
+        self.index_of(coords.into())
+            .map(|index| &mut self.cells[index])
+    }
+
+    /// Every coordinates of the grid, row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coords<usize>> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| Coords { x, y }))
+    }
+
+    /// Every cell of the grid along with its coordinates, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coords<usize>, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    /// Coordinates of the first cell, row by row, matching `predicate`
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Coords<usize>> {
+        self.cells.iter().position(predicate).map(|index| Coords {
+            x: index % self.width,
+            y: index / self.width,
+        })
+    }
+
+    /// The cell `(dx, dy)` away from `coords`, if still inside the grid
+    pub fn offset(&self, coords: Coords<usize>, (dx, dy): (isize, isize)) -> Option<Coords<usize>> {
+        let neighbour = Coords {
+            x: coords.x.checked_add_signed(dx)?,
+            y: coords.y.checked_add_signed(dy)?,
+        };
+        self.contains(neighbour).then_some(neighbour)
+    }
+
+    /// The orthogonal neighbours of `coords` inside the grid: north, east,
+    /// south then west
+    pub fn neighbours4(&self, coords: Coords<usize>) -> impl Iterator<Item = Coords<usize>> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |&delta| self.offset(coords, delta))
+    }
+
+    /// The (up to) 8 cells surrounding `coords` inside the grid, row by row
+    pub fn neighbours8(&self, coords: Coords<usize>) -> impl Iterator<Item = Coords<usize>> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&delta| self.offset(coords, delta))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` does not accept 0, which only happens with an empty grid
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "No column {} in this grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// A grid of the same size, each cell being transformed by `f`
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Mirror the grid along its top-left to bottom-right diagonal
+    pub fn transpose(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Rotate the grid a quarter turn, the first column becoming the first
+    /// row (read bottom to top)
+    pub fn rotate_clockwise(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self
+                .columns()
+                .flat_map(|column| column.collect::<Vec<_>>().into_iter().rev())
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Rotate the grid a quarter turn, the last column becoming the first row
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|x| self.column(x))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl<T, C: Into<Coords<usize>>> Index<C> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coords: C) -> &Self::Output {
+        let coords = coords.into();
+        self.get(coords)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", coords))
+    }
+}
+
+impl<T, C: Into<Coords<usize>>> IndexMut<C> for Grid<T> {
+    fn index_mut(&mut self, coords: C) -> &mut Self::Output {
+        let coords = coords.into();
+        self.get_mut(coords)
+            .unwrap_or_else(|| panic!("{} is outside of the grid", coords))
+    }
+}
+
+/// One line per row, one character per cell.
+///
+/// Surrounding whitespace and blank lines are ignored, but every row must
+/// have the same width.
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (index, line) in s.lines().enumerate() {
+            let row = line.trim();
+            if row.is_empty() {
+                continue;
+            }
+
+            let row_width = row.chars().count();
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(ParseError::at(
+                    index,
+                    line,
+                    row,
+                    format!("expected {} tiles, got {}", width.unwrap(), row_width),
+                ));
+            }
+
+            for (offset, c) in row.char_indices() {
+                cells.push(T::try_from(c).map_err(|_| {
+                    ParseError::at(
+                        index,
+                        line,
+                        &row[offset..offset + c.len_utf8()],
+                        "unknown tile",
+                    )
+                })?);
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests_grid {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn from_str_01() {
+        let grid = sample();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(1, 1)], 'e');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn from_str_ragged() {
+        assert_eq!(
+            "abc\nde".parse::<Grid<char>>(),
+            Err(ParseError {
+                line: 2,
+                column: 1,
+                text: String::from("de"),
+                reason: String::from("expected 3 tiles, got 2"),
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_unknown_tile() {
+        #[derive(Debug)]
+        struct Digit(#[allow(dead_code)] u32);
+
+        impl TryFrom<char> for Digit {
+            type Error = ();
+
+            fn try_from(c: char) -> Result<Self, Self::Error> {
+                c.to_digit(10).map(Digit).ok_or(())
+            }
+        }
+
+        let error = "123\n  4x6".parse::<Grid<Digit>>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.reason, "unknown tile");
+    }
+
+    #[test]
+    fn get_01() {
+        let grid = sample();
+        assert_eq!(grid.get((2, 0)), Some(&'c'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+        assert_eq!(grid.get(Coords::<i32> { x: 0, y: 1 }), Some(&'d'));
+    }
+
+    #[test]
+    fn position_01() {
+        assert_eq!(
+            sample().position(|&c| c == 'f'),
+            Some(Coords { x: 2, y: 1 })
+        );
+        assert_eq!(sample().position(|&c| c == 'z'), None);
+    }
+
+    #[test]
+    fn neighbours_01() {
+        let grid = sample();
+        assert_eq!(
+            grid.neighbours4(Coords { x: 0, y: 0 }).collect::<Vec<_>>(),
+            vec![Coords { x: 1, y: 0 }, Coords { x: 0, y: 1 }]
+        );
+        assert_eq!(grid.neighbours8(Coords { x: 1, y: 0 }).count(), 5);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = sample();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use std::{fs, io, path::Path};
+
+pub fn get_file_content(file_path: impl AsRef<Path>) -> io::Result<String> {
+    let file_path = file_path.as_ref();
+    crate::info!("Loading input file: {}", file_path.display());
+    fs::read_to_string(file_path)
+}
+
+#[cfg(test)]
+mod tests_get_file_content {
+    use super::*;
+
+    #[test]
+    fn get_file_content_missing() {
+        let error = get_file_content("/nonexistent/input").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,17 @@
+//! Helpers shared by every Advent Of Code solution of this repository
+//!
+//! Each day used to carry its own copy of these, so a fix made in one day
+//! never reached the others. They now live here, once.
+
+pub mod bench;
+pub mod coords;
+pub mod cycle;
+pub mod direction;
+pub mod display;
+pub mod dot;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod parse;
+pub mod range_set;
+pub mod solution;
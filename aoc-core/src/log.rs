@@ -0,0 +1,112 @@
+use std::{
+    fmt::Display,
+    str::FromStr,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// How much is written to stderr besides the answers, from nothing to
+/// everything
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Level {
+    Quiet,
+    /// What the runner is doing, e.g. which input is being loaded
+    Info,
+    /// Intermediate results of a solution
+    Debug,
+    /// Step by step progress, including whole map dumps
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 4] = [Level::Quiet, Level::Info, Level::Debug, Level::Trace];
+
+    /// Whether messages of `level` should be written when this is the level
+    /// set
+    fn allows(self, level: Level) -> bool {
+        level != Level::Quiet && level <= self
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Quiet => write!(f, "quiet"),
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+            Level::Trace => write!(f, "trace"),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.to_string() == s)
+            .ok_or_else(|| format!("unknown log level `{}`", s))
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    Level::ALL[LEVEL.load(Ordering::Relaxed) as usize]
+}
+
+/// Whether messages of `level` should be written
+pub fn enabled(level: Level) -> bool {
+    self::level().allows(level)
+}
+
+/// Write a line to stderr if messages of the given level are enabled
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::log::enabled($level) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Info, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Debug, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Trace, $($arg)*) };
+}
+
+#[cfg(test)]
+mod tests_level {
+    use super::*;
+
+    #[test]
+    fn level_01() {
+        assert_eq!("debug".parse(), Ok(Level::Debug));
+        assert!("loud".parse::<Level>().is_err());
+        assert!(Level::Info < Level::Trace);
+        assert_eq!(Level::Quiet.to_string(), "quiet");
+    }
+
+    #[test]
+    fn allows_01() {
+        assert!(Level::Debug.allows(Level::Info));
+        assert!(Level::Debug.allows(Level::Debug));
+        assert!(!Level::Debug.allows(Level::Trace));
+        assert!(!Level::Debug.allows(Level::Quiet));
+        assert!(!Level::Quiet.allows(Level::Quiet));
+    }
+}
@@ -0,0 +1,122 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Where, and why, an input could not be parsed
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    /// 1-based line of the input
+    pub line: usize,
+    /// 1-based column, in characters, where `text` starts
+    pub column: usize,
+    /// The offending text
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// An error about `text` on the `index`th (0-based) line of the input.
+    ///
+    /// When `text` is a slice of `line`, the column is where it starts,
+    /// otherwise it falls back to its first occurrence in `line`.
+    pub fn at(index: usize, line: &str, text: &str, reason: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| offset + text.len() <= line.len())
+            .or_else(|| line.find(text))
+            .unwrap_or(0);
+
+        ParseError {
+            line: index + 1,
+            column: line[..offset].chars().count() + 1,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// An error about a whole line
+    pub fn line(index: usize, line: &str, reason: impl Into<String>) -> Self {
+        ParseError::at(index, line, line, reason)
+    }
+
+    /// An error about something expected past the end of a truncated line
+    pub fn missing(index: usize, line: &str, what: &str) -> Self {
+        ParseError::at(
+            index,
+            line,
+            &line[line.len()..],
+            format!("missing {}", what),
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (`{}`)",
+            self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse `text`, a field of the `index`th (0-based) line of the input, and
+/// name it `what` if it is not a valid `T`
+pub fn parse_field<T>(index: usize, line: &str, text: &str, what: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse()
+        .map_err(|error| ParseError::at(index, line, text, format!("invalid {}: {}", what, error)))
+}
+
+#[cfg(test)]
+mod tests_parse_error {
+    use super::*;
+
+    #[test]
+    fn at_01() {
+        let line = "3   4x";
+        let error = ParseError::at(2, line, &line[4..], "invalid number");
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 5);
+        assert_eq!(error.text, "4x");
+        assert_eq!(error.to_string(), "line 3, column 5: invalid number (`4x`)");
+    }
+
+    #[test]
+    fn at_02() {
+        // Not a slice of the line: located by searching for it
+        let error = ParseError::at(0, "a -> b", "b", "unknown module");
+        assert_eq!(error.column, 6);
+    }
+
+    #[test]
+    fn missing_01() {
+        let error = ParseError::missing(0, "3   ", "right value");
+        assert_eq!(error.column, 5);
+        assert_eq!(error.text, "");
+        assert_eq!(error.reason, "missing right value");
+    }
+}
+
+#[cfg(test)]
+mod tests_parse_field {
+    use super::*;
+
+    #[test]
+    fn parse_field_01() {
+        let line = "12 x3";
+        assert_eq!(parse_field::<u32>(0, line, &line[..2], "number"), Ok(12));
+        assert_eq!(
+            parse_field::<u32>(0, line, &line[3..], "number"),
+            Err(ParseError {
+                line: 1,
+                column: 4,
+                text: String::from("x3"),
+                reason: String::from("invalid number: invalid digit found in string"),
+            })
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use std::ops::{Range, RangeInclusive};
+
+use num_traits::PrimInt;
+
+/// A set of integers, stored as sorted, disjoint and non adjacent ranges.
+///
+/// Each operation builds a new set, so that puzzles splitting a set in two
+/// (what matches a rule, what does not) can keep both halves around.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: PrimInt> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { ranges: vec![] }
+    }
+
+    /// Sort the ranges, then merge the ones overlapping or touching each
+    /// other, empty ones being dropped
+    fn normalized(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|range| range.start < range.end);
+        ranges.sort_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        RangeSet { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many values are in the set
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |acc, range| acc + (range.end - range.start))
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end - T::one())
+    }
+
+    /// Values in either set
+    pub fn union(&self, other: &Self) -> Self {
+        RangeSet::normalized(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    /// Values in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // Whichever range ends first cannot overlap anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// Values of this set that are not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        for range in &self.ranges {
+            let mut start = range.start;
+            for removed in &other.ranges {
+                if removed.end <= start {
+                    continue;
+                }
+                if removed.start >= range.end {
+                    break;
+                }
+                if removed.start > start {
+                    ranges.push(start..removed.start);
+                }
+                start = removed.end;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// Values below `value`, and values from `value` onwards
+    pub fn split_at(&self, value: T) -> (Self, Self) {
+        let (mut below, mut above) = (vec![], vec![]);
+        for range in &self.ranges {
+            if range.end <= value {
+                below.push(range.clone());
+            } else if range.start >= value {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..value);
+                above.push(value..range.end);
+            }
+        }
+        (RangeSet { ranges: below }, RangeSet { ranges: above })
+    }
+
+    /// Move every value by the same amount, so that `from` would end up at
+    /// `to`
+    pub fn shift(&self, from: T, to: T) -> Self {
+        let moved = |value: T| {
+            if to >= from {
+                value + (to - from)
+            } else {
+                value - (from - to)
+            }
+        };
+        RangeSet {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|range| moved(range.start)..moved(range.end))
+                .collect(),
+        }
+    }
+}
+
+impl<T: PrimInt> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet::new()
+    }
+}
+
+impl<T: PrimInt> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        RangeSet::normalized(vec![range])
+    }
+}
+
+impl<T: PrimInt> From<RangeInclusive<T>> for RangeSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        RangeSet::from(start..end + T::one())
+    }
+}
+
+impl<T: PrimInt> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        RangeSet::normalized(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests_range_set {
+    use super::*;
+
+    #[test]
+    fn from_iter_01() {
+        let set: RangeSet<u32> = vec![8..10, 1..3, 2..5, 5..6, 7..7].into_iter().collect();
+        assert_eq!(set.ranges(), &[1..6, 8..10]);
+        assert_eq!(set.len(), 7);
+        assert_eq!((set.min(), set.max()), (Some(1), Some(9)));
+        assert!(set.contains(5));
+        assert!(!set.contains(6));
+    }
+
+    #[test]
+    fn from_range_inclusive_01() {
+        let set = RangeSet::from(1..=4000_usize);
+        assert_eq!(set, RangeSet::from(1..4001));
+        assert_eq!(set.len(), 4000);
+        assert!(RangeSet::<i32>::new().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod tests_operations {
+    use super::*;
+
+    fn set(ranges: &[Range<i32>]) -> RangeSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn union_01() {
+        assert_eq!(
+            set(&[1..3, 10..12]).union(&set(&[3..5, 11..15])),
+            set(&[1..5, 10..15])
+        );
+    }
+
+    #[test]
+    fn intersection_01() {
+        assert_eq!(
+            set(&[1..5, 8..20]).intersection(&set(&[0..2, 4..10, 12..13, 19..30])),
+            set(&[1..2, 4..5, 8..10, 12..13, 19..20])
+        );
+        assert!(RangeSet::from(1..5)
+            .intersection(&RangeSet::from(5..8))
+            .is_empty());
+    }
+
+    #[test]
+    fn difference_01() {
+        assert_eq!(
+            set(&[0..10, 20..30]).difference(&set(&[2..4, 8..22, 25..26])),
+            set(&[0..2, 4..8, 22..25, 26..30])
+        );
+        assert_eq!(
+            RangeSet::from(0..10).difference(&RangeSet::new()),
+            RangeSet::from(0..10)
+        );
+    }
+
+    #[test]
+    fn split_at_01() {
+        assert_eq!(
+            set(&[0..5, 8..12]).split_at(10),
+            (set(&[0..5, 8..10]), RangeSet::from(10..12))
+        );
+        assert_eq!(
+            RangeSet::from(0..5).split_at(5),
+            (RangeSet::from(0..5), RangeSet::new())
+        );
+    }
+
+    #[test]
+    fn shift_01() {
+        assert_eq!(set(&[0..5, 8..12]).shift(8, 2), set(&[-6..-1, 2..6]));
+        assert_eq!(
+            RangeSet::from(98_u64..100).shift(98, 50),
+            RangeSet::from(50..52)
+        );
+    }
+}
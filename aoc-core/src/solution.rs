@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use crate::{dot::Dot, parse::ParseError};
+
+/// The common shape of every day: parse the input once, then solve each part
+/// from the parsed value.
+///
+/// Parts default to `None`, for days that only have one of them. So does
+/// `dot`, which only makes sense for days whose input is a graph.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(_input: &Self::Input) -> Option<impl Display> {
+        None::<u8>
+    }
+
+    fn part2(_input: &Self::Input) -> Option<impl Display> {
+        None::<u8>
+    }
+
+    /// The input drawn as a graph
+    fn dot(_input: &Self::Input) -> Option<Dot> {
+        None
+    }
+}
+
+/// The rendered answers of the requested parts, or why the input could not be
+/// parsed
+pub type Answers = Result<Vec<Option<String>>, ParseError>;
+
+/// Parse `input` once and solve each of the given parts of `S`, rendering the
+/// answers in the same order.
+///
+/// An answer is `None` when `S` does not implement that part.
+pub fn solve<S: Solution>(input: &str, parts: &[u8]) -> Answers {
+    let input = S::parse(input)?;
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            1 => S::part1(&input).map(|answer| answer.to_string()),
+            2 => S::part2(&input).map(|answer| answer.to_string()),
+            _ => None,
+        })
+        .collect())
+}
+
+/// The input drawn as a graph, if `S` knows how to, or why the input could
+/// not be parsed
+pub type Drawing = Result<Option<Dot>, ParseError>;
+
+pub fn draw<S: Solution>(input: &str) -> Drawing {
+    Ok(S::dot(&S::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests_solve {
+    use super::*;
+    use crate::parse::parse_field;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(index, line)| parse_field(index, line, line, "number"))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Option<impl Display> {
+            Some(input.iter().sum::<u32>())
+        }
+    }
+
+    #[test]
+    fn solve_01() {
+        assert_eq!(
+            solve::<Sum>("1\n2\n3", &[1, 2]),
+            Ok(vec![Some(String::from("6")), None])
+        );
+        assert_eq!(solve::<Sum>("1\n2\n3", &[2]), Ok(vec![None]));
+        assert_eq!(draw::<Sum>("1\n2\n3"), Ok(None));
+        assert_eq!(
+            solve::<Sum>("1\nx", &[1]),
+            Err(ParseError {
+                line: 2,
+                column: 1,
+                text: String::from("x"),
+                reason: String::from("invalid number: invalid digit found in string"),
+            })
+        );
+    }
+}
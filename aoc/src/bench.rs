@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use aoc_core::bench::Timing;
+
+use crate::solutions::Day;
+
+pub const CSV_HEADER: &str = "year,day,step,runs,min_ns,median_ns,max_ns";
+
+/// One CSV line per timed step of `day`
+pub fn csv_rows(day: &Day, timings: &[Timing]) -> Vec<String> {
+    timings
+        .iter()
+        .map(|timing| {
+            format!(
+                "{},{},{},{},{},{},{}",
+                day.year,
+                day.day,
+                timing.step,
+                timing.durations.len(),
+                timing.min().as_nanos(),
+                timing.median().as_nanos(),
+                timing.max().as_nanos()
+            )
+        })
+        .collect()
+}
+
+/// Human readable summary of the timings of `day`
+pub fn report(day: &Day, timings: &[Timing]) -> String {
+    let mut out = format!(
+        "===== {} day {} ({} runs) =====\n",
+        day.year,
+        day.day,
+        timings.first().map_or(0, |timing| timing.durations.len())
+    );
+    for timing in timings {
+        out.push_str(&format!(
+            "{:<8} min {:>10}   median {:>10}   max {:>10}\n",
+            timing.step.to_string(),
+            format_duration(timing.min()),
+            format_duration(timing.median()),
+            format_duration(timing.max())
+        ));
+    }
+    out
+}
+
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests_bench {
+    use super::*;
+    use crate::select;
+    use aoc_core::bench::Step;
+
+    fn timings() -> Vec<Timing> {
+        vec![
+            Timing::new(
+                Step::Parse,
+                [900, 1_500, 2_000].map(Duration::from_nanos).to_vec(),
+            ),
+            Timing::new(Step::Part(2), [3, 2, 4].map(Duration::from_millis).to_vec()),
+        ]
+    }
+
+    #[test]
+    fn csv_rows_01() {
+        assert_eq!(
+            csv_rows(select(2023, Some(5))[0], &timings()),
+            vec![
+                "2023,5,parse,3,900,1500,2000",
+                "2023,5,part 2,3,2000000,3000000,4000000"
+            ]
+        );
+    }
+
+    #[test]
+    fn report_01() {
+        assert_eq!(
+            report(select(2023, Some(5))[0], &timings()),
+            "\
+===== 2023 day 5 (3 runs) =====
+parse    min      900ns   median      1.5µs   max      2.0µs
+part 2   min      2.0ms   median      3.0ms   max      4.0ms
+"
+        );
+    }
+
+    #[test]
+    fn format_duration_01() {
+        assert_eq!(format_duration(Duration::from_secs(3)), "3.00s");
+    }
+}
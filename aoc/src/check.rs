@@ -0,0 +1,241 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::solutions::Day;
+
+/// The recorded answers of a day, by part.
+///
+/// The file holds one `<part>: <answer>` line per known answer, so a day can
+/// record part 1 before part 2 is solved.
+pub type Recorded = BTreeMap<u8, String>;
+
+pub fn parse_recorded(content: &str) -> Recorded {
+    content
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .filter_map(|(part, answer)| Some((part.trim().parse().ok()?, answer.trim().to_string())))
+        .collect()
+}
+
+pub fn render_recorded(answers: &[Option<String>]) -> String {
+    answers
+        .iter()
+        .zip(1..)
+        .filter_map(|(answer, part)| answer.as_ref().map(|a| format!("{}: {}\n", part, a)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_recorded {
+    use super::*;
+
+    #[test]
+    fn parse_recorded_01() {
+        assert_eq!(
+            parse_recorded("1: 142\n2:  281 \n"),
+            Recorded::from([(1, String::from("142")), (2, String::from("281"))])
+        );
+        assert_eq!(
+            parse_recorded("2: 281\nnot an answer\n"),
+            Recorded::from([(2, String::from("281"))])
+        );
+    }
+
+    #[test]
+    fn render_recorded_01() {
+        assert_eq!(
+            render_recorded(&[Some(String::from("142")), None]),
+            "1: 142\n"
+        );
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Status {
+    Ok,
+    Mismatch,
+    Skipped(&'static str),
+    Failed(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Mismatch => write!(f, "MISMATCH"),
+            Status::Skipped(reason) => write!(f, "skipped ({})", reason),
+            Status::Failed(reason) => write!(f, "FAILED ({})", reason),
+        }
+    }
+}
+
+/// The outcome of checking one part of a day (or the whole day, when `part`
+/// is `None`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: Option<u8>,
+    pub status: Status,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+}
+
+impl Row {
+    pub fn whole_day(day: &Day, status: Status) -> Self {
+        Row {
+            year: day.year,
+            day: day.day,
+            part: None,
+            status,
+            expected: None,
+            actual: None,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self.status, Status::Mismatch | Status::Failed(_))
+    }
+}
+
+/// Compare what each part of `day` answered with what was recorded
+pub fn compare(
+    day: &Day,
+    parts: &[u8],
+    answers: Vec<Option<String>>,
+    recorded: &Recorded,
+) -> Vec<Row> {
+    parts
+        .iter()
+        .zip(answers)
+        .map(|(&part, actual)| {
+            let expected = recorded.get(&part).cloned();
+            let status = match (&expected, &actual) {
+                (None, _) => Status::Skipped("no recorded answer"),
+                (Some(_), None) => Status::Failed(String::from("not solved")),
+                (Some(expected), Some(actual)) if expected == actual => Status::Ok,
+                (Some(_), Some(_)) => Status::Mismatch,
+            };
+            Row {
+                year: day.year,
+                day: day.day,
+                part: Some(part),
+                status,
+                expected,
+                actual,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_compare {
+    use super::*;
+    use crate::select;
+
+    #[test]
+    fn compare_01() {
+        let day = select(2023, Some(1))[0];
+        let rows = compare(
+            day,
+            &[1, 2],
+            vec![Some(String::from("142")), Some(String::from("280"))],
+            &Recorded::from([(1, String::from("142")), (2, String::from("281"))]),
+        );
+        assert_eq!(
+            rows.iter()
+                .map(|row| row.status.clone())
+                .collect::<Vec<_>>(),
+            vec![Status::Ok, Status::Mismatch]
+        );
+        assert!(rows[1].is_failure());
+    }
+
+    #[test]
+    fn compare_02() {
+        let day = select(2023, Some(25))[0];
+        let rows = compare(
+            day,
+            &[1, 2],
+            vec![None, None],
+            &Recorded::from([(1, String::from("54"))]),
+        );
+        assert_eq!(
+            rows.iter()
+                .map(|row| row.status.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                Status::Failed(String::from("not solved")),
+                Status::Skipped("no recorded answer")
+            ]
+        );
+    }
+}
+
+/// Lay the rows out as an aligned table
+pub fn render_table(rows: &[Row]) -> String {
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.year.to_string(),
+                row.day.to_string(),
+                row.part.map_or(String::from("-"), |part| part.to_string()),
+                row.status.to_string(),
+                row.expected.clone().unwrap_or_default(),
+                row.actual.clone().unwrap_or_default(),
+            ]
+        })
+        .collect();
+    let header = ["year", "day", "part", "status", "expected", "actual"].map(String::from);
+
+    let mut widths = header.clone().map(|title| title.chars().count());
+    for line in &cells {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    std::iter::once(&header)
+        .chain(&cells)
+        .map(|line| {
+            let mut out = line
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            out.truncate(out.trim_end().len());
+            out.push('\n');
+            out
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_render_table {
+    use super::*;
+    use crate::select;
+
+    #[test]
+    fn render_table_01() {
+        let day = select(2023, Some(1))[0];
+        let mut rows = compare(
+            day,
+            &[1],
+            vec![Some(String::from("142"))],
+            &Recorded::from([(1, String::from("143"))]),
+        );
+        rows.push(Row::whole_day(
+            select(2023, Some(2))[0],
+            Status::Skipped("no input"),
+        ));
+        assert_eq!(
+            render_table(&rows),
+            "\
+year  day  part  status              expected  actual
+2023  1    1     MISMATCH            143       142
+2023  2    -     skipped (no input)
+"
+        );
+    }
+}
@@ -0,0 +1,462 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_2023_day_17::{Crucible, Day17};
+use aoc_2023_day_25::{Day25, Method};
+use aoc_core::{
+    info,
+    input::get_file_content,
+    log::{self, Level},
+    solution::Solution,
+};
+use clap::{error::ErrorKind, CommandFactory, Parser, Subcommand};
+
+mod bench;
+mod check;
+mod solutions;
+
+use solutions::{Day, DAYS};
+
+#[derive(Parser)]
+#[command(about = "Run the Advent Of Code solutions of this repository")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// What else to write to stderr: quiet, info, debug or trace (which
+    /// includes map dumps)
+    #[arg(long, global = true, default_value_t = Level::Info)]
+    log_level: Level,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a whole year, a whole day, or a single part
+    Run {
+        year: u16,
+        day: Option<u8>,
+        part: Option<u8>,
+        /// Input file to use instead of the day's `assets/input`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Time the parsing and each part of a whole year, a whole day, or a
+    /// single part
+    Bench {
+        year: u16,
+        day: Option<u8>,
+        part: Option<u8>,
+        /// How many times each step is repeated
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u16).range(1..))]
+        runs: u16,
+        /// Where to write the timings, as CSV
+        #[arg(long, default_value = "bench.csv")]
+        output: PathBuf,
+    },
+    /// Write the input of a day as a GraphViz DOT graph, for the days whose
+    /// input is a graph
+    Dot {
+        year: u16,
+        day: u8,
+        /// Input file to use instead of the day's `assets/input`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Find the minimal heat loss of 2023 day 17 with other crucible rules
+    /// than the ones of both parts
+    Crucible {
+        /// `regular`, `ultra` or `<min run>-<max run>`, optionally followed by
+        /// `+<turn penalty>`, e.g. `2-6+1`
+        rules: Crucible,
+        /// Input file to use instead of the day's `assets/input`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Find the three wires to cut of 2023 day 25 with another algorithm
+    /// than the one of part 1
+    Wires {
+        /// `stoer-wagner` or `karger`, optionally followed by `:<seed>` and
+        /// then `:<attempts>`, e.g. `karger:25:1000`
+        method: Method,
+        /// Input file to use instead of the day's `assets/input`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Compare the answers of every day, a whole year or a single day with
+    /// the ones recorded in their `assets/answers`
+    Check {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Record the current answers of days that have none recorded yet
+        #[arg(long)]
+        record: bool,
+    },
+}
+
+/// Every day matching the given year, and optionally day
+fn select(year: u16, day: Option<u8>) -> Vec<&'static Day> {
+    DAYS.iter()
+        .filter(|d| d.year == year)
+        .filter(|d| day.is_none_or(|n| d.day == n))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests_select {
+    use super::*;
+
+    #[test]
+    fn select_single_day() {
+        let selected = select(2023, Some(17));
+        assert_eq!(selected.len(), 1);
+        assert_eq!((selected[0].year, selected[0].day), (2023, 17));
+    }
+
+    #[test]
+    fn select_whole_year() {
+        assert_eq!(select(2023, None).len(), 25);
+        assert_eq!(select(2024, None).len(), 11);
+        assert!(select(2022, None).is_empty());
+    }
+}
+
+/// Where a day keeps the asset `name`, from the root of the repository
+fn asset_path(day: &Day, name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(day.year.to_string())
+        .join(format!("day-{:02}", day.day))
+        .join("assets")
+        .join(name)
+}
+
+/// Where a day expects its input
+fn default_input_path(day: &Day) -> PathBuf {
+    asset_path(day, "input")
+}
+
+/// Where a day records the expected answers for its input
+fn answers_path(day: &Day) -> PathBuf {
+    asset_path(day, "answers")
+}
+
+#[cfg(test)]
+mod tests_asset_path {
+    use super::*;
+
+    #[test]
+    fn default_input_path_01() {
+        let path = default_input_path(select(2023, Some(7))[0]);
+        assert!(path.ends_with("2023/day-07/assets/input"));
+    }
+
+    #[test]
+    fn answers_path_01() {
+        let path = answers_path(select(2024, Some(3))[0]);
+        assert!(path.ends_with("2024/day-03/assets/answers"));
+    }
+}
+
+/// Every day matching the selection, or exit with an error if there is none
+fn select_or_exit(year: u16, day: Option<u8>, part: Option<u8>) -> Vec<&'static Day> {
+    let selected = select(year, day);
+    if selected.is_empty() || part.is_some_and(|p| p != 1 && p != 2) {
+        Cli::command()
+            .error(ErrorKind::InvalidValue, "No puzzle matches this selection")
+            .exit();
+    }
+    selected
+}
+
+/// The input of `day`, or `None` (after saying so) when the day's own input is
+/// missing. A file given `explicitly` with `--input` has to exist.
+fn read_input(day: &Day, path: &Path, explicitly: bool) -> Result<Option<String>, String> {
+    if !path.exists() && !explicitly {
+        info!(
+            "===== {} day {} =====\nNo input file at {}, skipping",
+            day.year,
+            day.day,
+            path.display()
+        );
+        return Ok(None);
+    }
+    get_file_content(path)
+        .map(Some)
+        .map_err(|error| format!("Cannot read {}: {}", path.display(), error))
+}
+
+#[cfg(test)]
+mod tests_read_input {
+    use super::*;
+
+    #[test]
+    fn read_input_missing() {
+        let day = select(2023, Some(1))[0];
+        let path = Path::new("/nonexistent/input");
+        assert_eq!(read_input(day, path, false), Ok(None));
+        assert!(read_input(day, path, true)
+            .unwrap_err()
+            .starts_with("Cannot read /nonexistent/input: "));
+    }
+}
+
+/// The input of a single day, from `input` or else from the day's own file,
+/// along with where it comes from. `None` once the reason why is written.
+fn read_single_input(day: &Day, input: Option<PathBuf>) -> Option<(PathBuf, String)> {
+    let explicitly = input.is_some();
+    let path = input.unwrap_or_else(|| default_input_path(day));
+    match read_input(day, &path, explicitly) {
+        Ok(content) => content.map(|content| (path, content)),
+        Err(error) => {
+            eprintln!("{}", error);
+            None
+        }
+    }
+}
+
+fn run(year: u16, day: Option<u8>, part: Option<u8>, input: Option<PathBuf>) -> bool {
+    let selected = select_or_exit(year, day, part);
+    if input.is_some() && selected.len() > 1 {
+        Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--input can only be used when running a single day",
+            )
+            .exit();
+    }
+    let parts = part.map_or(vec![1, 2], |p| vec![p]);
+
+    let mut failed = false;
+    for day in selected {
+        let path = input.clone().unwrap_or_else(|| default_input_path(day));
+        let content = match read_input(day, &path, input.is_some()) {
+            Ok(Some(content)) => content,
+            Ok(None) => continue,
+            Err(error) => {
+                eprintln!("{}", error);
+                failed = true;
+                continue;
+            }
+        };
+        // The input is parsed once, then every requested part is solved
+        match (day.solve)(&content, &parts) {
+            Ok(answers) => {
+                for (part, answer) in parts.iter().zip(answers) {
+                    info!("===== {} day {} part {} =====", day.year, day.day, part);
+                    match answer {
+                        Some(answer) => println!("{}", answer),
+                        None => info!("Not solved yet"),
+                    }
+                }
+            }
+            Err(error) => {
+                eprintln!("Cannot parse {}: {}", path.display(), error);
+                failed = true;
+            }
+        }
+    }
+    failed
+}
+
+fn bench(year: u16, day: Option<u8>, part: Option<u8>, runs: u16, output: PathBuf) -> bool {
+    let selected = select_or_exit(year, day, part);
+    let parts = part.map_or(vec![1, 2], |p| vec![p]);
+
+    let mut failed = false;
+    let mut csv = vec![bench::CSV_HEADER.to_string()];
+    for day in selected {
+        let path = default_input_path(day);
+        let content = match read_input(day, &path, false) {
+            Ok(Some(content)) => content,
+            Ok(None) => continue,
+            Err(error) => {
+                eprintln!("{}", error);
+                failed = true;
+                continue;
+            }
+        };
+        match (day.bench)(&content, &parts, runs.into()) {
+            Ok(timings) => {
+                println!("\n{}", bench::report(day, &timings).trim_end());
+                csv.extend(bench::csv_rows(day, &timings));
+            }
+            Err(error) => {
+                eprintln!("Cannot parse {}: {}", path.display(), error);
+                failed = true;
+            }
+        }
+    }
+
+    csv.push(String::new());
+    if let Err(error) = fs::write(&output, csv.join("\n")) {
+        eprintln!("Cannot write {}: {}", output.display(), error);
+        failed = true;
+    } else {
+        info!("Timings written to {}", output.display());
+    }
+    failed
+}
+
+fn dot(year: u16, day: u8, input: Option<PathBuf>) -> bool {
+    let day = select_or_exit(year, Some(day), None)[0];
+    let Some((path, content)) = read_single_input(day, input) else {
+        return true;
+    };
+    match (day.draw)(&content) {
+        Ok(Some(dot)) => {
+            print!("{}", dot);
+            false
+        }
+        Ok(None) => {
+            eprintln!("{} day {} has no graph to draw", day.year, day.day);
+            true
+        }
+        Err(error) => {
+            eprintln!("Cannot parse {}: {}", path.display(), error);
+            true
+        }
+    }
+}
+
+fn crucible(rules: Crucible, input: Option<PathBuf>) -> bool {
+    let day = select_or_exit(2023, Some(17), None)[0];
+    let Some((path, content)) = read_single_input(day, input) else {
+        return true;
+    };
+    match Day17::parse(&content) {
+        Ok(map) => match aoc_2023_day_17::solve_with(&map, &rules) {
+            Some(heat_loss) => {
+                info!("===== 2023 day 17 with a {} crucible =====", rules);
+                println!("{}", heat_loss);
+                false
+            }
+            None => {
+                eprintln!("A {} crucible cannot reach the factory", rules);
+                true
+            }
+        },
+        Err(error) => {
+            eprintln!("Cannot parse {}: {}", path.display(), error);
+            true
+        }
+    }
+}
+
+fn wires(method: Method, input: Option<PathBuf>) -> bool {
+    let day = select_or_exit(2023, Some(25), None)[0];
+    let Some((path, content)) = read_single_input(day, input) else {
+        return true;
+    };
+    match Day25::parse(&content) {
+        Ok(graph) => match aoc_2023_day_25::solve_with(&graph, &method) {
+            Some(product) => {
+                info!("===== 2023 day 25 with {} =====", method);
+                println!("{}", product);
+                false
+            }
+            None => {
+                eprintln!("{} found no three wires to cut", method);
+                true
+            }
+        },
+        Err(error) => {
+            eprintln!("Cannot parse {}: {}", path.display(), error);
+            true
+        }
+    }
+}
+
+/// Check one day against its recorded answers, recording them first if asked
+/// to and there are none yet
+fn check_day(day: &Day, record: bool) -> Vec<check::Row> {
+    use check::{Row, Status};
+
+    let parts = [1, 2];
+    let input_path = default_input_path(day);
+    if !input_path.exists() {
+        return vec![Row::whole_day(day, Status::Skipped("no input"))];
+    }
+    let content = match get_file_content(&input_path) {
+        Ok(content) => content,
+        Err(error) => {
+            let reason = format!("cannot read {}: {}", input_path.display(), error);
+            return vec![Row::whole_day(day, Status::Failed(reason))];
+        }
+    };
+    let answers = match (day.solve)(&content, &parts) {
+        Ok(answers) => answers,
+        Err(error) => return vec![Row::whole_day(day, Status::Failed(error.to_string()))],
+    };
+
+    let path = answers_path(day);
+    let recorded = match fs::read_to_string(&path) {
+        Ok(recorded) => check::parse_recorded(&recorded),
+        Err(_) if record => {
+            if let Err(error) = fs::write(&path, check::render_recorded(&answers)) {
+                let reason = format!("cannot write {}: {}", path.display(), error);
+                return vec![Row::whole_day(day, Status::Failed(reason))];
+            }
+            info!("Recorded the answers of {} day {}", day.year, day.day);
+            check::parse_recorded(&check::render_recorded(&answers))
+        }
+        Err(_) => return vec![Row::whole_day(day, Status::Skipped("no answers"))],
+    };
+    check::compare(day, &parts, answers, &recorded)
+}
+
+fn check(year: Option<u16>, day: Option<u8>, record: bool) -> bool {
+    let selected = match year {
+        Some(year) => select_or_exit(year, day, None),
+        None => DAYS.iter().collect(),
+    };
+
+    let rows: Vec<_> = selected
+        .into_iter()
+        .flat_map(|day| check_day(day, record))
+        .collect();
+    print!("{}", check::render_table(&rows));
+
+    let failures = rows.iter().filter(|row| row.is_failure()).count();
+    let skipped = rows
+        .iter()
+        .filter(|row| matches!(row.status, check::Status::Skipped(_)))
+        .count();
+    println!(
+        "\n{} checked, {} failed, {} skipped",
+        rows.len() - skipped,
+        failures,
+        skipped
+    );
+    failures > 0
+}
+
+fn main() {
+    let cli = Cli::parse();
+    log::set_level(cli.log_level);
+
+    let failed = match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => run(year, day, part, input),
+        Command::Bench {
+            year,
+            day,
+            part,
+            runs,
+            output,
+        } => bench(year, day, part, runs, output),
+        Command::Dot { year, day, input } => dot(year, day, input),
+        Command::Crucible { rules, input } => crucible(rules, input),
+        Command::Wires { method, input } => wires(method, input),
+        Command::Check { year, day, record } => check(year, day, record),
+    };
+
+    if failed {
+        std::process::exit(1);
+    }
+}
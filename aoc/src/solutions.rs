@@ -0,0 +1,64 @@
+use aoc_core::{
+    bench::{bench, Timings},
+    solution::{draw, solve, Answers, Drawing, Solution},
+};
+
+/// Both parts of a single day, how to solve them, how to time them and how to
+/// draw the input
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solve: fn(&str, &[u8]) -> Answers,
+    pub bench: fn(&str, &[u8], usize) -> Timings,
+    pub draw: fn(&str) -> Drawing,
+}
+
+const fn day<S: Solution>(year: u16, day: u8) -> Day {
+    Day {
+        year,
+        day,
+        solve: solve::<S>,
+        bench: bench::<S>,
+        draw: draw::<S>,
+    }
+}
+
+/// Every day of the repository, sorted by year and day
+pub const DAYS: &[Day] = &[
+    day::<aoc_2023_day_01::Day01>(2023, 1),
+    day::<aoc_2023_day_02::Day02>(2023, 2),
+    day::<aoc_2023_day_03::Day03>(2023, 3),
+    day::<aoc_2023_day_04::Day04>(2023, 4),
+    day::<aoc_2023_day_05::Day05>(2023, 5),
+    day::<aoc_2023_day_06::Day06>(2023, 6),
+    day::<aoc_2023_day_07::Day07>(2023, 7),
+    day::<aoc_2023_day_08::Day08>(2023, 8),
+    day::<aoc_2023_day_09::Day09>(2023, 9),
+    day::<aoc_2023_day_10::Day10>(2023, 10),
+    day::<aoc_2023_day_11::Day11>(2023, 11),
+    day::<aoc_2023_day_12::Day12>(2023, 12),
+    day::<aoc_2023_day_13::Day13>(2023, 13),
+    day::<aoc_2023_day_14::Day14>(2023, 14),
+    day::<aoc_2023_day_15::Day15>(2023, 15),
+    day::<aoc_2023_day_16::Day16>(2023, 16),
+    day::<aoc_2023_day_17::Day17>(2023, 17),
+    day::<aoc_2023_day_18::Day18>(2023, 18),
+    day::<aoc_2023_day_19::Day19>(2023, 19),
+    day::<aoc_2023_day_20::Day20>(2023, 20),
+    day::<aoc_2023_day_21::Day21>(2023, 21),
+    day::<aoc_2023_day_22::Day22>(2023, 22),
+    day::<aoc_2023_day_23::Day23>(2023, 23),
+    day::<aoc_2023_day_24::Day24>(2023, 24),
+    day::<aoc_2023_day_25::Day25>(2023, 25),
+    day::<aoc_2024_day_01::Day01>(2024, 1),
+    day::<aoc_2024_day_02::Day02>(2024, 2),
+    day::<aoc_2024_day_03::Day03>(2024, 3),
+    day::<aoc_2024_day_04::Day04>(2024, 4),
+    day::<aoc_2024_day_05::Day05>(2024, 5),
+    day::<aoc_2024_day_06::Day06>(2024, 6),
+    day::<aoc_2024_day_07::Day07>(2024, 7),
+    day::<aoc_2024_day_08::Day08>(2024, 8),
+    day::<aoc_2024_day_09::Day09>(2024, 9),
+    day::<aoc_2024_day_10::Day10>(2024, 10),
+    day::<aoc_2024_day_11::Day11>(2024, 11),
+];